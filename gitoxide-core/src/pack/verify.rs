@@ -227,6 +227,8 @@ fn print_statistics(out: &mut impl io::Write, stats: &index::traverse::Statistic
         decompressed_size,
         compressed_size,
         object_size,
+        delta_copy_bytes: _,
+        delta_insert_bytes: _,
     } = stats.average;
 
     let width = 30;
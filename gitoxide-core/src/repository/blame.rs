@@ -6,6 +6,7 @@ pub fn blame_file(
     mut repo: gix::Repository,
     file: &OsStr,
     options: gix::blame::Options,
+    mut progress: impl gix::Progress,
     out: impl std::io::Write,
     err: Option<&mut dyn std::io::Write>,
 ) -> anyhow::Result<()> {
@@ -45,6 +46,8 @@ pub fn blame_file(
         &mut resource_cache,
         file.as_bstr(),
         options,
+        &mut progress,
+        &gix::interrupt::IS_INTERRUPTED,
     )?;
     let statistics = outcome.statistics;
     show_blame_entries(out, outcome, file)?;
@@ -38,6 +38,31 @@ impl Search {
         }
         Ok(group)
     }
+
+    /// Given `git_dir`, a `.git` repository, create a search instance preloaded with *built-ins*, followed by
+    /// the global `attributes_file` if it is provided, typically from `core.attributesFile`, and `info/attributes`,
+    /// which takes precedence over all of them just like in Git.
+    ///
+    /// Note that it's not considered an error if the provided `attributes_file` or `info/attributes` do not exist.
+    /// `.gitattributes` files in the worktree are not loaded as they are relative to the directory containing them,
+    /// and are typically added while traversing the worktree.
+    ///
+    /// * `buf` is used to read files from disk.
+    /// * `collection` will be updated with information necessary to perform lookups later.
+    pub fn from_git_dir(
+        git_dir: &Path,
+        attributes_file: Option<PathBuf>,
+        buf: &mut Vec<u8>,
+        collection: &mut MetadataCollection,
+    ) -> std::io::Result<Self> {
+        Self::new_globals(
+            attributes_file
+                .into_iter()
+                .chain(Some(git_dir.join("info").join("attributes"))),
+            buf,
+            collection,
+        )
+    }
 }
 
 /// Mutation
@@ -117,12 +142,39 @@ impl Search {
         has_match
     }
 
+    /// Remember `case` for use in [`Cased::pattern_matching_relative_path_cased()`], typically derived from `core.ignoreCase`.
+    pub fn with_case(self, case: gix_glob::pattern::Case) -> Cased {
+        Cased { search: self, case }
+    }
+
     /// Return the amount of pattern lists contained in this instance.
     pub fn num_pattern_lists(&self) -> usize {
         self.patterns.len()
     }
 }
 
+/// A [`Search`] along with the case-sensitivity to use when matching, as created by [`Search::with_case()`].
+#[derive(PartialEq, Eq, Debug, Hash, Ord, PartialOrd, Clone, Default)]
+pub struct Cased {
+    /// The search to perform.
+    pub search: Search,
+    /// Whether cases should be folded during matching or not.
+    pub case: gix_glob::pattern::Case,
+}
+
+impl Cased {
+    /// Like [`Search::pattern_matching_relative_path()`], but uses the case-sensitivity stored in this instance.
+    pub fn pattern_matching_relative_path_cased(
+        &self,
+        relative_path: &BStr,
+        is_dir: Option<bool>,
+        out: &mut Outcome,
+    ) -> bool {
+        self.search
+            .pattern_matching_relative_path(relative_path, self.case, is_dir, out)
+    }
+}
+
 impl Pattern for Attributes {
     type Value = Value;
 
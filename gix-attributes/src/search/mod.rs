@@ -6,6 +6,7 @@ use smallvec::SmallVec;
 use crate::{Assignment, AssignmentRef};
 
 mod attributes;
+pub use attributes::Cased;
 mod outcome;
 mod refmap;
 pub(crate) use refmap::RefMap;
@@ -3,7 +3,7 @@ use std::collections::BTreeMap;
 use bstr::{BStr, ByteSlice};
 use gix_attributes::{
     AssignmentRef, NameRef, StateRef,
-    search::{AttributeId, Outcome},
+    search::{AttributeId, MetadataCollection, Outcome},
 };
 use gix_glob::pattern::Case;
 use gix_testtools::size_ok;
@@ -39,6 +39,29 @@ mod specials {
         );
     }
 
+    #[test]
+    fn stored_case_is_used_by_cased_lookups() {
+        let mut search = Search::default();
+        let mut collection = MetadataCollection::default();
+        search.add_patterns_buffer(b"simple test", "<memory>".into(), None, &mut collection, true);
+        let mut out = Outcome::default();
+        out.initialize(&collection);
+        let cased = search.clone().with_case(Default::default());
+        assert_eq!(cased.case, Case::Sensitive, "the default");
+        assert!(!cased.pattern_matching_relative_path_cased("Simple".into(), None, &mut out));
+
+        let cased = search.with_case(Case::Fold);
+        out.reset();
+        assert!(cased.pattern_matching_relative_path_cased("Simple".into(), None, &mut out));
+        out.reset();
+        assert!(
+            !cased
+                .search
+                .pattern_matching_relative_path("Simple".into(), Case::Sensitive, None, &mut out),
+            "the case can still be overridden per lookup"
+        );
+    }
+
     fn search_case(pattern: &str, path: &str, rela_containing_dir: Option<&str>, case: Case) -> bool {
         let mut search = Search::default();
         let mut collection = MetadataCollection::default();
@@ -300,6 +323,45 @@ fn macro_attributes_expand_only_when_macro_is_set() -> crate::Result {
     Ok(())
 }
 
+#[test]
+fn from_git_dir_gives_info_attributes_precedence_over_the_attributes_file() -> crate::Result {
+    let git_dir = gix_testtools::tempfile::TempDir::new()?;
+    std::fs::create_dir(git_dir.path().join("info"))?;
+    std::fs::write(git_dir.path().join("info").join("attributes"), "*.txt text diff=info\n")?;
+    let attributes_file = git_dir.path().join("global-attributes");
+    std::fs::write(&attributes_file, "*.txt -text binary\n")?;
+
+    let mut buf = Vec::new();
+    let mut collection = MetadataCollection::default();
+    let group = gix_attributes::Search::from_git_dir(git_dir.path(), Some(attributes_file), &mut buf, &mut collection)?;
+    let mut out = Outcome::default();
+    out.initialize(&collection);
+    group.pattern_matching_relative_path("a.txt".into(), Case::Sensitive, Some(false), &mut out);
+    let actual = by_name(out.iter().map(|m| m.assignment).collect());
+    let state = |name: &str| actual[&NameRef::try_from(BStr::new(name)).expect("valid")];
+    assert_eq!(
+        state("text"),
+        StateRef::Set,
+        "info/attributes has the highest precedence"
+    );
+    assert_eq!(state("diff"), StateRef::from_bytes(b"info"));
+    assert_eq!(state("binary"), StateRef::Set, "the attributes file is used as well");
+    assert_eq!(state("merge"), StateRef::Unset, "the built-in binary macro is expanded");
+
+    let group = gix_attributes::Search::from_git_dir(
+        git_dir.path(),
+        Some(git_dir.path().join("does-not-exist")),
+        &mut buf,
+        &mut collection,
+    )?;
+    assert_eq!(
+        group.num_pattern_lists(),
+        2,
+        "a missing attributes file is ignored, leaving only built-ins and info/attributes"
+    );
+    Ok(())
+}
+
 #[test]
 fn size_of_outcome() {
     let actual = std::mem::size_of::<Outcome>();
@@ -33,6 +33,27 @@ pub enum Error {
         "Invalid line range was given, line range is expected to be a 1-based inclusive range in the format '<start>,<end>'"
     )]
     InvalidOneBasedLineRange,
+    #[error("No function whose name matches '{name}' was found")]
+    FuncNameNotFound {
+        /// The pattern of the function name that wasn't found.
+        name: BString,
+    },
+    #[error("The function name '{name}' isn't a valid regular expression")]
+    InvalidFuncName {
+        /// The invalid pattern of the function name.
+        name: BString,
+        /// The reason the pattern couldn't be compiled.
+        source: regex::Error,
+    },
+    #[error("The first commit {start} isn't on the first-parent chain of the end commit {end}")]
+    ForwardEndNotReachable {
+        /// The commit to start the forward traversal at.
+        start: gix_hash::ObjectId,
+        /// The commit to end the forward traversal at.
+        end: gix_hash::ObjectId,
+    },
+    #[error("Interrupted by user")]
+    Interrupted,
     #[error("Failure to decode commit during traversal")]
     DecodeCommit(#[from] gix_object::decode::Error),
     #[error("Failed to get parent from commitgraph during traversal")]
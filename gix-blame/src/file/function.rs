@@ -1,6 +1,11 @@
-use std::num::NonZeroU32;
+use std::{
+    num::NonZeroU32,
+    ops::Range,
+    sync::atomic::{AtomicBool, Ordering},
+};
 
 use gix_diff::{blob::TokenSource, tree::Visit};
+use gix_features::progress::Progress;
 use gix_hash::ObjectId;
 use gix_object::{
     FindExt,
@@ -10,7 +15,10 @@ use gix_traverse::commit::find as find_commit;
 use smallvec::SmallVec;
 
 use super::{Change, UnblamedHunk, process_changes};
-use crate::{BlameEntry, Error, Options, Outcome, Statistics, types::BlamePathEntry};
+use crate::{
+    BlameDirection, BlameEntry, BlameRanges, CopyDetection, DateKind, Error, Options, Outcome, Statistics,
+    types::BlamePathEntry,
+};
 
 /// Produce a list of consecutive [`BlameEntry`] instances to indicate in which commits the ranges of the file
 /// at `suspect:<file_path>` originated in.
@@ -30,6 +38,10 @@ use crate::{BlameEntry, Error, Options, Outcome, Statistics, types::BlamePathEnt
 ///    - A *slash-separated* worktree-relative path to the file to blame.
 /// * `options`
 ///    - An instance of [`Options`].
+/// * `progress`
+///    - Incremented for each commit that is traversed. Pass [`gix_features::progress::Discard`] if not needed.
+/// * `should_interrupt`
+///    - If set, the operation stops with [`Error::Interrupted`] as soon as possible.
 ///
 /// ## The algorithm
 ///
@@ -60,6 +72,7 @@ use crate::{BlameEntry, Error, Options, Outcome, Statistics, types::BlamePathEnt
 /// <---><----------><-------><-----><------->
 /// <---><---><-----><-------><-----><------->
 /// <---><---><-----><-------><-----><-><-><->
+#[allow(clippy::too_many_arguments)]
 pub fn file(
     odb: impl gix_object::Find + gix_object::FindHeader,
     suspect: ObjectId,
@@ -67,19 +80,176 @@ pub fn file(
     resource_cache: &mut gix_diff::blob::Platform,
     file_path: &BStr,
     options: Options,
+    progress: &mut dyn Progress,
+    should_interrupt: &AtomicBool,
+) -> Result<Outcome, Error> {
+    file_incremental(
+        odb,
+        suspect,
+        cache,
+        resource_cache,
+        file_path,
+        options,
+        progress,
+        should_interrupt,
+        |_| {},
+    )
+}
+
+/// Like [`file()`], but call `on_entry` with each [`BlameEntry`] as soon as it's known during the traversal, similar to
+/// `git blame --incremental`.
+///
+/// Each entry is passed exactly once, but not necessarily in the order of the lines in the *Blamed File*.
+/// Note that the entries of the returned [`Outcome`] may differ as adjacent entries are coalesced only once the
+/// traversal is complete.
+#[allow(clippy::too_many_arguments)]
+pub fn file_incremental(
+    odb: impl gix_object::Find + gix_object::FindHeader,
+    suspect: ObjectId,
+    cache: Option<gix_commitgraph::Graph>,
+    resource_cache: &mut gix_diff::blob::Platform,
+    file_path: &BStr,
+    options: Options,
+    progress: &mut dyn Progress,
+    should_interrupt: &AtomicBool,
+    mut on_entry: impl FnMut(&BlameEntry),
 ) -> Result<Outcome, Error> {
     let _span = gix_trace::coarse!("gix_blame::file()", ?file_path, ?suspect);
+    progress.init(None, gix_features::progress::count("commits"));
+    file_at(
+        &odb,
+        suspect,
+        cache.as_ref(),
+        resource_cache,
+        file_path,
+        &options,
+        progress,
+        should_interrupt,
+        &mut on_entry,
+    )
+}
+
+/// Like [`file()`], but blame `contents` as the *Blamed File* instead of the file at `<suspect>:<file_path>`, which is useful
+/// to blame files with uncommitted modifications.
+///
+/// `contents` is diffed against the file at `<suspect>:<file_path>` of each of the `suspects` first, as if they were the
+/// parents of a commit with `contents`. This allows to blame the result of an in-progress merge by passing all commits
+/// that are merged. Lines that aren't present in any of them are attributed to the null id of the suspects' hash kind,
+/// like Git does for lines that are *Not Committed Yet*. All other lines are blamed as usual, traversing the ancestors of
+/// all `suspects` at once.
+///
+/// `contents` is expected to be in the form it would be stored in the object database, i.e. after worktree-to-git conversion.
+/// Note that [`Options::direction`] is ignored as `contents` can only be blamed backward, and that [`Error::EmptyTraversal`]
+/// is returned if there is no suspect.
+#[allow(clippy::too_many_arguments)]
+pub fn file_with_contents(
+    odb: impl gix_object::Find + gix_object::FindHeader,
+    suspects: impl IntoIterator<Item = ObjectId>,
+    cache: Option<gix_commitgraph::Graph>,
+    resource_cache: &mut gix_diff::blob::Platform,
+    file_path: &BStr,
+    contents: &[u8],
+    options: Options,
+    progress: &mut dyn Progress,
+    should_interrupt: &AtomicBool,
+) -> Result<Outcome, Error> {
+    let suspects = suspects.into_iter().fold(Vec::new(), |mut suspects, suspect| {
+        if !suspects.contains(&suspect) {
+            suspects.push(suspect);
+        }
+        suspects
+    });
+    let _span = gix_trace::coarse!("gix_blame::file_with_contents()", ?file_path, ?suspects);
+    let not_committed_yet = suspects.first().ok_or(Error::EmptyTraversal)?.kind().null();
+    progress.init(None, gix_features::progress::count("commits"));
 
     let mut stats = Statistics::default();
-    let (mut buf, mut buf2, mut buf3) = (Vec::new(), Vec::new(), Vec::new());
-    let blamed_file_entry_id = find_path_entry_in_commit(
+    let num_lines_in_blamed = tokens_for_diffing(contents).tokenize().count() as u32;
+    // Binary or otherwise empty?
+    if num_lines_in_blamed == 0 {
+        return Ok(Outcome::default());
+    }
+
+    let mut hunks_to_blame = options
+        .ranges
+        .to_zero_based_exclusive_ranges(num_lines_in_blamed)
+        .into_iter()
+        .map(|range| UnblamedHunk::new(range, not_committed_yet))
+        .collect::<Vec<_>>();
+
+    let (mut buf, mut buf2) = (Vec::new(), Vec::new());
+    for suspect in &suspects {
+        let entry = find_path_entry_in_commit(
+            &odb,
+            suspect,
+            file_path,
+            cache.as_ref(),
+            &mut buf,
+            &mut buf2,
+            &mut stats,
+        )?;
+        if let Some(entry_id) = entry {
+            let committed_blob = odb.find_blob(&entry_id, &mut buf)?.data;
+            let changes = changes_between(committed_blob, contents, &options);
+            stats.blobs_diffed += 1;
+            hunks_to_blame = process_changes(hunks_to_blame, changes, not_committed_yet, *suspect);
+        }
+    }
+
+    let mut out = Vec::new();
+    hunks_to_blame.retain_mut(|unblamed_hunk| {
+        if unblamed_hunk.suspects.len() == 1 {
+            if let Some(entry) = BlameEntry::from_unblamed_hunk(unblamed_hunk, not_committed_yet) {
+                out.push(entry);
+                return false;
+            }
+        }
+        unblamed_hunk.remove_blame(not_committed_yet);
+        true
+    });
+    let (entries, blame_path) = blame_backward(
         &odb,
-        &suspect,
-        file_path,
+        &suspects,
         cache.as_ref(),
-        &mut buf,
-        &mut buf2,
+        resource_cache,
+        file_path,
+        &options,
+        contents,
+        hunks_to_blame,
+        out,
         &mut stats,
+        progress,
+        should_interrupt,
+        &mut |_| {},
+    )?;
+    Ok(Outcome {
+        entries: coalesce_blame_entries(entries),
+        blob: contents.to_vec(),
+        statistics: stats,
+        blame_path,
+    })
+}
+
+/// Like [`file()`], but usable recursively to blame the lines that were copied from other files.
+#[allow(clippy::too_many_arguments)]
+fn file_at<O>(
+    odb: &O,
+    suspect: ObjectId,
+    cache: Option<&gix_commitgraph::Graph>,
+    resource_cache: &mut gix_diff::blob::Platform,
+    file_path: &BStr,
+    options: &Options,
+    progress: &mut dyn Progress,
+    should_interrupt: &AtomicBool,
+    on_entry: &mut dyn FnMut(&BlameEntry),
+) -> Result<Outcome, Error>
+where
+    O: gix_object::Find + gix_object::FindHeader,
+{
+    let mut stats = Statistics::default();
+    let (mut buf, mut buf2) = (Vec::new(), Vec::new());
+    let blamed_file_entry_id = find_path_entry_in_commit(
+        odb, &suspect, file_path, cache, &mut buf, &mut buf2, &mut stats,
     )?
     .ok_or_else(|| Error::FileMissing {
         file_path: file_path.to_owned(),
@@ -94,18 +264,89 @@ pub fn file(
     }
 
     let ranges_to_blame = options.ranges.to_zero_based_exclusive_ranges(num_lines_in_blamed);
-    let mut hunks_to_blame = ranges_to_blame
+    let hunks_to_blame = ranges_to_blame
         .into_iter()
         .map(|range| UnblamedHunk::new(range, suspect))
         .collect::<Vec<_>>();
 
-    let (mut buf, mut buf2) = (Vec::new(), Vec::new());
-    let commit = find_commit(cache.as_ref(), &odb, &suspect, &mut buf)?;
+    if let BlameDirection::Forward { end } = options.direction {
+        let out = blame_forward(
+            odb,
+            suspect,
+            end,
+            cache,
+            resource_cache,
+            file_path,
+            options,
+            hunks_to_blame,
+            &mut stats,
+            progress,
+            should_interrupt,
+            on_entry,
+        )?;
+        return Ok(Outcome {
+            entries: coalesce_blame_entries(out),
+            blob: blamed_file_blob,
+            statistics: stats,
+            blame_path: None,
+        });
+    }
+
+    let (entries, blame_path) = blame_backward(
+        odb,
+        &[suspect],
+        cache,
+        resource_cache,
+        file_path,
+        options,
+        &blamed_file_blob,
+        hunks_to_blame,
+        Vec::new(),
+        &mut stats,
+        progress,
+        should_interrupt,
+        on_entry,
+    )?;
+    Ok(Outcome {
+        entries: coalesce_blame_entries(entries),
+        blob: blamed_file_blob,
+        statistics: stats,
+        blame_path,
+    })
+}
+
+/// Attribute the lines of `hunks_to_blame`, which refer to `blamed_file_blob`, to the commits that introduced them,
+/// traversing the ancestors of all `suspects` and adding the resulting entries to `out`.
+///
+/// Return all entries of `out`, sorted by their position in the *Blamed File*, along with the path the
+/// traversal has taken if [`Options::debug_track_path`] is set.
+#[allow(clippy::too_many_arguments)]
+fn blame_backward<O>(
+    odb: &O,
+    suspects: &[ObjectId],
+    cache: Option<&gix_commitgraph::Graph>,
+    resource_cache: &mut gix_diff::blob::Platform,
+    file_path: &BStr,
+    options: &Options,
+    blamed_file_blob: &[u8],
+    mut hunks_to_blame: Vec<UnblamedHunk>,
+    mut out: Vec<BlameEntry>,
+    stats: &mut Statistics,
+    progress: &mut dyn Progress,
+    should_interrupt: &AtomicBool,
+    on_entry: &mut dyn FnMut(&BlameEntry),
+) -> Result<(Vec<BlameEntry>, Option<Vec<BlamePathEntry>>), Error>
+where
+    O: gix_object::Find + gix_object::FindHeader,
+{
+    let (mut buf, mut buf2, mut buf3) = (Vec::new(), Vec::new(), Vec::new());
     let mut queue: gix_revwalk::PriorityQueue<gix_date::SecondsSinceUnixEpoch, ObjectId> =
         gix_revwalk::PriorityQueue::new();
-    queue.insert(commit.commit_time()?, suspect);
+    for suspect in suspects {
+        let commit = find_commit(cache, odb, suspect, &mut buf)?;
+        queue.insert(commit.commit_time()?, *suspect);
+    }
 
-    let mut out = Vec::new();
     let mut diff_state = gix_diff::tree::State::default();
     let mut previous_entry: Option<(ObjectId, ObjectId)> = None;
     let mut blame_path = if options.debug_track_path {
@@ -114,8 +355,14 @@ pub fn file(
         None
     };
 
+    let mut num_emitted = 0;
     'outer: while let Some(suspect) = queue.pop_value() {
+        emit_new_entries(&out, &mut num_emitted, on_entry);
+        if should_interrupt.load(Ordering::Relaxed) {
+            return Err(Error::Interrupted);
+        }
         stats.commits_traversed += 1;
+        progress.inc();
         if hunks_to_blame.is_empty() {
             break;
         }
@@ -132,11 +379,19 @@ pub fn file(
             .clone()
             .unwrap_or_else(|| file_path.to_owned());
 
-        let commit = find_commit(cache.as_ref(), &odb, &suspect, &mut buf)?;
-        let commit_time = commit.commit_time()?;
+        let commit = find_commit(cache, odb, &suspect, &mut buf)?;
 
         if let Some(since) = options.since {
-            if commit_time < since.seconds {
+            let time = match options.since_uses {
+                DateKind::Committer => Some(commit.commit_time()?),
+                // Commits without a parseable author date are never considered to be before `since`.
+                DateKind::Author => odb
+                    .find_commit_iter(&suspect, &mut buf3)?
+                    .author()
+                    .ok()
+                    .map(|author| author.seconds()),
+            };
+            if time.is_some_and(|time| time < since.seconds) {
                 if unblamed_to_out_is_done(&mut hunks_to_blame, &mut out, suspect) {
                     break 'outer;
                 }
@@ -145,7 +400,7 @@ pub fn file(
             }
         }
 
-        let parent_ids: ParentIds = collect_parents(commit, &odb, cache.as_ref(), &mut buf2)?;
+        let parent_ids: ParentIds = collect_parents(commit, odb, cache, &mut buf2)?;
 
         if parent_ids.is_empty() {
             if queue.is_empty() {
@@ -185,13 +440,13 @@ pub fn file(
             .map(|(_, entry)| entry);
         if entry.is_none() {
             entry = find_path_entry_in_commit(
-                &odb,
+                odb,
                 &suspect,
                 current_file_path.as_ref(),
-                cache.as_ref(),
+                cache,
                 &mut buf,
                 &mut buf2,
-                &mut stats,
+                stats,
             )?;
         }
 
@@ -200,7 +455,7 @@ pub fn file(
         };
 
         // This block asserts that, for every `UnblamedHunk`, all lines in the *Blamed File* are
-        // identical to the corresponding lines in the *Source File*.
+        // identical to the corresponding lines in the *Source File*, unless whitespace is ignored.
         #[cfg(debug_assertions)]
         {
             let source_blob = odb.find_blob(&entry_id, &mut buf)?.data.to_vec();
@@ -211,7 +466,7 @@ pub fn file(
                 .collect();
 
             let mut blamed_interner = gix_diff::blob::Interner::new(blamed_file_blob.len() / 100);
-            let blamed_lines_as_tokens: Vec<_> = tokens_for_diffing(&blamed_file_blob)
+            let blamed_lines_as_tokens: Vec<_> = tokens_for_diffing(blamed_file_blob)
                 .tokenize()
                 .map(|token| blamed_interner.intern(token))
                 .collect();
@@ -220,13 +475,20 @@ pub fn file(
                 if let Some(range_in_suspect) = hunk.get_range(&suspect) {
                     let range_in_blamed_file = hunk.range_in_blamed_file.clone();
 
+                    let normalize = |line: &[u8]| {
+                        BString::new(if options.ignore_whitespace {
+                            without_whitespace(line)
+                        } else {
+                            line.into()
+                        })
+                    };
                     let source_lines = range_in_suspect
                         .clone()
-                        .map(|i| BString::new(source_interner[source_lines_as_tokens[i as usize]].into()))
+                        .map(|i| normalize(source_interner[source_lines_as_tokens[i as usize]]))
                         .collect::<Vec<_>>();
                     let blamed_lines = range_in_blamed_file
                         .clone()
-                        .map(|i| BString::new(blamed_interner[blamed_lines_as_tokens[i as usize]].into()))
+                        .map(|i| normalize(blamed_interner[blamed_lines_as_tokens[i as usize]]))
                         .collect::<Vec<_>>();
 
                     assert_eq!(source_lines, blamed_lines);
@@ -236,13 +498,13 @@ pub fn file(
 
         for (pid, (parent_id, parent_commit_time)) in parent_ids.iter().enumerate() {
             if let Some(parent_entry_id) = find_path_entry_in_commit(
-                &odb,
+                odb,
                 parent_id,
                 current_file_path.as_ref(),
-                cache.as_ref(),
+                cache,
                 &mut buf,
                 &mut buf2,
-                &mut stats,
+                stats,
             )? {
                 let no_change_in_entry = entry_id == parent_entry_id;
                 if pid == 0 {
@@ -260,12 +522,12 @@ pub fn file(
         for (index, (parent_id, parent_commit_time)) in parent_ids.iter().enumerate() {
             queue.insert(*parent_commit_time, *parent_id);
             let changes_for_file_path = tree_diff_at_file_path(
-                &odb,
+                odb,
                 current_file_path.as_ref(),
                 suspect,
                 *parent_id,
-                cache.as_ref(),
-                &mut stats,
+                cache,
+                stats,
                 &mut diff_state,
                 resource_cache,
                 &mut buf,
@@ -292,20 +554,37 @@ pub fn file(
                         // Do nothing under the assumption that this always (or almost always)
                         // implies that the file comes from a different parent, compared to which
                         // it was modified, not added.
-                    } else if unblamed_to_out_is_done(&mut hunks_to_blame, &mut out, suspect) {
-                        if let Some(ref mut blame_path) = blame_path {
-                            let blame_path_entry = BlamePathEntry {
-                                source_file_path: current_file_path.clone(),
-                                previous_source_file_path: None,
-                                commit_id: suspect,
-                                blob_id: id,
-                                previous_blob_id: gix_hash::Kind::shortest().null(),
-                                parent_index: index,
-                            };
-                            blame_path.push(blame_path_entry);
+                    } else {
+                        blame_copies(
+                            odb,
+                            suspect,
+                            &parent_ids[index..=index],
+                            current_file_path.as_ref(),
+                            id,
+                            cache,
+                            resource_cache,
+                            options,
+                            &mut hunks_to_blame,
+                            &mut out,
+                            stats,
+                            progress,
+                            should_interrupt,
+                        )?;
+                        if unblamed_to_out_is_done(&mut hunks_to_blame, &mut out, suspect) {
+                            if let Some(ref mut blame_path) = blame_path {
+                                let blame_path_entry = BlamePathEntry {
+                                    source_file_path: current_file_path.clone(),
+                                    previous_source_file_path: None,
+                                    commit_id: suspect,
+                                    blob_id: id,
+                                    previous_blob_id: gix_hash::Kind::shortest().null(),
+                                    parent_index: index,
+                                };
+                                blame_path.push(blame_path_entry);
+                            }
+
+                            break 'outer;
                         }
-
-                        break 'outer;
                     }
                 }
                 TreeDiffChange::Deletion => {
@@ -313,14 +592,14 @@ pub fn file(
                 }
                 TreeDiffChange::Modification { previous_id, id } => {
                     let changes = blob_changes(
-                        &odb,
+                        odb,
                         resource_cache,
                         id,
                         previous_id,
                         file_path,
                         file_path,
-                        options.diff_algorithm,
-                        &mut stats,
+                        options,
+                        stats,
                     )?;
                     hunks_to_blame = process_changes(hunks_to_blame, changes.clone(), suspect, *parent_id);
                     if let Some(ref mut blame_path) = blame_path {
@@ -345,14 +624,14 @@ pub fn file(
                     id,
                 } => {
                     let changes = blob_changes(
-                        &odb,
+                        odb,
                         resource_cache,
                         id,
                         source_id,
                         file_path,
                         source_location.as_ref(),
-                        options.diff_algorithm,
-                        &mut stats,
+                        options,
+                        stats,
                     )?;
                     hunks_to_blame = process_changes(hunks_to_blame, changes, suspect, *parent_id);
 
@@ -383,6 +662,22 @@ pub fn file(
             }
         }
 
+        blame_copies(
+            odb,
+            suspect,
+            &parent_ids,
+            current_file_path.as_ref(),
+            entry_id,
+            cache,
+            resource_cache,
+            options,
+            &mut hunks_to_blame,
+            &mut out,
+            stats,
+            progress,
+            should_interrupt,
+        )?;
+
         hunks_to_blame.retain_mut(|unblamed_hunk| {
             if unblamed_hunk.suspects.len() == 1 {
                 if let Some(entry) = BlameEntry::from_unblamed_hunk(unblamed_hunk, suspect) {
@@ -404,16 +699,149 @@ pub fn file(
         vec![],
         "only if there is no portion of the file left we have completed the blame"
     );
+    emit_new_entries(&out, &mut num_emitted, on_entry);
 
     // I don’t know yet whether it would make sense to use a data structure instead that preserves
     // order on insertion.
     out.sort_by_key(|a| a.start_in_blamed_file);
-    Ok(Outcome {
-        entries: coalesce_blame_entries(out),
-        blob: blamed_file_blob,
-        statistics: stats,
-        blame_path,
-    })
+    Ok((out, blame_path))
+}
+
+/// Attribute the lines of `hunks_to_blame`, which refer to the file at `start:<file_path>`, to the last commit in which
+/// they were still present, following the first-parent chain from `start` to `end`.
+///
+/// This works like the backward traversal, but each commit is diffed against its successor as if it was its parent,
+/// so that lines which are changed or deleted in the successor remain with the commit.
+#[allow(clippy::too_many_arguments)]
+fn blame_forward(
+    odb: impl gix_object::Find + gix_object::FindHeader,
+    start: ObjectId,
+    end: ObjectId,
+    cache: Option<&gix_commitgraph::Graph>,
+    resource_cache: &mut gix_diff::blob::Platform,
+    file_path: &BStr,
+    options: &Options,
+    mut hunks_to_blame: Vec<UnblamedHunk>,
+    stats: &mut Statistics,
+    progress: &mut dyn Progress,
+    should_interrupt: &AtomicBool,
+    on_entry: &mut dyn FnMut(&BlameEntry),
+) -> Result<Vec<BlameEntry>, Error> {
+    let (mut buf, mut buf2, mut buf3) = (Vec::new(), Vec::new(), Vec::new());
+    let mut commits = vec![end];
+    let mut current = end;
+    while current != start {
+        let commit = find_commit(cache, &odb, &current, &mut buf)?;
+        current = collect_parents(commit, &odb, cache, &mut buf2)?
+            .first()
+            .map(|(parent_id, _)| *parent_id)
+            .ok_or(Error::ForwardEndNotReachable { start, end })?;
+        commits.push(current);
+    }
+    commits.reverse();
+
+    let mut out = Vec::new();
+    let mut diff_state = gix_diff::tree::State::default();
+    let mut current_file_path = file_path.to_owned();
+    let mut num_emitted = 0;
+    for pair in commits.windows(2) {
+        emit_new_entries(&out, &mut num_emitted, on_entry);
+        if should_interrupt.load(Ordering::Relaxed) {
+            return Err(Error::Interrupted);
+        }
+        let (suspect, successor) = (pair[0], pair[1]);
+        stats.commits_traversed += 1;
+        progress.inc();
+
+        let change = tree_diff_at_file_path(
+            &odb,
+            current_file_path.as_ref(),
+            suspect,
+            successor,
+            cache,
+            stats,
+            &mut diff_state,
+            resource_cache,
+            &mut buf,
+            &mut buf2,
+            &mut buf3,
+            options.rewrites,
+        )?;
+        match change {
+            None => pass_blame_from_to(suspect, successor, &mut hunks_to_blame),
+            Some(TreeDiffChange::Addition { .. }) => {
+                // The file doesn't exist in `successor`, so `suspect` is the last commit to have any of its lines.
+                unblamed_to_out_is_done(&mut hunks_to_blame, &mut out, suspect);
+                break;
+            }
+            Some(TreeDiffChange::Deletion) => {
+                unreachable!("We already found the file in `suspect`, so it can't be missing there")
+            }
+            Some(TreeDiffChange::Modification { previous_id, id }) => {
+                let changes = blob_changes(
+                    &odb,
+                    resource_cache,
+                    id,
+                    previous_id,
+                    current_file_path.as_ref(),
+                    current_file_path.as_ref(),
+                    options,
+                    stats,
+                )?;
+                hunks_to_blame = process_changes(hunks_to_blame, changes, suspect, successor);
+            }
+            Some(TreeDiffChange::Rewrite {
+                source_location,
+                source_id,
+                id,
+            }) => {
+                let changes = blob_changes(
+                    &odb,
+                    resource_cache,
+                    id,
+                    source_id,
+                    current_file_path.as_ref(),
+                    source_location.as_ref(),
+                    options,
+                    stats,
+                )?;
+                hunks_to_blame = process_changes(hunks_to_blame, changes, suspect, successor);
+                for hunk in hunks_to_blame.iter_mut() {
+                    if hunk.has_suspect(&successor) {
+                        hunk.source_file_name = Some(source_location.clone());
+                    }
+                }
+                current_file_path = source_location;
+            }
+        }
+
+        hunks_to_blame.retain_mut(|unblamed_hunk| {
+            if unblamed_hunk.suspects.len() == 1 {
+                if let Some(entry) = BlameEntry::from_unblamed_hunk(unblamed_hunk, suspect) {
+                    out.push(entry);
+                    return false;
+                }
+            }
+            unblamed_hunk.remove_blame(suspect);
+            true
+        });
+        if hunks_to_blame.is_empty() {
+            break;
+        }
+    }
+    unblamed_to_out_is_done(&mut hunks_to_blame, &mut out, end);
+    emit_new_entries(&out, &mut num_emitted, on_entry);
+
+    out.sort_by_key(|a| a.start_in_blamed_file);
+    Ok(out)
+}
+
+/// Pass all entries of `out` that were added since the last call to `on_entry`, keeping track of them with `num_emitted`.
+fn emit_new_entries(out: &[BlameEntry], num_emitted: &mut usize, on_entry: &mut dyn FnMut(&BlameEntry)) {
+    for entry in &out[*num_emitted..] {
+        on_entry(entry);
+    }
+    *num_emitted = out.len();
 }
 
 /// Pass ownership of each unblamed hunk of `from` to `to`.
@@ -444,6 +872,245 @@ fn unblamed_to_out_is_done(
     hunks_to_blame.is_empty()
 }
 
+/// Like Git, consider only blocks of lines with at least this many alphanumeric characters to be copies.
+const MIN_COPY_SCORE: usize = 40;
+
+/// A block of lines in the *Blamed File* that was copied from `source_file_path` in `commit_id`.
+struct CopiedLines {
+    range_in_blamed_file: Range<u32>,
+    commit_id: ObjectId,
+    source_file_path: BString,
+    range_in_source_file: Range<u32>,
+}
+
+/// Find the lines of hunks that only `suspect` is responsible for in other files of `parent_ids`, as configured by
+/// [`Options::copy_detection`], and blame them where they were copied from, placing the resulting entries into `out`.
+///
+/// `file_path` and `id` identify the *Source File* in `suspect`, whose own version in the parents is never considered.
+#[allow(clippy::too_many_arguments)]
+fn blame_copies<O>(
+    odb: &O,
+    suspect: ObjectId,
+    parent_ids: &[(ObjectId, i64)],
+    file_path: &BStr,
+    id: ObjectId,
+    cache: Option<&gix_commitgraph::Graph>,
+    resource_cache: &mut gix_diff::blob::Platform,
+    options: &Options,
+    hunks_to_blame: &mut Vec<UnblamedHunk>,
+    out: &mut Vec<BlameEntry>,
+    stats: &mut Statistics,
+    progress: &mut dyn Progress,
+    should_interrupt: &AtomicBool,
+) -> Result<(), Error>
+where
+    O: gix_object::Find + gix_object::FindHeader,
+{
+    if options.copy_detection == CopyDetection::Disabled {
+        return Ok(());
+    }
+    let (mut candidates, others): (Vec<_>, Vec<_>) = hunks_to_blame
+        .drain(..)
+        .partition(|hunk| hunk.suspects.len() == 1 && hunk.has_suspect(&suspect));
+    *hunks_to_blame = others;
+    if candidates.is_empty() {
+        return Ok(());
+    }
+
+    let mut buf = Vec::new();
+    let blob = odb.find_blob(&id, &mut buf)?.data.to_vec();
+    let lines: Vec<_> = tokens_for_diffing(&blob).tokenize().collect();
+    let copy_score = |range: &Range<u32>| {
+        lines[range.start as usize..range.end as usize]
+            .iter()
+            .map(|line| line.iter().filter(|b| b.is_ascii_alphanumeric()).count())
+            .sum::<usize>()
+    };
+
+    let mut copies = Vec::new();
+    'parents: for (parent_id, _) in parent_ids {
+        for (source_file_path, source_id) in copy_sources(odb, suspect, *parent_id, file_path, cache, options, stats)? {
+            let changes = blob_changes(
+                odb,
+                resource_cache,
+                id,
+                source_id,
+                file_path,
+                source_file_path.as_ref(),
+                options,
+                stats,
+            )?;
+            // Each unchanged range in the *Source File*, along with the line it starts at in the copy source.
+            let mut start_in_source_file = 0;
+            let unchanged: Vec<_> = changes
+                .into_iter()
+                .filter_map(|change| match change {
+                    Change::Unchanged(range) => {
+                        let start = start_in_source_file;
+                        start_in_source_file += range.len() as u32;
+                        Some((range, start))
+                    }
+                    Change::AddedOrReplaced(_, deleted) | Change::Deleted(_, deleted) => {
+                        start_in_source_file += deleted;
+                        None
+                    }
+                })
+                .collect();
+
+            let mut todo = std::mem::take(&mut candidates);
+            while let Some(hunk) = todo.pop() {
+                let range = hunk
+                    .get_range(&suspect)
+                    .expect("only hunks of the suspect are candidates")
+                    .clone();
+                let copied = unchanged.iter().find_map(|(unchanged, start_in_source_file)| {
+                    let common = range.start.max(unchanged.start)..range.end.min(unchanged.end);
+                    (!common.is_empty() && copy_score(&common) >= MIN_COPY_SCORE).then(|| {
+                        let start = start_in_source_file + (common.start - unchanged.start);
+                        (common.clone(), start..start + common.len() as u32)
+                    })
+                });
+                let Some((common, range_in_source_file)) = copied else {
+                    candidates.push(hunk);
+                    continue;
+                };
+
+                let hunk_start = range.start;
+                let to_blamed_file = |range: Range<u32>| {
+                    let start = hunk.range_in_blamed_file.start + (range.start - hunk_start);
+                    start..start + range.len() as u32
+                };
+                copies.push(CopiedLines {
+                    range_in_blamed_file: to_blamed_file(common.clone()),
+                    commit_id: *parent_id,
+                    source_file_path: source_file_path.clone(),
+                    range_in_source_file,
+                });
+                for remaining in [range.start..common.start, common.end..range.end] {
+                    if !remaining.is_empty() {
+                        todo.push(UnblamedHunk {
+                            range_in_blamed_file: to_blamed_file(remaining.clone()),
+                            suspects: [(suspect, remaining)].into(),
+                            source_file_name: hunk.source_file_name.clone(),
+                        });
+                    }
+                }
+            }
+            if candidates.is_empty() {
+                break 'parents;
+            }
+        }
+    }
+    hunks_to_blame.extend(candidates);
+
+    for copy in copies {
+        let outcome = file_at(
+            odb,
+            copy.commit_id,
+            cache,
+            resource_cache,
+            copy.source_file_path.as_ref(),
+            &Options {
+                ranges: BlameRanges::PartialFile(vec![copy.range_in_source_file.clone()]),
+                debug_track_path: false,
+                ..options.clone()
+            },
+            progress,
+            should_interrupt,
+            &mut |_| {},
+        )?;
+        stats.commits_traversed += outcome.statistics.commits_traversed;
+        stats.trees_decoded += outcome.statistics.trees_decoded;
+        stats.trees_diffed += outcome.statistics.trees_diffed;
+        stats.trees_diffed_with_rewrites += outcome.statistics.trees_diffed_with_rewrites;
+        stats.blobs_diffed += outcome.statistics.blobs_diffed;
+
+        out.extend(outcome.entries.into_iter().map(|mut entry| {
+            entry.start_in_blamed_file =
+                copy.range_in_blamed_file.start + (entry.start_in_blamed_file - copy.range_in_source_file.start);
+            entry
+                .source_file_name
+                .get_or_insert_with(|| copy.source_file_path.clone());
+            entry
+        }));
+    }
+    Ok(())
+}
+
+/// Return the path and id of all blobs in `parent_id` that lines of `file_path` in `suspect` may have been copied from,
+/// as configured by [`Options::copy_detection`].
+fn copy_sources(
+    odb: &impl gix_object::Find,
+    suspect: ObjectId,
+    parent_id: ObjectId,
+    file_path: &BStr,
+    cache: Option<&gix_commitgraph::Graph>,
+    options: &Options,
+    stats: &mut Statistics,
+) -> Result<Vec<(BString, ObjectId)>, Error> {
+    let (mut buf, mut buf2) = (Vec::new(), Vec::new());
+    let parent_tree_id = find_commit(cache, odb, &parent_id, &mut buf)?.tree_id()?;
+    let parent_tree_iter = odb.find_tree_iter(&parent_tree_id, &mut buf2)?;
+    stats.trees_decoded += 1;
+
+    let mut sources: Vec<_> = match options.copy_detection {
+        CopyDetection::Disabled => Vec::new(),
+        CopyDetection::SameCommit => {
+            let mut buf3 = Vec::new();
+            let tree_id = find_commit(cache, odb, &suspect, &mut buf)?.tree_id()?;
+            let tree_iter = odb.find_tree_iter(&tree_id, &mut buf3)?;
+            stats.trees_decoded += 1;
+
+            let mut recorder = gix_diff::tree::Recorder::default();
+            gix_diff::tree(
+                parent_tree_iter,
+                tree_iter,
+                gix_diff::tree::State::default(),
+                odb,
+                &mut recorder,
+            )?;
+            stats.trees_diffed += 1;
+            recorder
+                .records
+                .into_iter()
+                .filter_map(|change| {
+                    use gix_diff::tree::recorder::Change;
+                    match change {
+                        Change::Modification {
+                            previous_entry_mode,
+                            previous_oid,
+                            path,
+                            ..
+                        } => previous_entry_mode.is_blob().then_some((path, previous_oid)),
+                        Change::Deletion {
+                            entry_mode, oid, path, ..
+                        } => entry_mode.is_blob().then_some((path, oid)),
+                        Change::Addition { .. } => None,
+                    }
+                })
+                .collect()
+        }
+        CopyDetection::AllCommits => {
+            let mut recorder = gix_traverse::tree::Recorder::default();
+            gix_traverse::tree::breadthfirst(
+                parent_tree_iter,
+                gix_traverse::tree::breadthfirst::State::default(),
+                odb,
+                &mut recorder,
+            )
+            .map_err(|err| Error::Traverse(err.into()))?;
+            recorder
+                .records
+                .into_iter()
+                .filter(|entry| entry.mode.is_blob())
+                .map(|entry| (entry.filepath, entry.oid))
+                .collect()
+        }
+    };
+    sources.retain(|(path, _)| path != file_path);
+    Ok(sources)
+}
+
 /// This function merges adjacent blame entries. It merges entries that are adjacent both in the
 /// blamed file and in the source file that introduced them. This follows `git`’s
 /// behaviour. `libgit2`, as of 2024-09-19, only checks whether two entries are adjacent in the
@@ -755,11 +1422,9 @@ fn blob_changes(
     previous_oid: ObjectId,
     file_path: &BStr,
     previous_file_path: &BStr,
-    diff_algorithm: gix_diff::blob::Algorithm,
+    options: &Options,
     stats: &mut Statistics,
 ) -> Result<Vec<Change>, Error> {
-    use gix_diff::blob::Hunk;
-
     resource_cache.set_resource(
         previous_oid,
         gix_object::tree::EntryKind::Blob,
@@ -776,13 +1441,42 @@ fn blob_changes(
     )?;
 
     let outcome = resource_cache.prepare_diff()?;
-    let input = gix_diff::blob::InternedInput::new(
+    let changes = changes_between(
         outcome.old.data.as_slice().unwrap_or_default(),
         outcome.new.data.as_slice().unwrap_or_default(),
+        options,
     );
 
-    let mut diff = gix_diff::blob::Diff::compute(diff_algorithm, &input);
-    diff.postprocess_lines(&input);
+    stats.blobs_diffed += 1;
+    Ok(changes)
+}
+
+/// Return the changes that turn `old` into `new`, as seen from `new`, using the diff-related settings of `options`.
+fn changes_between(old: &[u8], new: &[u8], options: &Options) -> Vec<Change> {
+    if options.ignore_whitespace {
+        let mut input = gix_diff::blob::InternedInput::default();
+        input.update_before(tokens_for_diffing(old).tokenize().map(without_whitespace));
+        input.update_after(tokens_for_diffing(new).tokenize().map(without_whitespace));
+        changes_from_input(&input, options.diff_algorithm)
+    } else {
+        let input = gix_diff::blob::InternedInput::new(tokens_for_diffing(old), tokens_for_diffing(new));
+        changes_from_input(&input, options.diff_algorithm)
+    }
+}
+
+/// Return `line` with all whitespace removed, for comparing lines while ignoring whitespace.
+fn without_whitespace(line: &[u8]) -> Vec<u8> {
+    line.iter().copied().filter(|b| !b.is_ascii_whitespace()).collect()
+}
+
+fn changes_from_input<T: AsRef<[u8]>>(
+    input: &gix_diff::blob::InternedInput<T>,
+    diff_algorithm: gix_diff::blob::Algorithm,
+) -> Vec<Change> {
+    use gix_diff::blob::Hunk;
+
+    let mut diff = gix_diff::blob::Diff::compute(diff_algorithm, input);
+    diff.postprocess_lines(input);
 
     let mut last_seen_after_end = 0;
     let mut changes = diff.hunks().fold(Vec::new(), |mut hunks, hunk| {
@@ -815,9 +1509,7 @@ fn blob_changes(
     if input.after.len() > last_seen_after_end as usize {
         changes.push(Change::Unchanged(last_seen_after_end..total_number_of_lines));
     }
-
-    stats.blobs_diffed += 1;
-    Ok(changes)
+    changes
 }
 
 fn find_path_entry_in_commit(
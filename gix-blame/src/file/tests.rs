@@ -47,6 +47,50 @@ fn one_sha() -> ObjectId {
     ObjectId::from_str("1111111111111111111111111111111111111111").unwrap()
 }
 
+mod offset {
+    use crate::file::Offset;
+
+    #[test]
+    fn combine_and_as_signed() {
+        assert_eq!(Offset::Added(3).as_signed(), 3);
+        assert_eq!(Offset::Deleted(3).as_signed(), -3);
+
+        assert_eq!(Offset::Added(3).combine(Offset::Added(2)), Offset::Added(5));
+        assert_eq!(Offset::Added(3).combine(Offset::Deleted(5)), Offset::Deleted(2));
+        assert_eq!(Offset::Deleted(3).combine(Offset::Deleted(2)), Offset::Deleted(5));
+        assert_eq!(
+            Offset::Deleted(3).combine(Offset::Added(3)),
+            Offset::Added(0),
+            "no offset is always represented the same way"
+        );
+        assert_eq!(
+            Offset::Added(u32::MAX).combine(Offset::Added(1)),
+            Offset::Added(u32::MAX),
+            "results saturate"
+        );
+
+        let mut offset = Offset::Added(3);
+        offset -= 5;
+        assert_eq!(
+            Offset::Added(3).combine(Offset::Deleted(5)),
+            offset,
+            "it's consistent with the assignment operators"
+        );
+    }
+
+    #[test]
+    fn try_shifted_range() {
+        assert_eq!(Offset::Added(2).try_shifted_range(&(5..7)), Some(3..5));
+        assert_eq!(Offset::Deleted(2).try_shifted_range(&(5..7)), Some(7..9));
+        assert_eq!(
+            Offset::Added(6).try_shifted_range(&(5..7)),
+            None,
+            "ranges can't be shifted before the start of the file"
+        );
+        assert_eq!(Offset::Deleted(1).try_shifted_range(&(5..u32::MAX)), None);
+    }
+}
+
 mod process_change {
     use super::*;
     use crate::file::{Change, Offset, process_change};
@@ -1109,4 +1153,117 @@ mod blame_ranges {
 
         assert!(matches!(ranges, BlameRanges::WholeFile));
     }
+
+    #[test]
+    fn from_funcname() {
+        use crate::FuncNameDriver;
+
+        let blob = b"fn first() {\n    call();\n}\n\nfn second() {\r\n    call();\r\n}\n";
+        let ranges = BlameRanges::from_funcname("first".into(), blob, &FuncNameDriver::Default).unwrap();
+        assert_eq!(
+            ranges.to_zero_based_exclusive_ranges(8),
+            vec![0..4],
+            "the function ends right before the next one"
+        );
+
+        let ranges = BlameRanges::from_funcname("second".into(), blob, &FuncNameDriver::Default).unwrap();
+        assert_eq!(
+            ranges.to_zero_based_exclusive_ranges(8),
+            vec![4..7],
+            "the last function ends with the file"
+        );
+
+        let ranges = BlameRanges::from_funcname(
+            "call".into(),
+            blob,
+            &FuncNameDriver::Custom(|line| line.starts_with(b"    ")),
+        )
+        .unwrap();
+        assert_eq!(
+            ranges.to_zero_based_exclusive_ranges(8),
+            vec![1..5],
+            "custom drivers determine what a function header is"
+        );
+
+        assert!(matches!(
+            BlameRanges::from_funcname("call".into(), blob, &FuncNameDriver::Default),
+            Err(Error::FuncNameNotFound { name }) if name == "call"
+        ));
+    }
+
+    #[test]
+    fn from_funcname_is_a_regular_expression() {
+        use crate::FuncNameDriver;
+
+        let blob = b"fn main_loop() {
+}
+
+fn main() {
+}
+";
+        let ranges = BlameRanges::from_funcname("main".into(), blob, &FuncNameDriver::Default).unwrap();
+        assert_eq!(ranges.to_zero_based_exclusive_ranges(5), vec![0..3]);
+
+        let ranges = BlameRanges::from_funcname(r"main\(".into(), blob, &FuncNameDriver::Default).unwrap();
+        assert_eq!(
+            ranges.to_zero_based_exclusive_ranges(5),
+            vec![3..5],
+            "special characters in the pattern have their regex meaning"
+        );
+
+        let ranges = BlameRanges::from_funcname("^fn m.*p".into(), blob, &FuncNameDriver::Default).unwrap();
+        assert_eq!(
+            ranges.to_zero_based_exclusive_ranges(5),
+            vec![0..3],
+            "anchors are supported"
+        );
+        assert!(matches!(
+            BlameRanges::from_funcname("^main".into(), blob, &FuncNameDriver::Default),
+            Err(Error::FuncNameNotFound { name }) if name == "^main"
+        ));
+
+        assert!(matches!(
+            BlameRanges::from_funcname("main(".into(), blob, &FuncNameDriver::Default),
+            Err(Error::InvalidFuncName { name, .. }) if name == "main("
+        ));
+    }
+
+    #[test]
+    fn from_funcname_with_builtin_driver() {
+        use crate::FuncNameDriver;
+
+        let rust = FuncNameDriver::builtin("rust").expect("rust is a built-in driver");
+        let blob =
+            b"use std::fmt;\n\npub(crate) fn first() {\n    call();\n}\n\nimpl Type {\n    async fn second() {}\n}\n";
+        let ranges = BlameRanges::from_funcname("first".into(), blob, &rust).unwrap();
+        assert_eq!(
+            ranges.to_zero_based_exclusive_ranges(9),
+            vec![2..6],
+            "only lines matching the driver's patterns start a function"
+        );
+        let ranges = BlameRanges::from_funcname("second".into(), blob, &rust).unwrap();
+        assert_eq!(
+            ranges.to_zero_based_exclusive_ranges(9),
+            vec![7..9],
+            "indented functions are found"
+        );
+
+        let cpp = FuncNameDriver::builtin("cpp").expect("cpp is a built-in driver");
+        assert!(cpp.is_funcname_line("int main(void)".into()));
+        assert!(
+            !cpp.is_funcname_line("public:".into()),
+            "negated patterns reject lines that would match otherwise"
+        );
+
+        let css = FuncNameDriver::builtin("css").expect("css is a built-in driver");
+        assert!(css.is_funcname_line("BODY {".into()), "some drivers ignore case");
+
+        let java = FuncNameDriver::builtin("java").expect("java is a built-in driver");
+        assert!(
+            java.is_funcname_line("    public List<String>[] names(int a) {".into()),
+            "brackets within bracket expressions are literal, as in POSIX"
+        );
+
+        assert!(FuncNameDriver::builtin("unknown").is_none());
+    }
 }
@@ -17,7 +17,10 @@
 mod error;
 pub use error::Error;
 mod types;
-pub use types::{BlameEntry, BlamePathEntry, BlameRanges, Options, Outcome, Statistics};
+pub use types::{
+    BlameDirection, BlameEntry, BlamePathEntry, BlameRanges, CopyDetection, DateKind, FuncNameDriver, Offset, Options,
+    Outcome, Statistics,
+};
 
 mod file;
-pub use file::function::file;
+pub use file::function::{file, file_incremental, file_with_contents};
@@ -1,5 +1,6 @@
+use gix_diff::blob::TokenSource;
 use gix_hash::ObjectId;
-use gix_object::bstr::BString;
+use gix_object::bstr::{BStr, BString, ByteSlice};
 use smallvec::SmallVec;
 use std::ops::RangeInclusive;
 use std::{
@@ -81,6 +82,42 @@ impl BlameRanges {
         Ok(result)
     }
 
+    /// Create a range spanning the first function in `blob` whose header line matches the regular expression `name`,
+    /// similar to `git blame -L :<funcname>`.
+    ///
+    /// Function headers are the lines that `driver` identifies as such, and the range ends right before the next one,
+    /// or at the end of `blob` if there is none.
+    ///
+    /// ### Deviation
+    ///
+    /// Git compiles `name` as POSIX regular expression, while we use the syntax of the `regex` crate, which is the same
+    /// for typical patterns like `^main` or `parse_.*`.
+    pub fn from_funcname(name: &BStr, blob: &[u8], driver: &FuncNameDriver) -> Result<Self, Error> {
+        let regex = regex::bytes::RegexBuilder::new(&name.to_str_lossy())
+            .unicode(false)
+            .build()
+            .map_err(|source| Error::InvalidFuncName {
+                name: name.to_owned(),
+                source,
+            })?;
+        let lines: Vec<_> = tokens_for_diffing(blob)
+            .tokenize()
+            .map(|line| {
+                let line = line.strip_suffix(b"\n").unwrap_or(line);
+                line.strip_suffix(b"\r").unwrap_or(line).as_bstr()
+            })
+            .collect();
+        let start = lines
+            .iter()
+            .position(|line| driver.is_funcname_line(line) && regex.is_match(line))
+            .ok_or_else(|| Error::FuncNameNotFound { name: name.to_owned() })?;
+        let end = lines[start + 1..]
+            .iter()
+            .position(|line| driver.is_funcname_line(line))
+            .map_or(lines.len(), |pos| start + 1 + pos);
+        Self::from_one_based_inclusive_range(start as u32 + 1..=end as u32)
+    }
+
     /// Convert a 1-based inclusive range to a 0-based exclusive range.
     fn inclusive_to_zero_based_exclusive(range: RangeInclusive<u32>) -> Result<Range<u32>, Error> {
         if range.start() == &0 {
@@ -95,7 +132,8 @@ impl BlameRanges {
 impl BlameRanges {
     /// Add a single range to blame.
     ///
-    /// The new range will be merged with any overlapping existing ranges.
+    /// The new range will be merged with all overlapping or adjacent existing ranges, so a range that bridges
+    /// several of them collapses them into one.
     pub fn add_one_based_inclusive_range(&mut self, new_range: RangeInclusive<u32>) -> Result<(), Error> {
         let zero_based_range = Self::inclusive_to_zero_based_exclusive(new_range)?;
         self.merge_zero_based_exclusive_range(zero_based_range);
@@ -150,6 +188,83 @@ impl BlameRanges {
     }
 }
 
+/// Identifies lines that start a function, as used by [`BlameRanges::from_funcname()`].
+#[derive(Default, Debug, Clone)]
+pub enum FuncNameDriver {
+    /// The built-in rule that Git uses if no `xfuncname` is configured for a file, which matches all lines that
+    /// start with an alphabetic character, `_` or `$`.
+    #[default]
+    Default,
+    /// Regular expressions as configured with `diff.<driver>.xfuncname`, or provided by a built-in driver.
+    Patterns(gix_diff::blob::funcname::Patterns),
+    /// A custom function that returns `true` if the given line, without line terminator, starts a function.
+    Custom(fn(&BStr) -> bool),
+}
+
+impl FuncNameDriver {
+    /// Return the built-in diff driver called `name`, like `rust` or `python`, or `None` if Git doesn't have such a driver.
+    pub fn builtin(name: &str) -> Option<Self> {
+        gix_diff::blob::funcname::Patterns::builtin(name).map(FuncNameDriver::Patterns)
+    }
+
+    /// Return `true` if `line`, without line terminator, starts a function.
+    pub fn is_funcname_line(&self, line: &BStr) -> bool {
+        match self {
+            FuncNameDriver::Default => line
+                .first()
+                .is_some_and(|b| b.is_ascii_alphabetic() || *b == b'_' || *b == b'$'),
+            FuncNameDriver::Patterns(patterns) => patterns.is_match(line),
+            FuncNameDriver::Custom(is_funcname_line) => is_funcname_line(line),
+        }
+    }
+}
+
+/// The kind of date of a commit, used to compare it with [`Options::since`].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateKind {
+    /// The time at which the commit was authored, which is retained when rebasing.
+    Author,
+    /// The time at which the commit was created, which is what `git blame --since` uses.
+    #[default]
+    Committer,
+}
+
+/// The direction in which [`file()`](crate::file()) traverses the commit-graph.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlameDirection {
+    /// Traverse from the suspect towards its ancestors to find the commits that introduced each line, like `git blame` does.
+    #[default]
+    Backward,
+    /// Traverse from the suspect towards its descendant `end` to find the last commit in which each line was still present,
+    /// like `git blame --reverse <suspect>..<end>` does.
+    ///
+    /// ### Deviation
+    ///
+    /// Only the first-parent chain from `end` back to the suspect is traversed, like `git blame --reverse --first-parent` would.
+    Forward {
+        /// The commit to stop at, which must have the suspect on its first-parent chain.
+        end: ObjectId,
+    },
+}
+
+/// Where [`file()`](crate::file()) looks for lines that were copied from other files, similar to `git blame -C`.
+///
+/// Copied lines are attributed to the commit that introduced them into the file they were copied from,
+/// with [`BlameEntry::source_file_name`] set to the name of that file.
+/// Like in Git, only blocks of lines with at least 40 alphanumeric characters are considered copies.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CopyDetection {
+    /// Attribute copied lines to the commit that copied them.
+    #[default]
+    Disabled,
+    /// Look for lines copied from files that were modified in the same commit, like `git blame -C` does.
+    SameCommit,
+    /// Look for lines copied from any file in the parent commit, like `git blame -C -C -C` does.
+    ///
+    /// Note that this is expensive as every file in the parent commit has to be diffed.
+    AllCommits,
+}
+
 /// Options to be passed to [`file()`](crate::file()).
 #[derive(Default, Debug, Clone)]
 pub struct Options {
@@ -157,13 +272,79 @@ pub struct Options {
     pub diff_algorithm: gix_diff::blob::Algorithm,
     /// The ranges to blame in the file.
     pub ranges: BlameRanges,
-    /// Don't consider commits before the given date.
+    /// Don't consider commits before the given date, as determined by `since_uses`.
     pub since: Option<gix_date::Time>,
+    /// The date of each commit to compare with `since`, which defaults to the committer date like Git does.
+    pub since_uses: DateKind,
     /// Determine if rename tracking should be performed, and how.
     pub rewrites: Option<gix_diff::Rewrites>,
     /// Collect debug information whenever there's a diff or rename that affects the outcome of a
     /// blame.
     pub debug_track_path: bool,
+    /// The direction in which to traverse the commit-graph.
+    ///
+    /// Note that [`since`](Self::since) and [`debug_track_path`](Self::debug_track_path) are ignored
+    /// when traversing [forward](BlameDirection::Forward).
+    pub direction: BlameDirection,
+    /// Whether and where to look for lines that were copied from other files.
+    ///
+    /// Note that it's ignored when traversing [forward](BlameDirection::Forward).
+    pub copy_detection: CopyDetection,
+    /// If `true`, ignore all whitespace when comparing lines, like `git blame -w` does, so that commits which only
+    /// change whitespace don't take the blame.
+    pub ignore_whitespace: bool,
+}
+
+/// Builder
+impl Options {
+    /// Use `algorithm` for diffing.
+    pub fn with_diff_algorithm(mut self, algorithm: gix_diff::blob::Algorithm) -> Self {
+        self.diff_algorithm = algorithm;
+        self
+    }
+
+    /// Blame only the lines in `ranges`.
+    pub fn with_ranges(mut self, ranges: BlameRanges) -> Self {
+        self.ranges = ranges;
+        self
+    }
+
+    /// Don't consider commits before `since`, comparing it to the date of each commit as specified by `since_uses`.
+    pub fn with_since(mut self, since: gix_date::Time, since_uses: DateKind) -> Self {
+        self.since = Some(since);
+        self.since_uses = since_uses;
+        self
+    }
+
+    /// Perform rename tracking as configured by `rewrites`, or disable it if `None`.
+    pub fn with_rewrites(mut self, rewrites: Option<gix_diff::Rewrites>) -> Self {
+        self.rewrites = rewrites;
+        self
+    }
+
+    /// If `toggle` is `true`, collect debug information for each change that affects the outcome of the blame.
+    pub fn with_debug_track_path(mut self, toggle: bool) -> Self {
+        self.debug_track_path = toggle;
+        self
+    }
+
+    /// Traverse the commit-graph in the given `direction`.
+    pub fn with_direction(mut self, direction: BlameDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Look for lines copied from other files as specified by `copy_detection`.
+    pub fn with_copy_detection(mut self, copy_detection: CopyDetection) -> Self {
+        self.copy_detection = copy_detection;
+        self
+    }
+
+    /// If `toggle` is `true`, ignore all whitespace when comparing lines.
+    pub fn with_ignore_whitespace(mut self, toggle: bool) -> Self {
+        self.ignore_whitespace = toggle;
+        self
+    }
 }
 
 /// Represents a change during history traversal for blame. It is supposed to capture enough
@@ -274,6 +455,36 @@ impl Offset {
             },
         }
     }
+
+    /// Like [`shifted_range()`](Self::shifted_range()), but return `None` if `range` can't be shifted
+    /// without under- or overflowing, which can happen with malformed hunks.
+    pub fn try_shifted_range(&self, range: &Range<u32>) -> Option<Range<u32>> {
+        Some(match self {
+            Offset::Added(added) => range.start.checked_sub(*added)?..range.end.checked_sub(*added)?,
+            Offset::Deleted(deleted) => range.start.checked_add(*deleted)?..range.end.checked_add(*deleted)?,
+        })
+    }
+
+    /// Return the sum of this offset and `other`, with `Added` counting as positive and `Deleted` as negative.
+    ///
+    /// The result saturates at `u32::MAX` lines in either direction, and an offset of zero is `Added(0)`.
+    pub fn combine(self, other: Offset) -> Offset {
+        let sum = self.as_signed() + other.as_signed();
+        let lines = u32::try_from(sum.unsigned_abs()).unwrap_or(u32::MAX);
+        if sum < 0 {
+            Offset::Deleted(lines)
+        } else {
+            Offset::Added(lines)
+        }
+    }
+
+    /// Return this offset as signed number, with `Added` lines being positive and `Deleted` lines being negative.
+    pub fn as_signed(&self) -> i64 {
+        match self {
+            Offset::Added(added) => i64::from(*added),
+            Offset::Deleted(deleted) => -i64::from(*deleted),
+        }
+    }
 }
 
 impl AddAssign<u32> for Offset {
@@ -321,9 +532,16 @@ pub struct BlameEntry {
     /// The amount of lines the hunk is spanning.
     pub len: NonZeroU32,
     /// The commit that introduced the section into the *Source File*.
+    ///
+    /// When traversing [forward](BlameDirection::Forward), it's the last commit in which the section was still present
+    /// instead, which is the `end` commit if it was never removed.
     pub commit_id: ObjectId,
     /// The *Source File*'s name, in case it differs from *Blamed File*'s name.
-    /// This happens when the file was renamed.
+    /// This happens when the file was renamed, and is only known if [rewrites](Options::rewrites) are tracked,
+    /// or when the lines were copied from another file, which is only known if [copies](Options::copy_detection)
+    /// are detected.
+    ///
+    /// It is `None` if the *Source File* has the same name as the *Blamed File*.
     pub source_file_name: Option<BString>,
 }
 
@@ -366,6 +584,20 @@ impl BlameEntry {
         let start = self.start_in_source_file as usize;
         start..start + self.len.get() as usize
     }
+
+    /// Return the lines this entry spans in the *Blamed File* as 1-based inclusive range, as used by `git`.
+    pub fn one_based_blamed_range(&self) -> RangeInclusive<u32> {
+        Self::one_based_inclusive(self.start_in_blamed_file, self.len)
+    }
+
+    /// Return the lines this entry spans in the *Source File* as 1-based inclusive range, as used by `git`.
+    pub fn one_based_source_range(&self) -> RangeInclusive<u32> {
+        Self::one_based_inclusive(self.start_in_source_file, self.len)
+    }
+
+    fn one_based_inclusive(zero_based_start: u32, len: NonZeroU32) -> RangeInclusive<u32> {
+        zero_based_start + 1..=zero_based_start + len.get()
+    }
 }
 
 pub(crate) trait LineRange {
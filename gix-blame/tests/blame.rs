@@ -1,6 +1,5 @@
-use std::{collections::BTreeMap, path::PathBuf};
+use std::{collections::BTreeMap, path::PathBuf, sync::atomic::AtomicBool};
 
-use gix_blame::BlameRanges;
 use gix_hash::ObjectId;
 use gix_object::bstr;
 
@@ -233,6 +232,8 @@ impl Fixture {
             &mut self.resource_cache,
             source_file_name,
             options,
+            &mut gix_features::progress::Discard,
+            &AtomicBool::new(false),
         )
     }
 }
@@ -255,13 +256,11 @@ macro_rules! mktest {
                 None,
                 &mut resource_cache,
                 source_file_name.as_ref(),
-                gix_blame::Options {
-                    diff_algorithm: gix_diff::blob::Algorithm::Histogram,
-                    ranges: BlameRanges::default(),
-                    since: None,
-                    rewrites: Some(gix_diff::Rewrites::default()),
-                    debug_track_path: false,
-                },
+                gix_blame::Options::default()
+                    .with_diff_algorithm(gix_diff::blob::Algorithm::Histogram)
+                    .with_rewrites(Some(gix_diff::Rewrites::default())),
+                &mut gix_features::progress::Discard,
+                &AtomicBool::new(false),
             )?
             .entries;
 
@@ -344,13 +343,11 @@ fn diff_algorithm_parity() {
             None,
             &mut resource_cache,
             source_file_name.as_ref(),
-            gix_blame::Options {
-                diff_algorithm,
-                ranges: BlameRanges::default(),
-                since: None,
-                rewrites: Some(gix_diff::Rewrites::default()),
-                debug_track_path: false,
-            },
+            gix_blame::Options::default()
+                .with_diff_algorithm(diff_algorithm)
+                .with_rewrites(Some(gix_diff::Rewrites::default())),
+            &mut gix_features::progress::Discard,
+            &AtomicBool::new(false),
         )
         .unwrap()
         .entries;
@@ -382,6 +379,8 @@ fn file_that_was_added_in_two_branches() -> gix_testtools::Result {
         &mut resource_cache,
         source_file_name.into(),
         gix_blame::Options::default(),
+        &mut gix_features::progress::Discard,
+        &AtomicBool::new(false),
     )?
     .entries;
 
@@ -395,6 +394,166 @@ fn file_that_was_added_in_two_branches() -> gix_testtools::Result {
     Ok(())
 }
 
+#[test]
+fn with_contents_of_an_in_progress_merge() -> gix_testtools::Result {
+    let worktree_path = gix_testtools::scripted_fixture_read_only("make_blame_merge_in_progress_repo.sh")?;
+    let Fixture {
+        odb,
+        mut resource_cache,
+        suspect,
+    } = Fixture::for_worktree_path(worktree_path.to_path_buf())?;
+    let git_dir = worktree_path.join(".git");
+    let merge_head = ObjectId::from_hex(std::fs::read_to_string(git_dir.join("MERGE_HEAD"))?.trim().as_bytes())?;
+    let contents = std::fs::read(worktree_path.join("file.txt"))?;
+
+    let source_file_name: gix_object::bstr::BString = "file.txt".into();
+    let outcome = gix_blame::file_with_contents(
+        &odb,
+        [suspect, merge_head],
+        None,
+        &mut resource_cache,
+        source_file_name.as_ref(),
+        &contents,
+        gix_blame::Options::default().with_diff_algorithm(gix_diff::blob::Algorithm::Histogram),
+        &mut gix_features::progress::Discard,
+        &AtomicBool::new(false),
+    )?;
+
+    let baseline = Baseline::collect(git_dir.join("file.baseline"), source_file_name)?;
+    pretty_assertions::assert_eq!(outcome.entries, baseline);
+    assert!(
+        outcome.entries.iter().any(|entry| entry.commit_id == merge_head),
+        "lines from the merged commit are attributed to it"
+    );
+
+    let err = gix_blame::file_with_contents(
+        &odb,
+        None,
+        None,
+        &mut resource_cache,
+        "file.txt".into(),
+        &contents,
+        gix_blame::Options::default(),
+        &mut gix_features::progress::Discard,
+        &AtomicBool::new(false),
+    )
+    .unwrap_err();
+    assert!(
+        matches!(err, gix_blame::Error::EmptyTraversal),
+        "at least one suspect is needed: {err}"
+    );
+    Ok(())
+}
+
+#[test]
+fn ignore_whitespace() -> gix_testtools::Result {
+    let git_dir = fixture_path()?.join(".git");
+    for (ignore_whitespace, baseline) in [(false, "reindented"), (true, "reindented-ignore-whitespace")] {
+        let Fixture {
+            odb,
+            mut resource_cache,
+            suspect,
+        } = Fixture::new()?;
+
+        let source_file_name: gix_object::bstr::BString = "reindented.txt".into();
+        let outcome = gix_blame::file(
+            &odb,
+            suspect,
+            None,
+            &mut resource_cache,
+            source_file_name.as_ref(),
+            gix_blame::Options::default()
+                .with_diff_algorithm(gix_diff::blob::Algorithm::Histogram)
+                .with_ignore_whitespace(ignore_whitespace),
+            &mut gix_features::progress::Discard,
+            &AtomicBool::new(false),
+        )?;
+
+        let baseline = Baseline::collect(git_dir.join(format!("{baseline}.baseline")), source_file_name)?;
+        pretty_assertions::assert_eq!(outcome.entries, baseline, "ignore_whitespace = {ignore_whitespace}");
+        assert_eq!(
+            outcome.blob, b"fn main() {\n    let a = 1;\n    let b  =  2;\n    let c = 3;\n}\n",
+            "the blob is never normalized"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn incremental() -> gix_testtools::Result {
+    let Fixture {
+        odb,
+        mut resource_cache,
+        suspect,
+    } = Fixture::new()?;
+
+    let mut entries = Vec::new();
+    let outcome = gix_blame::file_incremental(
+        &odb,
+        suspect,
+        None,
+        &mut resource_cache,
+        "simple.txt".into(),
+        gix_blame::Options::default(),
+        &mut gix_features::progress::Discard,
+        &AtomicBool::new(false),
+        |entry| entries.push(entry.clone()),
+    )?;
+    assert_eq!(
+        entries.first().map(|entry| entry.start_in_blamed_file),
+        Some(3),
+        "the last line was added by the first commit that is traversed, so it's known first"
+    );
+
+    entries.sort_by_key(|entry| entry.start_in_blamed_file);
+    assert_eq!(
+        entries, outcome.entries,
+        "each entry is passed exactly once, and none of them can be coalesced here"
+    );
+    Ok(())
+}
+
+#[test]
+fn one_based_ranges_of_entries() {
+    let entry = gix_blame::BlameEntry::new(4..6, 0..2, fixture_hash_kind().null(), None);
+    assert_eq!(entry.one_based_blamed_range(), 5..=6);
+    assert_eq!(entry.one_based_source_range(), 1..=2);
+
+    let single_line = gix_blame::BlameEntry::new(0..1, 9..10, fixture_hash_kind().null(), None);
+    assert_eq!(
+        single_line.one_based_blamed_range(),
+        1..=1,
+        "single lines start and end at the same line"
+    );
+    assert_eq!(single_line.one_based_source_range(), 10..=10);
+}
+
+#[test]
+fn interrupt() -> gix_testtools::Result {
+    let Fixture {
+        odb,
+        mut resource_cache,
+        suspect,
+    } = Fixture::new()?;
+
+    let err = gix_blame::file(
+        &odb,
+        suspect,
+        None,
+        &mut resource_cache,
+        "simple.txt".into(),
+        gix_blame::Options::default(),
+        &mut gix_features::progress::Discard,
+        &AtomicBool::new(true),
+    )
+    .unwrap_err();
+    assert!(
+        matches!(err, gix_blame::Error::Interrupted),
+        "a set interrupt flag stops the traversal: {err}"
+    );
+    Ok(())
+}
+
 #[test]
 fn since() -> gix_testtools::Result {
     let Fixture {
@@ -411,15 +570,15 @@ fn since() -> gix_testtools::Result {
         None,
         &mut resource_cache,
         source_file_name.as_ref(),
-        gix_blame::Options {
-            diff_algorithm: gix_diff::blob::Algorithm::Histogram,
-            ranges: BlameRanges::default(),
-            since: Some(
+        gix_blame::Options::default()
+            .with_diff_algorithm(gix_diff::blob::Algorithm::Histogram)
+            .with_since(
                 gix_date::parse("2025-01-31", None).expect("TODO: should be able to to retrieve inner from Exn"),
-            ),
-            rewrites: Some(gix_diff::Rewrites::default()),
-            debug_track_path: false,
-        },
+                gix_blame::DateKind::Committer,
+            )
+            .with_rewrites(Some(gix_diff::Rewrites::default())),
+        &mut gix_features::progress::Discard,
+        &AtomicBool::new(false),
     )?
     .entries;
 
@@ -433,7 +592,99 @@ fn since() -> gix_testtools::Result {
     Ok(())
 }
 
+#[test]
+fn since_uses_author_or_committer_date() -> gix_testtools::Result {
+    let worktree_path = gix_testtools::scripted_fixture_read_only("make_blame_date_skew_repo.sh")?;
+    let Fixture {
+        odb,
+        mut resource_cache,
+        suspect,
+    } = Fixture::for_worktree_path(worktree_path.to_path_buf())?;
+
+    let mut blame = |since_uses| {
+        gix_blame::file(
+            &odb,
+            suspect,
+            None,
+            &mut resource_cache,
+            "file.txt".into(),
+            gix_blame::Options::default()
+                .with_since(gix_date::parse("2024-01-01", None).expect("valid date"), since_uses),
+            &mut gix_features::progress::Discard,
+            &AtomicBool::new(false),
+        )
+        .map(|outcome| outcome.entries)
+    };
+
+    let entries = blame(gix_blame::DateKind::Committer)?;
+    assert_eq!(entries.len(), 2, "the committer date of the tip is after `since`");
+    assert_eq!(entries[1].commit_id, suspect, "the tip is blamed for the line it added");
+    assert_ne!(
+        entries[0].commit_id, suspect,
+        "the first line is assigned to the boundary commit"
+    );
+
+    let entries = blame(gix_blame::DateKind::Author)?;
+    assert_eq!(entries.len(), 1, "the author date of the tip is before `since`");
+    assert_eq!(entries[0].commit_id, suspect, "all lines are assigned to the tip");
+    assert_eq!(entries[0].len.get(), 2);
+    Ok(())
+}
+
+#[test]
+fn with_contents() -> gix_testtools::Result {
+    let Fixture {
+        odb,
+        mut resource_cache,
+        suspect,
+    } = Fixture::new()?;
+
+    let source_file_name: gix_object::bstr::BString = "simple.txt".into();
+    let git_dir = fixture_path()?.join(".git");
+    let contents = std::fs::read(git_dir.join("simple.contents"))?;
+
+    let outcome = gix_blame::file_with_contents(
+        &odb,
+        [suspect],
+        None,
+        &mut resource_cache,
+        source_file_name.as_ref(),
+        &contents,
+        gix_blame::Options::default().with_diff_algorithm(gix_diff::blob::Algorithm::Histogram),
+        &mut gix_features::progress::Discard,
+        &AtomicBool::new(false),
+    )?;
+    assert_eq!(outcome.blob, contents, "the contents are the blamed file");
+
+    let baseline = Baseline::collect(git_dir.join("simple-contents.baseline"), source_file_name)?;
+    pretty_assertions::assert_eq!(outcome.entries, baseline);
+    assert_eq!(
+        outcome.entries.iter().filter(|entry| entry.commit_id.is_null()).count(),
+        2,
+        "lines changed in the contents are not committed yet"
+    );
+
+    let outcome = gix_blame::file_with_contents(
+        &odb,
+        [suspect],
+        None,
+        &mut resource_cache,
+        "does-not-exist.txt".into(),
+        &contents,
+        gix_blame::Options::default(),
+        &mut gix_features::progress::Discard,
+        &AtomicBool::new(false),
+    )?;
+    assert!(
+        matches!(outcome.entries.as_slice(), [entry] if entry.commit_id.is_null() && entry.len.get() == 5),
+        "files that don't exist in the suspect are entirely not committed yet"
+    );
+    Ok(())
+}
+
 mod blame_ranges {
+    use std::sync::atomic::AtomicBool;
+
     use crate::{Baseline, Fixture, fixture_path};
     use gix_blame::BlameRanges;
 
@@ -453,13 +704,12 @@ mod blame_ranges {
             None,
             &mut resource_cache,
             source_file_name.as_ref(),
-            gix_blame::Options {
-                diff_algorithm: gix_diff::blob::Algorithm::Histogram,
-                ranges: BlameRanges::from_one_based_inclusive_range(1..=2).unwrap(),
-                since: None,
-                rewrites: Some(gix_diff::Rewrites::default()),
-                debug_track_path: false,
-            },
+            gix_blame::Options::default()
+                .with_diff_algorithm(gix_diff::blob::Algorithm::Histogram)
+                .with_ranges(BlameRanges::from_one_based_inclusive_range(1..=2).unwrap())
+                .with_rewrites(Some(gix_diff::Rewrites::default())),
+            &mut gix_features::progress::Discard,
+            &AtomicBool::new(false),
         )?
         .entries;
 
@@ -496,13 +746,11 @@ mod blame_ranges {
             None,
             &mut resource_cache,
             source_file_name.as_ref(),
-            gix_blame::Options {
-                diff_algorithm: gix_diff::blob::Algorithm::Histogram,
-                ranges,
-                since: None,
-                rewrites: None,
-                debug_track_path: false,
-            },
+            gix_blame::Options::default()
+                .with_diff_algorithm(gix_diff::blob::Algorithm::Histogram)
+                .with_ranges(ranges),
+            &mut gix_features::progress::Discard,
+            &AtomicBool::new(false),
         )?
         .entries;
 
@@ -537,13 +785,11 @@ mod blame_ranges {
             None,
             &mut resource_cache,
             source_file_name.as_ref(),
-            gix_blame::Options {
-                diff_algorithm: gix_diff::blob::Algorithm::Histogram,
-                ranges,
-                since: None,
-                rewrites: None,
-                debug_track_path: false,
-            },
+            gix_blame::Options::default()
+                .with_diff_algorithm(gix_diff::blob::Algorithm::Histogram)
+                .with_ranges(ranges),
+            &mut gix_features::progress::Discard,
+            &AtomicBool::new(false),
         )?
         .entries;
 
@@ -559,10 +805,25 @@ mod blame_ranges {
 
         Ok(())
     }
+
+    #[test]
+    fn add_range_coalesces_ranges_bridged_by_the_new_one() -> gix_testtools::Result {
+        let mut ranges = BlameRanges::from_one_based_inclusive_range(1..=2)?;
+        ranges.add_one_based_inclusive_range(5..=6)?;
+        assert_eq!(ranges.to_zero_based_exclusive_ranges(10), vec![0..2, 4..6]);
+
+        ranges.add_one_based_inclusive_range(3..=4)?;
+        assert_eq!(
+            ranges.to_zero_based_exclusive_ranges(10),
+            vec![0..6],
+            "a range bridging two existing ones collapses all of them into one"
+        );
+        Ok(())
+    }
 }
 
 mod rename_tracking {
-    use gix_blame::BlameRanges;
+    use std::sync::atomic::AtomicBool;
 
     use crate::{Baseline, Fixture};
 
@@ -583,13 +844,11 @@ mod rename_tracking {
             None,
             &mut resource_cache,
             source_file_name.into(),
-            gix_blame::Options {
-                diff_algorithm: gix_diff::blob::Algorithm::Histogram,
-                ranges: BlameRanges::default(),
-                since: None,
-                rewrites: Some(gix_diff::Rewrites::default()),
-                debug_track_path: false,
-            },
+            gix_blame::Options::default()
+                .with_diff_algorithm(gix_diff::blob::Algorithm::Histogram)
+                .with_rewrites(Some(gix_diff::Rewrites::default())),
+            &mut gix_features::progress::Discard,
+            &AtomicBool::new(false),
         )?
         .entries;
 
@@ -613,13 +872,9 @@ mod rename_tracking {
         let lines_blamed = fixture
             .blame_file(
                 source_file_name.into(),
-                gix_blame::Options {
-                    diff_algorithm: gix_diff::blob::Algorithm::Histogram,
-                    ranges: BlameRanges::default(),
-                    since: None,
-                    rewrites: Some(gix_diff::Rewrites::default()),
-                    debug_track_path: false,
-                },
+                gix_blame::Options::default()
+                    .with_diff_algorithm(gix_diff::blob::Algorithm::Histogram)
+                    .with_rewrites(Some(gix_diff::Rewrites::default())),
             )?
             .entries;
 
@@ -634,6 +889,154 @@ mod rename_tracking {
     }
 }
 
+mod forward {
+    use std::sync::atomic::AtomicBool;
+
+    use crate::{Baseline, Fixture};
+
+    #[test]
+    fn lines_are_attributed_to_the_last_commit_they_were_present_in() -> gix_testtools::Result {
+        let worktree_path = gix_testtools::scripted_fixture_read_only("make_blame_reverse_repo.sh")?;
+        let Fixture {
+            odb,
+            mut resource_cache,
+            suspect: end,
+        } = Fixture::for_worktree_path(worktree_path.to_path_buf())?;
+        let git_dir = worktree_path.join(".git");
+        let start = gix_hash::ObjectId::from_hex(std::fs::read_to_string(git_dir.join("start"))?.trim().as_bytes())?;
+
+        let source_file_name = "file.txt";
+        let lines_blamed = gix_blame::file(
+            &odb,
+            start,
+            None,
+            &mut resource_cache,
+            source_file_name.into(),
+            gix_blame::Options::default()
+                .with_diff_algorithm(gix_diff::blob::Algorithm::Histogram)
+                .with_direction(gix_blame::BlameDirection::Forward { end }),
+            &mut gix_features::progress::Discard,
+            &AtomicBool::new(false),
+        )?
+        .entries;
+
+        let baseline = Baseline::collect(git_dir.join("file.baseline"), source_file_name.into())?;
+        pretty_assertions::assert_eq!(lines_blamed, baseline);
+        assert_eq!(
+            lines_blamed.iter().filter(|entry| entry.commit_id == end).count(),
+            2,
+            "lines that are still present are attributed to the end commit"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn end_must_descend_from_start() -> gix_testtools::Result {
+        let worktree_path = gix_testtools::scripted_fixture_read_only("make_blame_reverse_repo.sh")?;
+        let Fixture {
+            odb,
+            mut resource_cache,
+            suspect: end,
+        } = Fixture::for_worktree_path(worktree_path.to_path_buf())?;
+        let start = gix_hash::ObjectId::from_hex(
+            std::fs::read_to_string(worktree_path.join(".git/start"))?
+                .trim()
+                .as_bytes(),
+        )?;
+
+        let err = gix_blame::file(
+            &odb,
+            end,
+            None,
+            &mut resource_cache,
+            "file.txt".into(),
+            gix_blame::Options::default().with_direction(gix_blame::BlameDirection::Forward { end: start }),
+            &mut gix_features::progress::Discard,
+            &AtomicBool::new(false),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(err, gix_blame::Error::ForwardEndNotReachable { .. }),
+            "traversing forward from a descendant is an error: {err}"
+        );
+        Ok(())
+    }
+}
+
+mod copy_detection {
+    use std::sync::atomic::AtomicBool;
+
+    use gix_blame::CopyDetection;
+
+    use crate::{Baseline, Fixture};
+
+    fn blame(
+        source_file_name: &str,
+        copy_detection: CopyDetection,
+    ) -> gix_testtools::Result<Vec<gix_blame::BlameEntry>> {
+        let worktree_path = gix_testtools::scripted_fixture_read_only("make_blame_copy_repo.sh")?;
+        let Fixture {
+            odb,
+            mut resource_cache,
+            suspect,
+        } = Fixture::for_worktree_path(worktree_path.to_path_buf())?;
+
+        Ok(gix_blame::file(
+            &odb,
+            suspect,
+            None,
+            &mut resource_cache,
+            source_file_name.into(),
+            gix_blame::Options::default()
+                .with_diff_algorithm(gix_diff::blob::Algorithm::Histogram)
+                .with_copy_detection(copy_detection),
+            &mut gix_features::progress::Discard,
+            &AtomicBool::new(false),
+        )?
+        .entries)
+    }
+
+    fn baseline(name: &str, source_file_name: &str) -> gix_testtools::Result<Vec<gix_blame::BlameEntry>> {
+        let worktree_path = gix_testtools::scripted_fixture_read_only("make_blame_copy_repo.sh")?;
+        Ok(Baseline::collect(
+            worktree_path.join(".git").join(name),
+            source_file_name.into(),
+        )?)
+    }
+
+    #[test]
+    fn lines_moved_from_files_modified_in_the_same_commit() -> gix_testtools::Result {
+        let lines_blamed = blame("moved.txt", CopyDetection::SameCommit)?;
+        pretty_assertions::assert_eq!(lines_blamed, baseline("moved.baseline", "moved.txt")?);
+        assert_eq!(
+            lines_blamed[1].source_file_name.as_ref().map(AsRef::as_ref),
+            Some("source.txt".as_bytes()),
+            "the moved lines are attributed to the file they were moved from"
+        );
+
+        let lines_blamed = blame("moved.txt", CopyDetection::Disabled)?;
+        assert!(
+            lines_blamed.iter().all(|entry| entry.source_file_name.is_none()),
+            "without copy detection, the moved lines are attributed to the commit that moved them"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn lines_copied_from_unmodified_files() -> gix_testtools::Result {
+        let lines_blamed = blame("copied.txt", CopyDetection::SameCommit)?;
+        pretty_assertions::assert_eq!(
+            lines_blamed,
+            baseline("copied-same-commit.baseline", "copied.txt")?,
+            "the file the lines were copied from wasn't modified, so it isn't considered"
+        );
+
+        let lines_blamed = blame("copied.txt", CopyDetection::AllCommits)?;
+        pretty_assertions::assert_eq!(lines_blamed, baseline("copied-all-commits.baseline", "copied.txt")?);
+        Ok(())
+    }
+}
+
 fn fixture_path() -> gix_testtools::Result<PathBuf> {
     gix_testtools::scripted_fixture_read_only("make_blame_repo.sh")
 }
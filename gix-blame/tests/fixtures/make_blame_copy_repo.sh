@@ -0,0 +1,65 @@
+#!/usr/bin/env bash
+set -eu -o pipefail
+
+git init -q
+git checkout -q -b main
+
+cat <<'EOT' > source.txt
+fn source() {
+    let first_copied_line = "alpha beta gamma delta";
+    let second_copied_line = "epsilon zeta eta theta";
+}
+
+fn unrelated() {
+    let stays_where_it_is = "iota kappa lambda";
+}
+EOT
+printf 'line 1\nline 2\n' > moved.txt
+git add source.txt moved.txt
+git commit -q -m c1
+
+cat <<'EOT' >> source.txt
+
+fn later() {
+    let only_copied_much_later = "mu nu xi omicron pi";
+}
+EOT
+git commit -q -am c2
+
+# Lines are moved from 'source.txt' into 'moved.txt', which modifies both in the same commit.
+cat <<'EOT' > source.txt
+fn unrelated() {
+    let stays_where_it_is = "iota kappa lambda";
+}
+
+fn later() {
+    let only_copied_much_later = "mu nu xi omicron pi";
+}
+EOT
+cat <<'EOT' > moved.txt
+line 1
+fn source() {
+    let first_copied_line = "alpha beta gamma delta";
+    let second_copied_line = "epsilon zeta eta theta";
+}
+line 2
+EOT
+git commit -q -am c3
+
+echo "unrelated" > unrelated.txt
+git add unrelated.txt
+git commit -q -m c4
+
+# Lines are copied from 'source.txt' into a new file, without touching 'source.txt'.
+cat <<'EOT' > copied.txt
+a new line
+fn later() {
+    let only_copied_much_later = "mu nu xi omicron pi";
+}
+EOT
+git add copied.txt
+git commit -q -m c5
+
+git blame --porcelain -C moved.txt > .git/moved.baseline
+git blame --porcelain -C copied.txt > .git/copied-same-commit.baseline
+git blame --porcelain -C -C -C copied.txt > .git/copied-all-commits.baseline
@@ -0,0 +1,14 @@
+#!/usr/bin/env bash
+set -eu -o pipefail
+
+git init -q
+git checkout -q -b main
+
+echo "line 1" > file.txt
+git add file.txt
+GIT_AUTHOR_DATE="2020-01-01T00:00:00Z" GIT_COMMITTER_DATE="2020-01-01T00:00:00Z" git commit -q -m c1
+
+# Authored long ago, but committed recently, as it happens when rebasing.
+echo "line 2" >> file.txt
+git add file.txt
+GIT_AUTHOR_DATE="2020-06-01T00:00:00Z" GIT_COMMITTER_DATE="2025-01-01T00:00:00Z" git commit -q -m c2
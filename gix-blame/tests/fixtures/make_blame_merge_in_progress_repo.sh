@@ -0,0 +1,23 @@
+#!/usr/bin/env bash
+set -eu -o pipefail
+
+git init -q
+git checkout -q -b main
+
+printf 'line 1\nline 2\nline 3\n' > file.txt
+git add file.txt
+git commit -q -m c1
+
+git checkout -q -b other
+printf 'line 1\nline 2 changed in other\nline 3\n' > file.txt
+git commit -q -am c2
+
+git checkout -q main
+printf 'line 1\nline 2\nline 3 changed in main\n' > file.txt
+git commit -q -am c3
+
+# The merge is left in progress, with the merged file modified further in the worktree.
+git merge -q --no-commit other || true
+printf 'line 1\nline 2 changed in other\nline 3 changed in main\nline 4 added while merging\n' > file.txt
+
+git blame --porcelain file.txt > .git/file.baseline
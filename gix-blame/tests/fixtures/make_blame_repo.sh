@@ -259,10 +259,24 @@ git commit -q -m c15.2
 
 git merge branch-that-has-earlier-commit || true
 
+printf 'fn main() {\n  let a = 1;\n  let b = 2;\n}\n' > reindented.txt
+git add reindented.txt
+git commit -q -m c16
+
+printf 'fn main() {\n    let a = 1;\n    let b  =  2;\n}\n' > reindented.txt
+git add reindented.txt
+git commit -q -m c16.1
+
+printf 'fn main() {\n    let a = 1;\n    let b  =  2;\n    let c = 3;\n}\n' > reindented.txt
+git add reindented.txt
+git commit -q -m c16.2
+
 git blame --porcelain simple.txt > .git/simple.baseline
 git blame --porcelain -L 1,2 simple.txt > .git/simple-lines-1-2.baseline
 git blame --porcelain -L 1,2 -L 4 simple.txt > .git/simple-lines-multiple-1-2-and-4.baseline
 git blame --porcelain --since 2025-01-31 simple.txt > .git/simple-since.baseline
+printf 'line 1\nline 2 changed in the worktree\nline 3\nline 4\nline 5 added in the worktree\n' > .git/simple.contents
+git blame --porcelain --contents .git/simple.contents simple.txt > .git/simple-contents.baseline
 git blame --porcelain multiline-hunks.txt > .git/multiline-hunks.baseline
 git blame --porcelain deleted-lines.txt > .git/deleted-lines.baseline
 git blame --porcelain deleted-lines-multiple-hunks.txt > .git/deleted-lines-multiple-hunks.baseline
@@ -291,6 +305,8 @@ git blame --porcelain file-changed-in-two-branches.txt > .git/file-changed-in-tw
 git blame --porcelain file-topo-order-different-than-date-order.txt > .git/file-topo-order-different-than-date-order.baseline
 
 git blame --porcelain empty-lines-histogram.txt > .git/empty-lines-histogram.baseline
+git blame --porcelain reindented.txt > .git/reindented.baseline
+git blame --porcelain -w reindented.txt > .git/reindented-ignore-whitespace.baseline
 
 git config --local diff.algorithm myers
 
@@ -0,0 +1,29 @@
+#!/usr/bin/env bash
+set -eu -o pipefail
+
+git init -q
+git checkout -q -b main
+
+printf 'line 1\nline 2\nline 3\nline 4\nline 5\nline 6\n' > file.txt
+git add file.txt
+git commit -q -m c1
+git rev-parse HEAD > .git/start
+
+# Lines are removed in different commits, while others are added.
+printf 'line 1\nline 2\nline 3\nline 4\nline 5\nline 6\nline 7\n' > file.txt
+git commit -q -am c2
+
+printf 'line 1\nline 3\nline 4\nline 5\nline 6\nline 7\n' > file.txt
+git commit -q -am c3
+
+printf 'line 1\nline 3\nline 4 changed\nline 5\nline 6\nline 7\n' > file.txt
+git commit -q -am c4
+
+printf 'new first line\nline 3\nline 4 changed\nline 5\n' > file.txt
+git commit -q -am c5
+
+echo "unrelated" > other.txt
+git add other.txt
+git commit -q -m c6
+
+git blame --porcelain --reverse --first-parent "$(cat .git/start)..HEAD" -- file.txt > .git/file.baseline
@@ -31,7 +31,7 @@ pub mod time;
 
 ///
 pub mod parse;
-pub use parse::function::{parse, parse_header};
+pub use parse::function::{parse, parse_date_only, parse_header};
 
 pub use gix_error::ValidationError as Error;
 
@@ -22,6 +22,9 @@ use gix_error::{Exn, ResultExt};
 /// *   `1950-12-31`
 /// *   `2024-12-31`
 ///
+/// These resolve to midnight at the start of the day in UTC, independently of the local time zone,
+/// which is the same as calling [`parse_date_only(input, 0)`](parse_date_only()).
+///
 /// ### 2. RFC2822 Format
 ///
 /// *   `Thu, 18 Aug 2022 12:45:06 +0800`
@@ -72,12 +75,11 @@ use gix_error::{Exn, ResultExt};
 /// If `now` is October 27, 2023 at 10:00:00 UTC:
 ///     *   `2 minutes ago` (October 27, 2023 at 09:58:00 UTC)
 ///     *   `3 hours ago` (October 27, 2023 at 07:00:00 UTC)
+///     *   `1 hour from now` (October 27, 2023 at 11:00:00 UTC)
+///     *   `in 2 days` (October 29, 2023 at 10:00:00 UTC)
 pub fn parse(input: &str, now: Option<SystemTime>) -> Result<Time, Exn<Error>> {
     Ok(if let Ok(val) = Date::strptime(SHORT.0, input) {
-        let val = val
-            .to_zoned(TimeZone::UTC)
-            .or_raise(|| Error::new_with_input("Timezone conversion failed", input))?;
-        Time::new(val.timestamp().as_second(), val.offset().seconds())
+        date_at_midnight(val, TimeZone::UTC, input)?
     } else if let Ok(val) = rfc2822_relaxed(input) {
         Time::new(val.timestamp().as_second(), val.offset().seconds())
     } else if let Ok(val) = strptime_relaxed(ISO8601.0, input) {
@@ -102,6 +104,26 @@ pub fn parse(input: &str, now: Option<SystemTime>) -> Result<Time, Exn<Error>> {
     })
 }
 
+/// Parse `input` in the SHORT format, like `2025-01-31`, as midnight at the start of that day in the time zone
+/// that is `offset` seconds east of UTC.
+///
+/// This makes explicit what [`parse()`] assumes for such dates, which always is UTC, and allows to produce
+/// reproducible results independently of the time zone of the host.
+pub fn parse_date_only(input: &str, offset: OffsetInSeconds) -> Result<Time, Exn<Error>> {
+    let date =
+        Date::strptime(SHORT.0, input).or_raise(|| Error::new_with_input("Date must be like YYYY-MM-DD", input))?;
+    let offset = jiff::tz::Offset::from_seconds(offset)
+        .or_raise(|| Error::new_with_input("Time zone offset is out of range", input))?;
+    date_at_midnight(date, TimeZone::fixed(offset), input)
+}
+
+fn date_at_midnight(date: Date, tz: TimeZone, input: &str) -> Result<Time, Exn<Error>> {
+    let val = date
+        .to_zoned(tz)
+        .or_raise(|| Error::new_with_input("Timezone conversion failed", input))?;
+    Ok(Time::new(val.timestamp().as_second(), val.offset().seconds()))
+}
+
 /// Unlike [`parse()`] which handles all kinds of input, this function only parses the commit-header format
 /// like `1745582210 +0200`.
 ///
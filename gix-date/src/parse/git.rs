@@ -16,6 +16,24 @@ pub fn parse_git_date_format(input: &str) -> Option<Time> {
         .or_else(|| parse_flexible_iso8601(input))
 }
 
+/// Parse ISO8601 as commonly found in machine-generated data, like `2025-01-31T12:00:00Z` or `2025-01-31T12:00:00.123+05:30`,
+/// with subseconds being ignored.
+/// Dates without time, like `2025-01-31` or `2025-01-31+05:30`, are midnight in the given offset, or in UTC without offset.
+pub(crate) fn parse_iso8601(input: &str) -> Option<Time> {
+    parse_flexible_iso8601(input).or_else(|| {
+        let (date_part, offset_str) = input.trim().split_at_checked(10)?;
+        if !date_part.is_ascii() || date_part.chars().nth(4)? != '-' || date_part.chars().nth(7)? != '-' {
+            return None;
+        }
+        let year: i32 = date_part[0..4].parse().ok()?;
+        let month: i32 = date_part[5..7].parse().ok()?;
+        let day: i32 = date_part[8..10].parse().ok()?;
+        let offset = parse_flexible_offset(offset_str)?;
+        let zoned = new_zoned(year, month, day, 0, 0, 0, offset)?;
+        Some(Time::new(zoned.timestamp().as_second(), offset))
+    })
+}
+
 /// Parse ISO8601 with dots: `2008.02.14 20:30:45 -0500`
 fn parse_iso8601_dots(input: &str) -> Option<Time> {
     // Format: YYYY.MM.DD HH:MM:SS offset
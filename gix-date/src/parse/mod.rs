@@ -61,13 +61,80 @@ impl Time {
             .expect("write to memory of just the right size cannot fail");
         buf.as_str()
     }
+
+    /// Serialize this instance into `buf` according to the strftime-style `format`, in the time zone of our offset,
+    /// and return `buf` as `&str`. Use [`to_str()`](Self::to_str()) for the raw format as used in Git commits.
+    ///
+    /// Supported specifiers are `%Y`, `%m`, `%d`, `%H`, `%M`, `%S`, `%z` (like `+0530`), `%:z` (like `+05:30`) and `%%`,
+    /// while all other characters are copied verbatim.
+    /// This fails if `format` contains any other specifier, if our offset is out of range, or if the result
+    /// doesn't fit into `buf`, which holds as many bytes as the raw format needs at most.
+    pub fn format_into<'a>(&self, format: &str, buf: &'a mut TimeBuf) -> Result<&'a str, Error> {
+        use std::io::Write;
+
+        let datetime = jiff::tz::Offset::from_seconds(self.offset)
+            .and_then(|offset| Ok(offset.to_datetime(jiff::Timestamp::from_second(self.seconds)?)))
+            .map_err(|_| {
+                Error::new_with_input(
+                    "time or offset is out of range",
+                    format!("{} {}", self.seconds, self.offset),
+                )
+            })?;
+        let too_long = || Error::new_with_input("formatted time doesn't fit into the buffer", format);
+        let offset = self.offset.unsigned_abs();
+        let (sign, offset_hours, offset_minutes) = (
+            if self.offset < 0 { '-' } else { '+' },
+            offset / 3600,
+            offset % 3600 / 60,
+        );
+
+        buf.clear();
+        let mut out = TimeBufWriter(buf);
+        let mut chars = format.char_indices();
+        while let Some((pos, c)) = chars.next() {
+            if c != '%' {
+                let mut utf8 = [0; 4];
+                out.write_all(c.encode_utf8(&mut utf8).as_bytes())
+                    .map_err(|_| too_long())?;
+                continue;
+            }
+            let specifier = match chars.next() {
+                Some((_, ':')) => chars.next().filter(|(_, c)| *c == 'z').map(|_| ':'),
+                other => other.map(|(_, c)| c),
+            };
+            let res = match specifier {
+                Some('Y') => write!(out, "{:04}", datetime.year()),
+                Some('m') => write!(out, "{:02}", datetime.month()),
+                Some('d') => write!(out, "{:02}", datetime.day()),
+                Some('H') => write!(out, "{:02}", datetime.hour()),
+                Some('M') => write!(out, "{:02}", datetime.minute()),
+                Some('S') => write!(out, "{:02}", datetime.second()),
+                Some('z') => write!(out, "{sign}{offset_hours:02}{offset_minutes:02}"),
+                Some(':') => write!(out, "{sign}{offset_hours:02}:{offset_minutes:02}"),
+                Some('%') => out.write_all(b"%"),
+                _ => {
+                    let end = chars.next().map_or(format.len(), |(end, _)| end);
+                    return Err(Error::new_with_span("unknown format specifier", format, pos..end));
+                }
+            };
+            res.map_err(|_| too_long())?;
+        }
+        Ok(buf.as_str())
+    }
 }
 
+/// Parse the [raw format](function::parse_header()) like `1745582210 +0200`, or ISO8601 like `2025-01-31T12:00:00Z`,
+/// `2025-01-31T12:00:00.5+05:30` or `2025-01-31`.
+///
+/// Subseconds are truncated, and dates without time are midnight in the given offset, or in UTC if there is none.
+/// Use [`parse()`](function::parse()) to handle all formats supported by Git, including relative dates.
 impl FromStr for Time {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        crate::parse_header(s).ok_or_else(|| Error::new_with_input("invalid time", s))
+        git::parse_iso8601(s)
+            .or_else(|| crate::parse_header(s))
+            .ok_or_else(|| Error::new_with_input("invalid time", s))
     }
 }
 
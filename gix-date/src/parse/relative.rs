@@ -1,6 +1,6 @@
 use std::{str::FromStr, time::SystemTime};
 
-use crate::Error;
+use crate::{Error, Time};
 use gix_error::{Exn, ResultExt, ValidationError, ensure};
 use jiff::{Span, Timestamp, Zoned, tz::TimeZone};
 
@@ -11,15 +11,23 @@ pub fn parse(input: &str, now: Option<SystemTime>) -> Option<Result<Zoned, Exn<E
     }
 
     // Then try numeric relative dates
-    parse_ago(input).map(|result| -> Result<Zoned, Exn<Error>> {
-        let span = result?;
-        // This was an error case in a previous version of this code, where
-        // it would fail when converting from a negative signed integer
-        // to an unsigned integer. This preserves that failure case even
-        // though the code below handles it okay.
-        ensure!(!span.is_negative(), ValidationError::new(""));
-        subtract_span(now, span)
-    })
+    parse_ago(input)
+        .map(|result| -> Result<Zoned, Exn<Error>> {
+            let span = result?;
+            // This was an error case in a previous version of this code, where
+            // it would fail when converting from a negative signed integer
+            // to an unsigned integer. This preserves that failure case even
+            // though the code below handles it okay.
+            ensure!(!span.is_negative(), ValidationError::new(""));
+            subtract_span(now, span)
+        })
+        .or_else(|| {
+            parse_future(input).map(|result| -> Result<Zoned, Exn<Error>> {
+                let span = result?;
+                ensure!(!span.is_negative(), ValidationError::new(""));
+                add_span(now, span)
+            })
+        })
 }
 
 /// Parse named relative dates like "now", "today", "yesterday".
@@ -49,7 +57,39 @@ fn parse_ago(input: &str) -> Option<Result<Span, Exn<Error>>> {
     span(period, units)
 }
 
+/// Parse future dates like `in 3 days` or `2 weeks from now`.
+fn parse_future(input: &str) -> Option<Result<Span, Exn<Error>>> {
+    let mut split = input.split_whitespace();
+    let (units, period) = match split.next()? {
+        "in" => (i64::from_str(split.next()?).ok()?, split.next()?),
+        units => {
+            let units = i64::from_str(units).ok()?;
+            let period = split.next()?;
+            if split.next()? != "from" || split.next()? != "now" {
+                return None;
+            }
+            (units, period)
+        }
+    };
+    if split.next().is_some() {
+        return None;
+    }
+    span(period, units)
+}
+
+fn add_span(now: Option<SystemTime>, span: Span) -> Result<Zoned, Exn<ValidationError>> {
+    let zdt = now_in_utc(now)?;
+    zdt.checked_add(span)
+        .or_raise(|| Error::new(format!("Failed to add {span} to {zdt}")))
+}
+
 fn subtract_span(now: Option<SystemTime>, span: Span) -> Result<Zoned, Exn<ValidationError>> {
+    let zdt = now_in_utc(now)?;
+    zdt.checked_sub(span)
+        .or_raise(|| Error::new(format!("Failed to subtract {zdt} from {span}")))
+}
+
+fn now_in_utc(now: Option<SystemTime>) -> Result<Zoned, Exn<ValidationError>> {
     let now = now.ok_or(ValidationError::new("Missing current time"))?;
     let ts: Timestamp = Timestamp::try_from(now).or_raise(|| Error::new("Could not convert current time"))?;
     // N.B. This matches the behavior of this code when it was
@@ -58,9 +98,7 @@ fn subtract_span(now: Option<SystemTime>, span: Span) -> Result<Zoned, Exn<Valid
     // ago" correctly, even when it crosses DST transitions. Since
     // we're in the UTC time zone here, which has no DST, 1 day is
     // in practice always 24 hours. ---AG
-    let zdt = ts.to_zoned(TimeZone::UTC);
-    zdt.checked_sub(span)
-        .or_raise(|| Error::new(format!("Failed to subtract {zdt} from {span}")))
+    Ok(ts.to_zoned(TimeZone::UTC))
 }
 
 fn span(period: &str, units: i64) -> Option<Result<Span, Exn<Error>>> {
@@ -78,3 +116,65 @@ fn span(period: &str, units: i64) -> Option<Result<Span, Exn<Error>>> {
     };
     Some(result.or_raise(|| Error::new(format!("Couldn't parse span from '{period} {units}'"))))
 }
+
+impl Time {
+    /// Describe this instance relative to `now` like `git log --date=relative` does, producing strings
+    /// like `5 minutes ago`, `yesterday`, `3 weeks ago`, `1 year, 2 months ago`, or `in 2 hours` for times after `now`.
+    ///
+    /// Unlike Git, which would say `24 hours ago` to `35 hours ago`, we say `yesterday` (or `tomorrow`) instead.
+    /// The offsets of both times are ignored as only the seconds between them matter.
+    pub fn humanize_relative_to(&self, now: Time) -> String {
+        let diff = now.seconds.saturating_sub(self.seconds);
+        let (past, diff) = (diff >= 0, diff.unsigned_abs());
+        let describe = |amount: u64, unit: &str| -> String {
+            let plural = if amount == 1 { "" } else { "s" };
+            if past {
+                format!("{amount} {unit}{plural} ago")
+            } else {
+                format!("in {amount} {unit}{plural}")
+            }
+        };
+
+        // The thresholds and rounding match `show_date_relative()` in Git, except for `yesterday`.
+        if diff < 90 {
+            return describe(diff, "second");
+        }
+        let minutes = (diff + 30) / 60;
+        if minutes < 90 {
+            return describe(minutes, "minute");
+        }
+        let hours = (minutes + 30) / 60;
+        if hours < 24 {
+            return describe(hours, "hour");
+        }
+        if hours < 36 {
+            return if past { "yesterday" } else { "tomorrow" }.into();
+        }
+        let days = (hours + 12) / 24;
+        if days < 14 {
+            return describe(days, "day");
+        }
+        if days < 70 {
+            return describe((days + 3) / 7, "week");
+        }
+        if days < 365 {
+            return describe((days + 15) / 30, "month");
+        }
+        if days < 1825 {
+            let total_months = (days * 12 * 2 + 365) / (365 * 2);
+            let (years, months) = (total_months / 12, total_months % 12);
+            if months != 0 {
+                let years_plural = if years == 1 { "" } else { "s" };
+                let years = format!("{years} year{years_plural}");
+                let months = describe(months, "month");
+                return if past {
+                    format!("{years}, {months}")
+                } else {
+                    format!("in {years}, {}", months.trim_start_matches("in "))
+                };
+            }
+            return describe(years, "year");
+        }
+        describe((days + 183) / 365, "year")
+    }
+}
@@ -96,6 +96,60 @@ fn git_default() -> gix_testtools::Result {
     Ok(())
 }
 
+#[test]
+fn format_into() -> gix_testtools::Result {
+    let mut buf = gix_date::parse::TimeBuf::default();
+    assert_eq!(
+        time().format_into("%Y-%m-%d %H:%M:%S %z", &mut buf)?,
+        "1973-11-30 00:03:09 +0230"
+    );
+    assert_eq!(
+        time().format_into("%Y-%m-%dT%H:%M:%S%:z", &mut buf)?,
+        "1973-11-30T00:03:09+02:30"
+    );
+    assert_eq!(
+        Time {
+            seconds: 123456789,
+            offset: -3600,
+        }
+        .format_into("%d.%m.%Y %H %z %%", &mut buf)?,
+        "29.11.1973 20 -0100 %"
+    );
+    assert_eq!(
+        time().to_str(&mut buf),
+        "123456789 +0230",
+        "the raw format is still available"
+    );
+    Ok(())
+}
+
+#[test]
+fn format_into_fails_gracefully() {
+    let mut buf = gix_date::parse::TimeBuf::default();
+    for format in ["%Y %a", "%", "%:Y", "%Y %Q trailing"] {
+        let err = time().format_into(format, &mut buf).unwrap_err();
+        assert!(
+            err.to_string().starts_with("unknown format specifier"),
+            "{format}: {err}"
+        );
+    }
+    assert!(
+        time()
+            .format_into("%Y-%m-%d %H:%M:%S %z and some more", &mut buf)
+            .is_err(),
+        "the output may not be larger than the buffer"
+    );
+    assert!(
+        Time {
+            seconds: 0,
+            offset: 100 * 3600,
+        }
+        .format_into("%Y", &mut buf)
+        .is_err(),
+        "offsets out of range are an error"
+    );
+}
+
 fn time() -> Time {
     Time {
         seconds: 123456789,
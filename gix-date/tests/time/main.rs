@@ -146,3 +146,45 @@ mod write_to {
         Ok(())
     }
 }
+
+mod from_str {
+    use gix_date::Time;
+
+    #[test]
+    fn iso8601_with_z_and_colon_offsets() {
+        for (input, expected) in [
+            ("2025-01-31T12:00:00Z", Time::new(1738324800, 0)),
+            ("2025-01-31T12:00:00+05:30", Time::new(1738305000, 19800)),
+            ("2025-01-31T12:00:00.123456Z", Time::new(1738324800, 0)),
+            ("2025-01-31T12:00:00.9+05:30", Time::new(1738305000, 19800)),
+            ("2025-01-31", Time::new(1738281600, 0)),
+            ("2025-01-31Z", Time::new(1738281600, 0)),
+            ("2025-01-31-08:00", Time::new(1738310400, -28800)),
+        ] {
+            assert_eq!(input.parse::<Time>().expect("valid"), expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn raw_format_still_works() {
+        assert_eq!(
+            "1745582210 +0200".parse::<Time>().expect("valid"),
+            Time::new(1745582210, 7200)
+        );
+        assert_eq!(
+            "-1660874655 +0800".parse::<Time>().expect("valid"),
+            Time::new(-1660874655, 28800)
+        );
+    }
+
+    #[test]
+    fn invalid_iso8601_is_rejected() {
+        for input in [
+            "2025-13-31T12:00:00Z",
+            "2025-01-31T25:00:00Z",
+            "2025-01-31T12:00:00+05:3",
+        ] {
+            assert!(input.parse::<Time>().is_err(), "{input} must not parse");
+        }
+    }
+}
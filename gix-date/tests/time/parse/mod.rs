@@ -36,6 +36,31 @@ fn short() {
     );
 }
 
+#[test]
+fn date_only() {
+    assert_eq!(
+        gix_date::parse_date_only("1979-02-26", 0).unwrap(),
+        gix_date::parse("1979-02-26", None).unwrap(),
+        "`parse()` assumes UTC for dates"
+    );
+    assert_eq!(
+        gix_date::parse_date_only("1979-02-26", 2 * 3600).unwrap(),
+        Time {
+            seconds: 288835200 - 2 * 3600,
+            offset: 2 * 3600,
+        },
+        "midnight is in the time zone of the given offset"
+    );
+    assert!(
+        gix_date::parse_date_only("1979-02-26 18:30:00", 0).is_err(),
+        "only dates are supported"
+    );
+    assert!(
+        gix_date::parse_date_only("1979-02-26", 30 * 3600).is_err(),
+        "offsets must be valid"
+    );
+}
+
 #[test]
 fn rfc2822() {
     assert_eq!(
@@ -191,3 +191,96 @@ mod named {
         assert_eq!(actual.seconds, 1_000_000);
     }
 }
+
+mod future {
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn in_and_from_now() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        for (input, expected) in [
+            ("in 3 days", 1_000_000 + 3 * 86400),
+            ("2 weeks from now", 1_000_000 + 14 * 86400),
+            ("in 1 hour", 1_000_000 + 3600),
+            ("5 seconds from now", 1_000_005),
+            ("in 0 minutes", 1_000_000),
+        ] {
+            let actual = gix_date::parse(input, Some(now)).unwrap();
+            assert_eq!(actual.seconds, expected, "{input}");
+            assert_eq!(actual.offset, 0, "{input}: relative dates are always UTC");
+        }
+    }
+
+    #[test]
+    fn missing_current_time_is_an_error() {
+        for input in ["in 3 days", "3 days from now"] {
+            assert_eq!(
+                gix_date::parse(input, None).unwrap_err().to_string(),
+                "Missing current time",
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        let err = gix_date::parse("in 9000 years", Some(SystemTime::now())).unwrap_err();
+        assert!(
+            err.to_string().starts_with("Failed to add"),
+            "adding beyond the largest representable date fails: {err}"
+        );
+    }
+
+    #[test]
+    fn incomplete_phrases_are_rejected() {
+        for input in ["in 3", "3 days from", "3 days from now on", "in three days"] {
+            assert!(
+                gix_date::parse(input, Some(SystemTime::now())).is_err(),
+                "{input} must not parse"
+            );
+        }
+    }
+}
+
+mod humanize {
+    use gix_date::Time;
+
+    #[test]
+    fn past_and_future() {
+        let now = Time::new(1_000_000_000, 3600);
+        for (seconds_ago, expected) in [
+            (0, "0 seconds ago"),
+            (1, "1 second ago"),
+            (89, "89 seconds ago"),
+            (90, "2 minutes ago"),
+            (89 * 60, "89 minutes ago"),
+            (2 * 3600, "2 hours ago"),
+            (23 * 3600, "23 hours ago"),
+            (35 * 3600, "yesterday"),
+            (36 * 3600, "2 days ago"),
+            (13 * 86400, "13 days ago"),
+            (21 * 86400, "3 weeks ago"),
+            (69 * 86400, "10 weeks ago"),
+            (70 * 86400, "2 months ago"),
+            (364 * 86400, "12 months ago"),
+            (365 * 86400, "1 year ago"),
+            (430 * 86400, "1 year, 2 months ago"),
+            (1824 * 86400, "5 years ago"),
+            (1825 * 86400, "5 years ago"),
+            (20 * 365 * 86400, "20 years ago"),
+            (-5 * 60, "in 5 minutes"),
+            (-3 * 86400, "in 3 days"),
+            (-430 * 86400, "in 1 year, 2 months"),
+        ] {
+            let time = Time::new(now.seconds - seconds_ago, 0);
+            assert_eq!(time.humanize_relative_to(now), expected, "{seconds_ago} seconds ago");
+        }
+    }
+
+    #[test]
+    fn yesterday_and_tomorrow() {
+        let now = Time::new(1_000_000_000, 0);
+        assert_eq!(Time::new(now.seconds - 86400, 0).humanize_relative_to(now), "yesterday");
+        assert_eq!(Time::new(now.seconds + 86400, 0).humanize_relative_to(now), "tomorrow");
+    }
+}
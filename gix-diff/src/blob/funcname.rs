@@ -0,0 +1,212 @@
+use bstr::BStr;
+
+/// The error returned by [`Patterns::from_xfuncname()`].
+#[derive(Debug, thiserror::Error)]
+#[allow(missing_docs)]
+pub enum Error {
+    #[error("Could not compile the funcname pattern {pattern:?}")]
+    Regex { pattern: String, source: regex::Error },
+}
+
+/// Regular expressions to identify lines that start a function, as configured with `diff.<driver>.xfuncname`
+/// or provided by one of the [built-in drivers](Self::builtin()).
+#[derive(Debug, Clone)]
+pub struct Patterns {
+    /// Each expression along with `true` if it is negated, i.e. prevents lines from being a function header.
+    regexes: Vec<(bool, regex::bytes::Regex)>,
+}
+
+impl Patterns {
+    /// Compile `pattern` the way Git compiles `diff.<driver>.xfuncname`, as one POSIX extended regular expression per line,
+    /// where lines starting with `!` reject the lines they match. Set `ignore_case` to match case-insensitively.
+    pub fn from_xfuncname(pattern: &str, ignore_case: bool) -> Result<Self, Error> {
+        let regexes = pattern
+            .lines()
+            .map(|line| {
+                let (negated, posix) = line.strip_prefix('!').map_or((false, line), |line| (true, line));
+                regex::bytes::RegexBuilder::new(&posix_to_rust(posix))
+                    .case_insensitive(ignore_case)
+                    .unicode(false)
+                    .build()
+                    .map(|regex| (negated, regex))
+                    .map_err(|source| Error::Regex {
+                        pattern: line.to_owned(),
+                        source,
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Patterns { regexes })
+    }
+
+    /// Return the patterns of the built-in diff driver called `name`, like `rust` or `python`, as Git would use them
+    /// if `diff=<name>` is set for a file, or `None` if there is no such driver.
+    pub fn builtin(name: &str) -> Option<Self> {
+        let (_, pattern, ignore_case) = BUILTIN.iter().find(|(driver, _, _)| *driver == name)?;
+        Some(Self::from_xfuncname(pattern, *ignore_case).expect("built-in patterns are valid"))
+    }
+
+    /// Return the names of all built-in diff drivers.
+    pub fn builtin_names() -> impl Iterator<Item = &'static str> {
+        BUILTIN.iter().map(|(name, _, _)| *name)
+    }
+
+    /// Return `true` if `line`, without line terminator, starts a function.
+    ///
+    /// The first expression that matches decides, and if none does, the line isn't a function header.
+    pub fn is_match(&self, line: &BStr) -> bool {
+        self.regexes
+            .iter()
+            .find(|(_, regex)| regex.is_match(line))
+            .is_some_and(|(negated, _)| !negated)
+    }
+}
+
+/// Make the bracket expressions of the POSIX extended regular expression `posix` compatible with the `regex` crate,
+/// which treats `[` and `\` within them specially, unlike POSIX.
+fn posix_to_rust(posix: &str) -> String {
+    let mut out = String::with_capacity(posix.len());
+    let mut chars = posix.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                out.push(c);
+                out.extend(chars.next());
+            }
+            '[' => {
+                out.push(c);
+                if chars.next_if_eq(&'^').is_some() {
+                    out.push('^');
+                }
+                if chars.next_if_eq(&']').is_some() {
+                    out.push_str("\\]");
+                }
+                while let Some(c) = chars.next() {
+                    match c {
+                        ']' => {
+                            out.push(c);
+                            break;
+                        }
+                        '[' if chars.peek() == Some(&':') => {
+                            out.push(c);
+                            for c in chars.by_ref() {
+                                out.push(c);
+                                if c == ']' {
+                                    break;
+                                }
+                            }
+                        }
+                        '[' | '\\' | '&' | '~' => {
+                            out.push('\\');
+                            out.push(c);
+                        }
+                        c => out.push(c),
+                    }
+                }
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// The funcname patterns of Git's built-in diff drivers, along with whether they match case-insensitively.
+const BUILTIN: &[(&str, &str, bool)] = &[
+    (
+        "bash",
+        "^[ \t]*(([a-zA-Z_][a-zA-Z0-9_]*[ \t]*\\([ \t]*\\))|(function[ \t]+[a-zA-Z_][a-zA-Z0-9_]*(([ \t]*\\([ \t]*\\))|([ \t]+)))[ \t]*(\\{|\\(\\(?|\\[\\[))",
+        false,
+    ),
+    (
+        "bibtex",
+        "(@[a-zA-Z]{1,}[ \t]*\\{{0,1}[ \t]*[^ \t\"@',\\#}{~%]*).*$",
+        false,
+    ),
+    (
+        "cpp",
+        "!^[ \t]*[A-Za-z_][A-Za-z_0-9]*:[[:space:]]*($|/[/*])\n\
+         ^((::[[:space:]]*)?[A-Za-z_].*)$",
+        false,
+    ),
+    (
+        "csharp",
+        "!^[ \t]*(do|while|for|if|else|instanceof|new|return|switch|case|throw|catch|using)\n\
+         ^[ \t]*(((static|public|internal|private|protected|new|virtual|sealed|override|unsafe|async)[ \t]+)*[][<>@.~_[:alnum:]]+[ \t]+[<>@._[:alnum:]]+[ \t]*\\(.*\\))[ \t]*$\n\
+         ^[ \t]*(((static|public|internal|private|protected|new|virtual|sealed|override|unsafe)[ \t]+)*[][<>@.~_[:alnum:]]+[ \t]+[@._[:alnum:]]+)[ \t]*$\n\
+         ^[ \t]*(((static|public|internal|private|protected|new|unsafe|sealed|abstract|partial)[ \t]+)*(class|enum|interface|struct|record)[ \t]+.*)$\n\
+         ^[ \t]*(namespace[ \t]+.*)$",
+        false,
+    ),
+    ("css", "![:;][[:space:]]*$\n^[:[@.#]?[_a-z0-9].*$", true),
+    ("dts", "!;\n!=\n^[ \t]*((/[ \t]*\\{|&?[a-zA-Z_]).*)", false),
+    (
+        "elixir",
+        "^[ \t]*((def(macro|module|impl|protocol|p)?|test)[ \t].*)$",
+        false,
+    ),
+    (
+        "golang",
+        "^[ \t]*(func[ \t]*.*(\\{[ \t]*)?)\n\
+         ^[ \t]*(type[ \t].*(struct|interface)[ \t]*(\\{[ \t]*)?)",
+        false,
+    ),
+    ("html", "^[ \t]*(<[Hh][1-6]([ \t].*)?>.*)$", false),
+    (
+        "java",
+        "!^[ \t]*(catch|do|for|if|instanceof|new|return|switch|throw|while)\n\
+         ^[ \t]*(([a-z]+[ \t]+)*(class|enum|interface)[ \t]+[A-Za-z][A-Za-z0-9_$]*[ \t]+.*)$\n\
+         ^[ \t]*(([A-Za-z_<>&][][?&<>.,A-Za-z_0-9]*[ \t]+)+[A-Za-z_][A-Za-z_0-9]*[ \t]*\\([^;]*)$",
+        false,
+    ),
+    (
+        "kotlin",
+        "^[ \t]*(([a-z]+[ \t]+)*(fun|class|interface)[ \t]+.*)$",
+        false,
+    ),
+    ("markdown", "^ {0,3}#{1,6}[ \t].*", false),
+    (
+        "matlab",
+        "^[[:space:]]*((classdef|function)[[:space:]].*)$|^(%%%?|##)[[:space:]].*$",
+        false,
+    ),
+    (
+        "objc",
+        "!^[ \t]*(do|for|if|else|return|switch|while)\n\
+         ^[ \t]*([-+][ \t]*\\([ \t]*[A-Za-z_][A-Za-z_0-9* \t]*\\)[ \t]*[A-Za-z_].*)$\n\
+         ^[ \t]*(([A-Za-z_][A-Za-z_0-9]*[ \t]+)+[A-Za-z_][A-Za-z_0-9]*[ \t]*\\([^;]*)$\n\
+         ^(@(implementation|interface|protocol)[ \t].*)$",
+        false,
+    ),
+    (
+        "pascal",
+        "^(((class[ \t]+)?(procedure|function)|constructor|destructor|interface|implementation|initialization|finalization)[ \t]*.*)$\n\
+         ^(.*=[ \t]*(class|record).*)$",
+        false,
+    ),
+    (
+        "perl",
+        "^package .*\n\
+         ^sub [[:alnum:]_':]+[ \t]*(\\([^)]*\\)[ \t]*)?(:[^;#]*)?(\\{[ \t]*)?(#.*)?$\n\
+         ^(BEGIN|END|INIT|CHECK|UNITCHECK|AUTOLOAD|DESTROY)[ \t]*(\\{[ \t]*)?(#.*)?$\n\
+         ^=head[0-9] .*",
+        false,
+    ),
+    (
+        "php",
+        "^[\t ]*(((public|protected|private|static|abstract|final)[\t ]+)*function.*)$\n\
+         ^[\t ]*((((final|abstract)[\t ]+)?class|enum|interface|trait).*)$",
+        false,
+    ),
+    ("python", "^[ \t]*((class|(async[ \t]+)?def)[ \t].*)$", false),
+    ("ruby", "^[ \t]*((class|module|def)[ \t].*)$", false),
+    (
+        "rust",
+        "^[\t ]*((pub(\\([^\\)]+\\))?[\t ]+)?((async|const|unsafe|extern([\t ]+\"[^\"]+\"))[\t ]+)?(struct|enum|union|mod|trait|fn|impl|macro_rules!)[< \t]+[^;]*)$",
+        false,
+    ),
+    (
+        "scheme",
+        "^[\t ]*(\\(((define|def(struct|syntax|class|method|rules|record|proto|alias)?)[-*/ \t]|(library|module|struct|class)[*+ \t]).*)$",
+        false,
+    ),
+    ("tex", "^(\\\\((sub)*section|chapter|part)\\*{0,1}\\{.*)$", false),
+];
@@ -37,12 +37,18 @@ pub fn diff_with_slider_heuristics<T: AsRef<[u8]>>(algorithm: Algorithm, input:
     diff
 }
 
+/// Identify lines that start a function, as configured with `diff.<driver>.xfuncname` or provided by Git's built-in drivers.
+pub mod funcname;
+
 ///
 pub mod pipeline;
 
 ///
 pub mod platform;
 
+///
+pub mod word_diff;
+
 /// Information about the diff performed to detect similarity.
 #[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
 pub struct DiffLineStats {
@@ -0,0 +1,164 @@
+//! Compute word-level (intra-line) diffs of changed lines, similar to `git diff --word-diff`.
+//!
+//! This is a post-processing step for line-based diffs: lines removed and added by a [`Hunk`]
+//! are split into words, which are then diffed against each other to learn which parts of the lines actually changed.
+use bstr::BString;
+use imara_diff::{Algorithm, Diff, Hunk, InternedInput};
+
+/// Determine how lines are split into words.
+///
+/// Note that tokens always cover the entire line, so that the text of all [segments](Segment) reproduces the original lines.
+#[derive(Default, Debug, Clone, Copy)]
+pub enum Tokenizer {
+    /// Runs of non-whitespace characters are words, and runs of whitespace separate them, similar to Git's default.
+    #[default]
+    Whitespace,
+    /// Runs of alphanumeric characters and `_` are words, along with runs of whitespace, while all other characters
+    /// are a word of their own. Non-ASCII characters are treated as alphanumeric.
+    ///
+    /// This resembles `--word-diff-regex='[[:alnum:]_]+|[^[:space:]]'`.
+    Alphanumeric,
+    /// A custom function which receives the yet unprocessed remainder of a line and returns the length of the next word
+    /// in bytes, which may be used to implement regex-based tokenization.
+    ///
+    /// The returned length is clamped to be at least one byte and at most the length of the input.
+    Custom(fn(&[u8]) -> usize),
+}
+
+/// The kind of change of a [`Segment`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum SegmentKind {
+    /// The text is present in both the old and the new version of the lines.
+    Unchanged,
+    /// The text was only present in the old version of the lines.
+    Removed,
+    /// The text is only present in the new version of the lines.
+    Added,
+}
+
+/// A run of text along with its kind of change.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Segment {
+    /// The kind of change of `text`.
+    pub kind: SegmentKind,
+    /// The text of all consecutive words with the same `kind`.
+    pub text: BString,
+}
+
+/// Compute the word diff of the lines removed and added by `hunk`, which refers to tokens in `input`, splitting each line
+/// with `tokenizer`.
+///
+/// Removed text is placed before the added text replacing it, and consecutive words of the same kind are merged into
+/// a single [`Segment`]. Note that lines are used as is, so line terminators are only part of the output if `input`
+/// kept them.
+pub fn hunk<T: AsRef<[u8]> + ?Sized>(hunk: &Hunk, input: &InternedInput<&T>, tokenizer: Tokenizer) -> Vec<Segment> {
+    let before: Vec<_> = hunk
+        .before
+        .clone()
+        .map(|idx| input.interner[input.before[idx as usize]].as_ref())
+        .flat_map(|line| words(line, tokenizer))
+        .collect();
+    let after: Vec<_> = hunk
+        .after
+        .clone()
+        .map(|idx| input.interner[input.after[idx as usize]].as_ref())
+        .flat_map(|line| words(line, tokenizer))
+        .collect();
+    segments(&before, &after)
+}
+
+/// Compute the word diff between the `before` and `after` version of one or more lines, splitting them with `tokenizer`.
+///
+/// This is useful if the lines to compare were obtained separately, and is otherwise similar to [`hunk()`].
+pub fn compute(before: &[u8], after: &[u8], tokenizer: Tokenizer) -> Vec<Segment> {
+    let before: Vec<_> = words(before, tokenizer).collect();
+    let after: Vec<_> = words(after, tokenizer).collect();
+    segments(&before, &after)
+}
+
+fn segments(before: &[&[u8]], after: &[&[u8]]) -> Vec<Segment> {
+    let mut input = InternedInput::default();
+    input.update_before(before.iter().copied());
+    input.update_after(after.iter().copied());
+    let mut diff = Diff::default();
+    diff.compute_with(
+        Algorithm::Myers,
+        &input.before,
+        &input.after,
+        input.interner.num_tokens(),
+    );
+    diff.postprocess_no_heuristic(&input);
+
+    let mut out = Vec::new();
+    let mut unchanged_start = 0;
+    for Hunk {
+        before: removed,
+        after: added,
+    } in diff.hunks()
+    {
+        push(
+            &mut out,
+            SegmentKind::Unchanged,
+            &before[unchanged_start..removed.start as usize],
+        );
+        push(
+            &mut out,
+            SegmentKind::Removed,
+            &before[removed.start as usize..removed.end as usize],
+        );
+        push(
+            &mut out,
+            SegmentKind::Added,
+            &after[added.start as usize..added.end as usize],
+        );
+        unchanged_start = removed.end as usize;
+    }
+    push(&mut out, SegmentKind::Unchanged, &before[unchanged_start..]);
+    out
+}
+
+fn push(out: &mut Vec<Segment>, kind: SegmentKind, words: &[&[u8]]) {
+    if words.is_empty() {
+        return;
+    }
+    let text = match out.last_mut() {
+        Some(last) if last.kind == kind => &mut last.text,
+        _ => {
+            out.push(Segment {
+                kind,
+                text: BString::default(),
+            });
+            &mut out.last_mut().expect("just pushed").text
+        }
+    };
+    for word in words {
+        text.extend_from_slice(word);
+    }
+}
+
+fn words(mut line: &[u8], tokenizer: Tokenizer) -> impl Iterator<Item = &[u8]> {
+    std::iter::from_fn(move || {
+        if line.is_empty() {
+            return None;
+        }
+        let len = word_len(line, tokenizer).clamp(1, line.len());
+        let (word, rest) = line.split_at(len);
+        line = rest;
+        Some(word)
+    })
+}
+
+fn word_len(line: &[u8], tokenizer: Tokenizer) -> usize {
+    fn run_len(line: &[u8], belongs: impl Fn(u8) -> bool) -> usize {
+        line.iter().position(|b| !belongs(*b)).unwrap_or(line.len())
+    }
+    let is_word_byte = |b: u8| b.is_ascii_alphanumeric() || b == b'_' || !b.is_ascii();
+    let first = line[0];
+    match tokenizer {
+        Tokenizer::Custom(next_word_len) => next_word_len(line),
+        _ if first.is_ascii_whitespace() => run_len(line, |b| b.is_ascii_whitespace()),
+        Tokenizer::Whitespace => run_len(line, |b| !b.is_ascii_whitespace()),
+        Tokenizer::Alphanumeric if is_word_byte(first) => run_len(line, is_word_byte),
+        Tokenizer::Alphanumeric => 1,
+    }
+}
@@ -0,0 +1,29 @@
+use gix_diff::blob::funcname::Patterns;
+
+#[test]
+fn all_builtin_drivers_compile() {
+    assert_eq!(Patterns::builtin_names().count(), 22);
+    for name in Patterns::builtin_names() {
+        assert!(Patterns::builtin(name).is_some(), "{name}");
+    }
+}
+
+#[test]
+fn negated_patterns_reject_lines() {
+    let patterns = Patterns::from_xfuncname("!^skip\n^[a-z]", false).expect("valid");
+    assert!(patterns.is_match("fn main()".into()));
+    assert!(
+        !patterns.is_match("skip this".into()),
+        "the first matching pattern decides"
+    );
+    assert!(
+        !patterns.is_match("  indented".into()),
+        "lines matching no pattern aren't function headers"
+    );
+}
+
+#[test]
+fn invalid_patterns_are_reported() {
+    let err = Patterns::from_xfuncname("^ok\n(unclosed", false).expect_err("invalid");
+    assert_eq!(err.to_string(), "Could not compile the funcname pattern \"(unclosed\"");
+}
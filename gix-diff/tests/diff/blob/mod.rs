@@ -1,4 +1,6 @@
+mod funcname;
 pub(crate) mod pipeline;
 mod platform;
 mod slider;
 mod unified_diff;
+mod word_diff;
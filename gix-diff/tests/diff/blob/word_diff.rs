@@ -0,0 +1,71 @@
+use gix_diff::blob::{
+    Algorithm, Diff, InternedInput,
+    word_diff::{self, Segment, SegmentKind, Tokenizer},
+};
+
+#[test]
+fn changed_lines_of_hunk() {
+    let before = "unchanged\nlet x = foo(1);\nlet y = 2;\n";
+    let after = "unchanged\nlet x = bar(1);\nlet y = 2;\n";
+    let input = InternedInput::new(before.as_bytes(), after.as_bytes());
+    let diff = Diff::compute(Algorithm::Myers, &input);
+    let hunks: Vec<_> = diff.hunks().collect();
+    assert_eq!(hunks.len(), 1, "only the second line changed");
+
+    assert_eq!(
+        rendered(&word_diff::hunk(&hunks[0], &input, Tokenizer::Whitespace)),
+        "let x = [-foo(1);-]{+bar(1);+}\n",
+        "whitespace-delimited words are replaced as a whole"
+    );
+    assert_eq!(
+        rendered(&word_diff::hunk(&hunks[0], &input, Tokenizer::Alphanumeric)),
+        "let x = [-foo-]{+bar+}(1);\n",
+        "punctuation is tokenized separately"
+    );
+}
+
+#[test]
+fn pure_additions_and_removals() {
+    assert_eq!(
+        word_diff::compute(b"", b"new line", Tokenizer::default()),
+        vec![Segment {
+            kind: SegmentKind::Added,
+            text: "new line".into()
+        }]
+    );
+    assert_eq!(
+        word_diff::compute(b"old line", b"", Tokenizer::default()),
+        vec![Segment {
+            kind: SegmentKind::Removed,
+            text: "old line".into()
+        }]
+    );
+    assert_eq!(word_diff::compute(b"", b"", Tokenizer::default()), vec![]);
+}
+
+#[test]
+fn custom_tokenizer() {
+    fn characters(_line: &[u8]) -> usize {
+        1
+    }
+    assert_eq!(
+        rendered(&word_diff::compute(b"colour", b"color", Tokenizer::Custom(characters))),
+        "colo[-u-]r"
+    );
+    assert_eq!(
+        rendered(&word_diff::compute(b"colour", b"color", Tokenizer::Custom(|_| 0))),
+        "colo[-u-]r",
+        "empty words are prevented to assure progress"
+    );
+}
+
+fn rendered(segments: &[Segment]) -> String {
+    segments
+        .iter()
+        .map(|s| match s.kind {
+            SegmentKind::Unchanged => s.text.to_string(),
+            SegmentKind::Removed => format!("[-{}-]", s.text),
+            SegmentKind::Added => format!("{{+{}+}}", s.text),
+        })
+        .collect()
+}
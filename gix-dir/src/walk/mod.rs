@@ -106,6 +106,23 @@ pub enum EmissionMode {
     CollapseDirectory,
 }
 
+/// Control if symlinks to directories are followed during the walk, with the directory they point to traversed
+/// as if it was located at the symlink.
+///
+/// Symlinks that aren't followed are emitted with [`Kind::Symlink`](crate::entry::Kind::Symlink).
+/// No matter the policy, a symlink is never followed if it points into the [git directory](Context::git_dir_realpath),
+/// or to a directory that is currently being traversed, which would lead to infinite recursion.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum SymlinkPolicy {
+    /// Never follow symlinks, which is what Git does.
+    #[default]
+    Never,
+    /// Only follow symlinks if the real path they point to is contained in the real path of the worktree root.
+    WithinWorktree,
+    /// Follow all symlinks to directories, even if they leave the worktree.
+    Always,
+}
+
 /// The way entries that are contained in collapsed directories are emitted using the [Delegate].
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
 pub enum CollapsedEntriesEmissionMode {
@@ -190,6 +207,10 @@ pub struct Options<'a> {
     ///
     /// In other words, for Git compatibility this flag should be `false`, the default, for `git2` compatibility it should be `true`.
     pub symlinks_to_directories_are_ignored_like_directories: bool,
+    /// Control which symlinks to directories are followed, with all of them being emitted as symlink by default.
+    ///
+    /// Followed symlinks are classified like directories, which affects how they are emitted.
+    pub follow_symlinks: SymlinkPolicy,
     /// A set of all git worktree checkouts that are located within the main worktree directory.
     ///
     /// They will automatically be detected as 'tracked', but without providing index information (as there is no actual index entry).
@@ -207,6 +228,7 @@ pub struct Context<'a> {
     ///
     /// It's used to help us differentiate our own `.git` directory from nested unrelated repositories,
     /// which is needed if `core.worktree` is used to nest the `.git` directory deeper within.
+    /// It's also the boundary that [followed symlinks](Options::follow_symlinks) can't cross, independently of their [policy](SymlinkPolicy).
     pub git_dir_realpath: &'a std::path::Path,
     /// The current working directory as returned by `gix_fs::current_dir()` to assure it respects `core.precomposeUnicode`.
     /// It's used to produce the realpath of the git-dir of a repository candidate to assure it's not our own repository.
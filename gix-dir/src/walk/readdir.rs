@@ -13,7 +13,7 @@ use crate::{
     walk::{
         Action, CollapsedEntriesEmissionMode, Context, Delegate,
         EmissionMode::CollapseDirectory,
-        Error, ForDeletionMode, Options, Outcome, classify,
+        Error, ForDeletionMode, Options, Outcome, SymlinkPolicy, classify,
         function::{can_recurse, emit_entry},
     },
 };
@@ -76,25 +76,42 @@ pub(super) fn recursive(
             ctx,
         )?;
 
+        let followed_symlink_parent = (info.disk_kind == Some(entry::Kind::Symlink))
+            .then(|| state.symlink_to_follow(current, opts.follow_symlinks, ctx))
+            .flatten();
+        let recurse_info = if followed_symlink_parent.is_some() {
+            classify::Outcome {
+                disk_kind: Some(entry::Kind::Directory),
+                ..info
+            }
+        } else {
+            info
+        };
         if can_recurse(
             current_bstr.as_bstr(),
-            info,
+            recurse_info,
             opts.for_deletion,
             false, /* is root */
             delegate,
         ) {
             let subdir_may_collapse = state.may_collapse(current);
-            let (action, subdir_prevent_collapse) = recursive(
+            let is_followed_symlink = followed_symlink_parent.is_some();
+            state.followed_symlink_parents.extend(followed_symlink_parent);
+            let res = recursive(
                 subdir_may_collapse,
                 current,
                 current_bstr,
-                info,
+                recurse_info,
                 ctx,
                 opts,
                 delegate,
                 out,
                 state,
-            )?;
+            );
+            if is_followed_symlink {
+                state.followed_symlink_parents.pop();
+            }
+            let (action, subdir_prevent_collapse) = res?;
             prevent_collapse |= subdir_prevent_collapse;
             if action.is_break() {
                 return Ok((action, prevent_collapse));
@@ -144,6 +161,12 @@ pub(super) struct State {
     pub on_hold: Vec<Entry>,
     /// The path the user is currently in, as seen from the workdir root.
     worktree_relative_current_dir: Option<PathBuf>,
+    /// The worktree root, to be turned into its real path once a symlink is encountered.
+    worktree_root: PathBuf,
+    /// The real path of the worktree root, or `None` if it wasn't needed yet.
+    worktree_root_realpath: Option<PathBuf>,
+    /// The real paths of the directories containing the symlinks we are currently traversing through.
+    followed_symlink_parents: Vec<PathBuf>,
 }
 
 impl State {
@@ -179,7 +202,42 @@ impl State {
         Self {
             on_hold: Vec::new(),
             worktree_relative_current_dir,
+            worktree_root: worktree_root.to_owned(),
+            worktree_root_realpath: None,
+            followed_symlink_parents: Vec::new(),
+        }
+    }
+
+    /// Return the real path of the directory containing the `symlink` if it should be followed according to `policy`.
+    ///
+    /// Symlinks are never followed into the git directory, nor to directories that contain any of the directories we are
+    /// currently traversing as that would cause an infinite loop.
+    fn symlink_to_follow(&mut self, symlink: &Path, policy: SymlinkPolicy, ctx: &Context<'_>) -> Option<PathBuf> {
+        if policy == SymlinkPolicy::Never {
+            return None;
+        }
+        let realpath =
+            |path: &Path| gix_path::realpath_opts(path, ctx.current_dir, gix_path::realpath::MAX_SYMLINKS).ok();
+        let target = realpath(symlink).filter(|target| target.is_dir())?;
+        if target.starts_with(ctx.git_dir_realpath) {
+            return None;
+        }
+        if policy == SymlinkPolicy::WithinWorktree {
+            if self.worktree_root_realpath.is_none() {
+                self.worktree_root_realpath = Some(realpath(&self.worktree_root)?);
+            }
+            if !target.starts_with(self.worktree_root_realpath.as_ref()?) {
+                return None;
+            }
+        }
+        let parent = realpath(symlink.parent()?)?;
+        if std::iter::once(&parent)
+            .chain(&self.followed_symlink_parents)
+            .any(|dir| dir.starts_with(&target))
+        {
+            return None;
         }
+        Some(parent)
     }
 
     /// Returns `true` if the worktree-relative `directory_to_traverse` is not the current working directory.
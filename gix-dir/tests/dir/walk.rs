@@ -7,7 +7,7 @@ use gix_dir::{
     walk::{
         CollapsedEntriesEmissionMode::{All, OnStatusMismatch},
         EmissionMode::*,
-        ForDeletionMode,
+        ForDeletionMode, SymlinkPolicy,
     },
 };
 use gix_ignore::Kind::*;
@@ -186,6 +186,64 @@ fn symlink_to_dir_can_be_excluded() -> crate::Result {
     Ok(())
 }
 
+#[test]
+fn symlinks_to_directories_can_be_followed() -> crate::Result {
+    let root = gix_path::realpath(fixture_in("many-symlinks", "symlinks-to-directories"))?;
+    for (policy, expected_read_dir_calls, expected) in [
+        (
+            SymlinkPolicy::Never,
+            2,
+            &[
+                entry("dir/file", Untracked, File),
+                entry("git-dir", Untracked, Symlink),
+                entry("outside", Untracked, Symlink),
+                entry("parent", Untracked, Symlink),
+                entry("within", Untracked, Symlink),
+            ][..],
+        ),
+        (
+            SymlinkPolicy::WithinWorktree,
+            3,
+            &[
+                entry("dir/file", Untracked, File),
+                entry("git-dir", Untracked, Symlink),
+                entry("outside", Untracked, Symlink),
+                entry("parent", Untracked, Symlink),
+                entry("within/file", Untracked, File),
+            ][..],
+        ),
+        (
+            SymlinkPolicy::Always,
+            4,
+            &[
+                entry("dir/file", Untracked, File),
+                entry("git-dir", Untracked, Symlink),
+                entry("outside/file", Untracked, File),
+                entry("parent", Untracked, Symlink),
+                entry("within/file", Untracked, File),
+            ][..],
+        ),
+    ] {
+        let ((out, _root), entries) = collect(&root, None, |keep, ctx| {
+            walk(
+                &root,
+                ctx,
+                gix_dir::walk::Options {
+                    follow_symlinks: policy,
+                    ..options()
+                },
+                keep,
+            )
+        });
+        assert_eq!(out.read_dir_calls, expected_read_dir_calls, "{policy:?}");
+        assert_eq!(
+            entries, expected,
+            "{policy:?}: symlinks into the git directory or to a parent are never followed"
+        );
+    }
+    Ok(())
+}
+
 #[test]
 fn root_may_not_lead_through_symlinks() -> crate::Result {
     for (name, intermediate, expected) in [
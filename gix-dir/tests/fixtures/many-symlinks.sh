@@ -66,3 +66,16 @@ git init -q submodule-symlink
   rm -Rf sub
   ln -s ../module sub
 )
+
+mkdir outside-of-worktree
+touch outside-of-worktree/file
+
+git init -q symlinks-to-directories
+(cd symlinks-to-directories
+  mkdir dir
+  touch dir/file
+  ln -s dir within
+  ln -s ../outside-of-worktree outside
+  ln -s .. parent
+  ln -s .git git-dir
+)
@@ -1,7 +1,8 @@
 use crate::Message;
-use bstr::BString;
+use bstr::{BString, ByteSlice};
 use std::borrow::Cow;
-use std::fmt::{Debug, Display, Formatter};
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
 
 /// An error occurred when validating input.
 ///
@@ -12,6 +13,8 @@ pub struct ValidationError {
     pub message: Cow<'static, str>,
     /// The input or portion of the input that wasn't valid.
     pub input: Option<BString>,
+    /// The range of bytes within `input` that caused the failure, if known.
+    pub span: Option<Range<usize>>,
 }
 
 /// Lifecycle
@@ -21,6 +24,16 @@ impl ValidationError {
         ValidationError {
             message: message.into(),
             input: Some(input.into()),
+            span: None,
+        }
+    }
+
+    /// Create a new error with `message` and `input`, where `span` is the range of bytes within `input` that caused the failure.
+    /// With alternate formatting, i.e. `{:#}`, it's displayed by underlining the offending portion of `input` on a second line.
+    pub fn new_with_span(message: impl Into<Cow<'static, str>>, input: impl Into<BString>, span: Range<usize>) -> Self {
+        ValidationError {
+            span: Some(span),
+            ..Self::new_with_input(message, input)
         }
     }
 
@@ -29,6 +42,7 @@ impl ValidationError {
         ValidationError {
             message: message.into(),
             input: None,
+            span: None,
         }
     }
 }
@@ -38,7 +52,17 @@ impl Display for ValidationError {
         match &self.input {
             None => f.write_str(self.message.as_ref()),
             Some(input) => {
-                write!(f, "{}: {input:?}", self.message)
+                write!(f, "{}: {input:?}", self.message)?;
+                if let Some(span) = self.span.as_ref().filter(|_| f.alternate()) {
+                    // Underline the span within the debug-formatted input, which is escaped and starts with a quote.
+                    let end = span.end.min(input.len());
+                    let start = span.start.min(end);
+                    let escaped_len = |bytes: &[u8]| format!("{:?}", bytes.as_bstr()).chars().count() - 2;
+                    let indent = self.message.chars().count() + 2 + 1 + escaped_len(&input[..start]);
+                    let width = escaped_len(&input[start..end]).max(1);
+                    write!(f, "\n{:indent$}{:^<width$}", "", "")?;
+                }
+                Ok(())
             }
         }
     }
@@ -0,0 +1,129 @@
+//! Implementations of [`miette::Diagnostic`] so our errors can be rendered as `miette` reports.
+//!
+//! As there is no source code for the location at which an error was raised, it's provided as help text,
+//! while the input stored in a [`ValidationError`] is provided as source code with a label spanning its `span`, or all of it.
+use crate::ValidationError;
+use miette::{Diagnostic, LabeledSpan, SourceCode};
+use std::fmt::Display;
+use std::panic::Location;
+
+impl Diagnostic for ValidationError {
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.input.as_ref().map(|input| input as &Vec<u8> as &dyn SourceCode)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let input = self.input.as_ref()?;
+        let span = self.span.clone().unwrap_or(0..input.len());
+        Some(Box::new(std::iter::once(LabeledSpan::new(
+            Some(self.message.to_string()),
+            span.start,
+            span.len(),
+        ))))
+    }
+}
+
+fn as_validation_error<'a>(err: &'a (dyn std::error::Error + 'static)) -> Option<&'a ValidationError> {
+    err.downcast_ref::<ValidationError>()
+}
+
+fn help(location: &'static Location<'static>) -> Option<Box<dyn Display>> {
+    Some(Box::new(format!("at {}:{}", location.file(), location.line())))
+}
+
+mod frame {
+    use super::{as_validation_error, help};
+    use crate::Frame;
+    use miette::{Diagnostic, LabeledSpan, SourceCode};
+    use std::fmt::Display;
+
+    impl Diagnostic for Frame {
+        fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+            help(self.location())
+        }
+
+        fn source_code(&self) -> Option<&dyn SourceCode> {
+            as_validation_error(self.error())?.source_code()
+        }
+
+        fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+            as_validation_error(self.error())?.labels()
+        }
+
+        fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+            if self.children().is_empty() {
+                return None;
+            }
+            Some(Box::new(self.children().iter().map(|f| f as &dyn Diagnostic)))
+        }
+    }
+}
+
+#[cfg(any(feature = "tree-error", not(feature = "auto-chain-error")))]
+mod error {
+    use crate::Error;
+    use miette::{Diagnostic, LabeledSpan, SourceCode};
+    use std::fmt::Display;
+
+    /// Delegate to the topmost frame.
+    impl Diagnostic for Error {
+        fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+            self.inner.frame().help()
+        }
+
+        fn source_code(&self) -> Option<&dyn SourceCode> {
+            self.inner.frame().source_code()
+        }
+
+        fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+            self.inner.frame().labels()
+        }
+
+        fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+            self.inner.frame().related()
+        }
+    }
+}
+
+#[cfg(all(feature = "auto-chain-error", not(feature = "tree-error")))]
+mod error {
+    use crate::Error;
+    use miette::{Diagnostic, LabeledSpan, SourceCode};
+    use std::fmt::Display;
+
+    /// Delegate to the chain, whose sources are provided by [`std::error::Error::source()`].
+    impl Diagnostic for Error {
+        fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+            self.inner.help()
+        }
+
+        fn source_code(&self) -> Option<&dyn SourceCode> {
+            self.inner.source_code()
+        }
+
+        fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+            self.inner.labels()
+        }
+    }
+}
+
+mod chain {
+    use super::{as_validation_error, help};
+    use crate::ChainedError;
+    use miette::{Diagnostic, LabeledSpan, SourceCode};
+    use std::fmt::Display;
+
+    impl Diagnostic for ChainedError {
+        fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+            help(self.location)
+        }
+
+        fn source_code(&self) -> Option<&dyn SourceCode> {
+            as_validation_error(&*self.err)?.source_code()
+        }
+
+        fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+            as_validation_error(&*self.err)?.labels()
+        }
+    }
+}
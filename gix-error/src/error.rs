@@ -16,6 +16,19 @@ mod _impl {
         pub fn sources(&self) -> impl Iterator<Item = &(dyn std::error::Error + 'static)> + '_ {
             self.inner.frame().iter_frames().map(|f| f.error() as _)
         }
+
+        /// Return the topmost error as `T` if it is of that type.
+        pub fn downcast_ref<T: std::error::Error + 'static>(&self) -> Option<&T> {
+            self.inner.frame().error().downcast_ref()
+        }
+
+        /// Iterate over all frames in depth-first order along with their depth, as described in
+        /// [`Frame::iter_depth_first()`](crate::Frame::iter_depth_first()).
+        ///
+        /// Note that this isn't available with the `auto-chain-error` feature, which doesn't retain frames.
+        pub fn frames(&self) -> impl Iterator<Item = (usize, &crate::Frame)> + '_ {
+            self.inner.frame().iter_depth_first()
+        }
     }
 
     pub(crate) enum Inner {
@@ -24,7 +37,7 @@ mod _impl {
     }
 
     impl Inner {
-        fn frame(&self) -> &crate::exn::Frame {
+        pub(crate) fn frame(&self) -> &crate::exn::Frame {
             match self {
                 Inner::ExnAsError(f) | Inner::Exn(f) => f,
             }
@@ -39,6 +52,17 @@ mod _impl {
                 inner: Inner::ExnAsError(Exn::new(error).into()),
             }
         }
+
+        /// Create a new instance from the already boxed `error`, which is stored as is so that its concrete type
+        /// can be recovered with [`downcast_ref()`](Self::downcast_ref()).
+        ///
+        /// Note that only `error` itself is preserved, while all of its sources are stringified and type-erased.
+        #[track_caller]
+        pub fn from_error_boxed(error: Box<dyn std::error::Error + Send + Sync + 'static>) -> Self {
+            Error {
+                inner: Inner::ExnAsError(Box::new(crate::exn::Frame::from_boxed(error))),
+            }
+        }
     }
 
     impl std::fmt::Display for Error {
@@ -104,6 +128,11 @@ mod _impl {
                 err.source()
             })
         }
+
+        /// Return the topmost error as `T` if it is of that type.
+        pub fn downcast_ref<T: std::error::Error + 'static>(&self) -> Option<&T> {
+            self.inner.err.downcast_ref()
+        }
     }
 
     impl Error {
@@ -114,6 +143,17 @@ mod _impl {
                 inner: Exn::new(error).into_chain(),
             }
         }
+
+        /// Create a new instance from the already boxed `error`, which is stored as is so that its concrete type
+        /// can be recovered with [`downcast_ref()`](Self::downcast_ref()).
+        ///
+        /// Note that only `error` itself is preserved, while all of its sources are stringified and type-erased.
+        #[track_caller]
+        pub fn from_error_boxed(error: Box<dyn std::error::Error + Send + Sync + 'static>) -> Self {
+            Error {
+                inner: crate::exn::Frame::from_boxed(error).into_chain(),
+            }
+        }
     }
 
     impl std::fmt::Display for Error {
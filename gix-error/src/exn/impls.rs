@@ -41,29 +41,8 @@ impl<E: Error + Send + Sync + 'static> Exn<E> {
     /// [source chain of the error]: Error::source
     #[track_caller]
     pub fn new(error: E) -> Self {
-        fn walk_sources(error: &dyn Error, location: &'static Location<'static>) -> Vec<Frame> {
-            if let Some(source) = error.source() {
-                let children = vec![Frame {
-                    error: Box::new(SourceError::new(source)),
-                    location,
-                    children: walk_sources(source, location),
-                }];
-                children
-            } else {
-                vec![]
-            }
-        }
-
-        let location = Location::caller();
-        let children = walk_sources(&error, location);
-        let frame = Frame {
-            error: Box::new(error),
-            location,
-            children,
-        };
-
         Self {
-            frame: Box::new(frame),
+            frame: Box::new(Frame::from_boxed(Box::new(error))),
             phantom: PhantomData,
         }
     }
@@ -115,6 +94,17 @@ impl<E: Error + Send + Sync + 'static> Exn<E> {
         self
     }
 
+    /// Sort the direct children of this error by the key returned by `f` for each child [`Frame`], keeping the relative
+    /// order of children with equal keys.
+    ///
+    /// As [`raise_all()`](Self::raise_all()) and [`chain_all()`](Self::chain_all()) retain the order in which errors are
+    /// provided, callers that collect them from sources without stable order, like hash-sets, can use this to
+    /// produce deterministic error trees.
+    pub fn sort_children_by_key<K: Ord>(mut self, f: impl FnMut(&Frame) -> K) -> Self {
+        self.frame.children.sort_by_cached_key(f);
+        self
+    }
+
     /// Drain all sources of this error as untyped [`Exn`].
     ///
     /// This is useful if one wants to re-organise errors, and the error layout is well known.
@@ -198,13 +188,36 @@ impl<E: Error + Send + Sync + 'static> Exn<E> {
         self.frame().iter_frames()
     }
 
+    /// Iterate over all frames in depth-first order along with their depth, as described in
+    /// [`Frame::iter_depth_first()`].
+    pub fn frames(&self) -> impl Iterator<Item = (usize, &Frame)> {
+        self.frame().iter_depth_first()
+    }
+
     /// Iterate over all frames and find one that downcasts into error of type `T`.
     /// Note that the search includes this instance as well.
+    ///
+    /// Only errors that were raised or chained as typed values can be found, while the `source()` errors of errors
+    /// passed to [`Exn::new()`] are stringified and type-erased.
     pub fn downcast_any_ref<T: Error + 'static>(&self) -> Option<&T> {
-        self.iter().find_map(|e| e.error.downcast_ref())
+        downcast_first(self.iter())
+    }
+
+    /// Like [`downcast_any_ref()`](Self::downcast_any_ref()), but only search the frames below this one
+    /// in breadth-first order, to learn if `T` was a cause of this error.
+    ///
+    /// The same limitation applies: `source()` errors of errors passed to [`Exn::new()`] are stringified
+    /// and can't be found.
+    pub fn find_source<T: Error + 'static>(&self) -> Option<&T> {
+        downcast_first(self.iter().skip(1))
     }
 }
 
+/// Return the error of the first of `frames` that downcasts into `T`.
+fn downcast_first<'a, T: Error + 'static>(mut frames: impl Iterator<Item = &'a Frame>) -> Option<&'a T> {
+    frames.find_map(|frame| frame.error.downcast_ref())
+}
+
 impl<E> Deref for Exn<E>
 where
     E: Error + Send + Sync + 'static,
@@ -218,13 +231,71 @@ where
 
 impl<E: Error + Send + Sync + 'static> fmt::Debug for Exn<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write_frame_recursive(f, self.frame(), "", ErrorMode::Display, TreeMode::Linearize)
+        fmt::Debug::fmt(self.frame(), f)
     }
 }
 
 impl fmt::Debug for Frame {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write_frame_recursive(f, self, "", ErrorMode::Display, TreeMode::Linearize)
+        write_frame_recursive(
+            f,
+            self,
+            "",
+            ErrorMode::Display,
+            TreeMode::Linearize,
+            FormatOptions::default(),
+            1,
+        )
+    }
+}
+
+/// Options to limit the size of the error tree when printing it with [`Exn::debug_with()`].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatOptions {
+    /// If set, do not print frames whose depth is larger, with 0 only printing the topmost frame.
+    ///
+    /// The depth is the level of indentation as reported by [`Frame::iter_depth_first()`], so chains of single children,
+    /// which are linearized when printed, have the same depth.
+    pub max_depth: Option<usize>,
+    /// If set, print at most this amount of children of each frame.
+    pub max_children: Option<usize>,
+}
+
+/// A type to print an error tree like the [`Debug`](fmt::Debug) implementation of [`Exn`], but with [`FormatOptions`].
+pub struct DebugWith<'a> {
+    frame: &'a Frame,
+    options: FormatOptions,
+}
+
+impl fmt::Debug for DebugWith<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_frame_recursive(
+            f,
+            self.frame,
+            "",
+            ErrorMode::Display,
+            TreeMode::Linearize,
+            self.options,
+            1,
+        )
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> Exn<E> {
+    /// Return a type whose [`Debug`](fmt::Debug) implementation prints the error tree like ours, but elides frames
+    /// according to `options`.
+    ///
+    /// Elided frames are indicated by a `… (N more)` marker, with `N` being the amount of frames that weren't printed.
+    pub fn debug_with(&self, options: FormatOptions) -> DebugWith<'_> {
+        self.frame().debug_with(options)
+    }
+}
+
+impl Frame {
+    /// Return a type whose [`Debug`](fmt::Debug) implementation prints this frame and its children,
+    /// but elides frames according to `options`. See [`Exn::debug_with()`] for details.
+    pub fn debug_with(&self, options: FormatOptions) -> DebugWith<'_> {
+        DebugWith { frame: self, options }
     }
 }
 
@@ -246,6 +317,8 @@ fn write_frame_recursive(
     prefix: &str,
     err_mode: ErrorMode,
     tree_mode: TreeMode,
+    options: FormatOptions,
+    children_depth: usize,
 ) -> fmt::Result {
     match err_mode {
         ErrorMode::Display => fmt::Display::fmt(frame.error(), f),
@@ -258,8 +331,21 @@ fn write_frame_recursive(
     }
 
     let children = frame.children();
-    let children_len = children.len();
+    if children.is_empty() {
+        return Ok(());
+    }
+    let count_frames = |frames: &[Frame]| -> usize { frames.iter().map(|frame| frame.iter_frames().count()).sum() };
+    if options.max_depth.is_some_and(|max_depth| children_depth > max_depth) {
+        return write!(f, "\n{prefix}|\n{prefix}└─ … ({} more)", count_frames(children));
+    }
 
+    let children_len = children.len();
+    let (children, elided) = match options.max_children {
+        Some(max_children) if max_children < children_len => {
+            (&children[..max_children], count_frames(&children[max_children..]))
+        }
+        _ => (children, 0),
+    };
     for (cidx, child) in children.iter().enumerate() {
         write!(f, "\n{prefix}|")?;
         write!(f, "\n{prefix}└─ ")?;
@@ -267,13 +353,32 @@ fn write_frame_recursive(
         let child_child_len = child.children().len();
         let may_linearize_chain = matches!(tree_mode, TreeMode::Linearize) && children_len == 1 && child_child_len == 1;
         if may_linearize_chain {
-            write_frame_recursive(f, child, prefix, err_mode, tree_mode)?;
+            write_frame_recursive(f, child, prefix, err_mode, tree_mode, options, children_depth)?;
         } else if cidx < children_len - 1 {
-            write_frame_recursive(f, child, &format!("{prefix}|   "), err_mode, tree_mode)?;
+            write_frame_recursive(
+                f,
+                child,
+                &format!("{prefix}|   "),
+                err_mode,
+                tree_mode,
+                options,
+                children_depth + 1,
+            )?;
         } else {
-            write_frame_recursive(f, child, &format!("{prefix}    "), err_mode, tree_mode)?;
+            write_frame_recursive(
+                f,
+                child,
+                &format!("{prefix}    "),
+                err_mode,
+                tree_mode,
+                options,
+                children_depth + 1,
+            )?;
         }
     }
+    if elided != 0 {
+        write!(f, "\n{prefix}|\n{prefix}└─ … ({elided} more)")?;
+    }
 
     Ok(())
 }
@@ -288,13 +393,24 @@ impl fmt::Display for Frame {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if f.alternate() {
             // Avoid printing alternate versions of the debug info, keep it in one line, also print the tree.
-            write_frame_recursive(f, self, "", ErrorMode::Debug, TreeMode::Verbatim)
+            write_frame_recursive(
+                f,
+                self,
+                "",
+                ErrorMode::Debug,
+                TreeMode::Verbatim,
+                FormatOptions::default(),
+                1,
+            )
         } else {
             fmt::Display::fmt(self.error(), f)
         }
     }
 }
 
+/// Note that no [source](Error::source()) is provided as a frame may have multiple [children](Frame::children()).
+impl Error for Frame {}
+
 /// A frame in the exception tree.
 pub struct Frame {
     /// The error that occurred at this frame.
@@ -322,6 +438,47 @@ impl Frame {
     }
 }
 
+impl Frame {
+    /// Create a new frame that stores `error` as is, with all of its sources as children.
+    #[track_caller]
+    pub(crate) fn from_boxed(error: Box<dyn Error + Send + Sync + 'static>) -> Self {
+        fn walk_sources(error: &dyn Error, location: &'static Location<'static>) -> Vec<Frame> {
+            if let Some(source) = error.source() {
+                let children = vec![Frame {
+                    error: Box::new(SourceError::new(source)),
+                    location,
+                    children: walk_sources(source, location),
+                }];
+                children
+            } else {
+                vec![]
+            }
+        }
+
+        let location = Location::caller();
+        let children = walk_sources(&*error, location);
+        Frame {
+            error,
+            location,
+            children,
+        }
+    }
+
+    /// Convert this frame and all of its children into a chain of errors, breadth first.
+    pub(crate) fn into_chain(self) -> ChainedError {
+        let Frame {
+            error,
+            location,
+            children,
+        } = self;
+        ChainedError {
+            err: error,
+            location,
+            source: recurse_source_frames(children.into()),
+        }
+    }
+}
+
 /// Navigation
 impl Frame {
     /// Find the best possible cause:
@@ -378,6 +535,18 @@ impl Frame {
         if std::ptr::addr_eq(res, self) { None } else { Some(res) }
     }
 
+    /// Iterate over all frames in depth-first order along with their depth, starting with this instance at depth 0.
+    ///
+    /// Frames are visited in the same order as they are printed by the [`Debug`](fmt::Debug) implementation,
+    /// and the depth is their level of indentation there. Thus, chains of single children, which are linearized
+    /// when printed, have the same depth.
+    /// This is also the depth that [`FormatOptions::max_depth`] refers to.
+    pub fn iter_depth_first(&self) -> impl Iterator<Item = (usize, &Frame)> + '_ {
+        DepthFirstFrames {
+            stack: vec![(self, 0, 1)],
+        }
+    }
+
     /// Iterate over all frames in breadth-first order. The first frame is this instance,
     /// followed by all of its children.
     pub fn iter_frames(&self) -> impl Iterator<Item = &Frame> + '_ {
@@ -387,6 +556,31 @@ impl Frame {
     }
 }
 
+/// Depth-first iterator over `Frame`s along with their depth.
+pub struct DepthFirstFrames<'a> {
+    /// Frames to visit along with their depth and the depth of their children, in reverse order.
+    stack: Vec<(&'a Frame, usize, usize)>,
+}
+
+impl<'a> Iterator for DepthFirstFrames<'a> {
+    type Item = (usize, &'a Frame);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (frame, depth, children_depth) = self.stack.pop()?;
+        let children = frame.children();
+        for child in children.iter().rev() {
+            // Keep this in sync with `write_frame_recursive()` and its linearization of chains.
+            let grand_children_depth = if children.len() == 1 && child.children().len() == 1 {
+                children_depth
+            } else {
+                children_depth + 1
+            };
+            self.stack.push((child, children_depth, grand_children_depth));
+        }
+        Some((depth, frame))
+    }
+}
+
 /// Breadth-first iterator over `Frame`s.
 pub struct BreadthFirstFrames<'a> {
     queue: std::collections::VecDeque<&'a Frame>,
@@ -520,14 +714,8 @@ impl<E> From<Exn<E>> for ChainedError
 where
     E: std::error::Error + Send + Sync + 'static,
 {
-    fn from(mut err: Exn<E>) -> Self {
-        let stack: VecDeque<_> = err.frame.children.drain(..).collect();
-        let location = err.frame.location;
-        ChainedError {
-            err: err.into_box(),
-            location,
-            source: recurse_source_frames(stack),
-        }
+    fn from(err: Exn<E>) -> Self {
+        err.frame.into_chain()
     }
 }
 
@@ -20,10 +20,15 @@ mod ext;
 pub use ext::{ErrorExt, OptionExt, ResultExt};
 
 mod impls;
-pub use impls::{Frame, Something, Untyped};
+pub use impls::{DebugWith, FormatOptions, Frame, Something, Untyped};
 
 mod macros;
 
+#[cfg(feature = "serde")]
+mod serializable;
+#[cfg(feature = "serde")]
+pub use serializable::SerializableFrame;
+
 /// An exception type that can hold an [error tree](Exn::raise_all) and the call site.
 ///
 /// While an error chain, a list, is automatically created when [raise](Exn::raise)
@@ -0,0 +1,33 @@
+use crate::Frame;
+
+/// A plain representation of a [`Frame`] and all of its children, for serialization with `serde`.
+///
+/// Errors are stringified with their [`Display`](std::fmt::Display) implementation, so they don't have to
+/// implement `serde::Serialize` themselves.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize)]
+pub struct SerializableFrame {
+    /// The message of the error at this frame.
+    pub message: String,
+    /// The source file in which this frame was created.
+    pub file: String,
+    /// The line in `file` at which this frame was created.
+    pub line: u32,
+    /// The column in `file` at which this frame was created.
+    pub column: u32,
+    /// The frames of all errors that caused this one.
+    pub children: Vec<SerializableFrame>,
+}
+
+impl Frame {
+    /// Convert this frame and all of its children into a representation that implements `serde::Serialize`.
+    pub fn to_serializable(&self) -> SerializableFrame {
+        let location = self.location();
+        SerializableFrame {
+            message: self.error().to_string(),
+            file: location.file().to_owned(),
+            line: location.line(),
+            column: location.column(),
+            children: self.children().iter().map(Frame::to_serializable).collect(),
+        }
+    }
+}
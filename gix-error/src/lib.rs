@@ -311,7 +311,9 @@
 mod exn;
 
 pub use bstr;
-pub use exn::{ErrorExt, Exn, Frame, OptionExt, ResultExt, Something, Untyped};
+#[cfg(feature = "serde")]
+pub use exn::SerializableFrame;
+pub use exn::{DebugWith, ErrorExt, Exn, FormatOptions, Frame, OptionExt, ResultExt, Something, Untyped};
 
 /// An error type that wraps an inner type-erased boxed `std::error::Error` or an `Exn` frame.
 ///
@@ -348,6 +350,9 @@ pub use concrete::chain::ChainedError;
 pub use concrete::message::{Message, message};
 pub use concrete::validate::ValidationError;
 
+#[cfg(feature = "miette")]
+mod diagnostic;
+
 pub(crate) fn write_location(f: &mut std::fmt::Formatter<'_>, location: &std::panic::Location) -> std::fmt::Result {
     write!(f, ", at {}:{}", location.file(), location.line())
 }
@@ -94,3 +94,13 @@ pub fn debug_string(input: impl std::fmt::Debug) -> String {
 fn fixup_paths(input: String) -> String {
     if cfg!(windows) { input.replace('\\', "/") } else { input }
 }
+
+#[test]
+fn from_boxed_error_keeps_its_type() {
+    let err = Error::from_error_boxed(Box::new(std::io::Error::from(std::io::ErrorKind::NotFound)));
+    assert_eq!(
+        err.downcast_ref::<std::io::Error>().map(std::io::Error::kind),
+        Some(std::io::ErrorKind::NotFound),
+        "typed information is recoverable from the chain as well"
+    );
+}
@@ -132,6 +132,37 @@ fn from_any_error_with_source() {
     );
 }
 
+#[test]
+fn from_boxed_error_keeps_its_type() {
+    let boxed: Box<dyn std::error::Error + Send + Sync> = Box::new(ErrorWithSource("main", message("one")));
+    let err = Error::from_error_boxed(boxed);
+    assert_eq!(err.to_string(), "main");
+    assert!(
+        err.downcast_ref::<ErrorWithSource>().is_some(),
+        "the boxed error itself is stored by value and can be downcast"
+    );
+    assert_eq!(
+        err.source()
+            .map(|err| err.downcast_ref::<gix_error::Message>().is_some()),
+        Some(false),
+        "sources are still stringified"
+    );
+    assert_eq!(err.source().map(debug_string).as_deref(), Some(r#"Message("one")"#));
+
+    let err = Error::from_error_boxed(Box::new(std::io::Error::from(std::io::ErrorKind::NotFound)));
+    assert_eq!(
+        err.downcast_ref::<std::io::Error>().map(std::io::Error::kind),
+        Some(std::io::ErrorKind::NotFound),
+        "typed information is recoverable"
+    );
+    assert!(
+        Error::from_error(message("one"))
+            .downcast_ref::<gix_error::Message>()
+            .is_some(),
+        "it also works for errors that weren't boxed"
+    );
+}
+
 #[test]
 fn validation_error_displays_input_with_debug_formatting() {
     let err = ValidationError::new_with_input("invalid input", "hello\n ");
@@ -141,3 +172,64 @@ fn validation_error_displays_input_with_debug_formatting() {
         "it won't hide whitespace and other special characters"
     );
 }
+
+#[test]
+fn miette_diagnostic() {
+    use miette::Diagnostic;
+
+    let err: Error = ValidationError::new_with_input("invalid input", "hello")
+        .raise_all([message("one").raise(), message("two").raise()])
+        .into();
+    assert!(
+        err.help().is_some_and(|help| help.to_string().starts_with("at ")),
+        "the location is provided as help"
+    );
+    assert!(err.source_code().is_some(), "the input is the source code");
+    let labels: Vec<_> = err.labels().expect("the input is labelled").collect();
+    assert_eq!(labels.len(), 1);
+    assert_eq!(labels[0].label(), Some("invalid input"));
+    assert_eq!(
+        (labels[0].offset(), labels[0].len()),
+        (0, 5),
+        "the label spans the whole input"
+    );
+
+    #[cfg(any(feature = "tree-error", not(feature = "auto-chain-error")))]
+    assert_eq!(
+        err.related()
+            .map(|related| related.map(ToString::to_string).collect::<Vec<_>>()),
+        Some(vec!["one".to_owned(), "two".to_owned()]),
+        "children are related diagnostics"
+    );
+
+    let err = Error::from_error(message("no input"));
+    assert!(err.source_code().is_none() && err.labels().is_none());
+}
+
+#[test]
+fn validation_error_underlines_span() {
+    let err = ValidationError::new_with_span("unexpected character", "HEAD~1^{tre}", 8..11);
+    assert_eq!(
+        err.to_string(),
+        "unexpected character: \"HEAD~1^{tre}\"",
+        "by default, errors are displayed on a single line"
+    );
+    assert_eq!(
+        format!("{err:#}"),
+        "unexpected character: \"HEAD~1^{tre}\"\n                               ^^^",
+        "the caret line is aligned with the span within the quoted input"
+    );
+    assert_eq!(err.span, Some(8..11));
+
+    let err = ValidationError::new_with_span("unexpected end", "a\tb", 3..3);
+    assert_eq!(
+        format!("{err:#}"),
+        "unexpected end: \"a\\tb\"\n                     ^",
+        "escapes are accounted for, and empty spans are still visible"
+    );
+    assert_eq!(
+        ValidationError::new_with_input("invalid", "a").span,
+        None,
+        "other constructors don't set a span"
+    );
+}
@@ -697,3 +697,224 @@ fn causes_display(err: &(dyn std::error::Error + 'static), style: Style) -> Vec<
     }
     out
 }
+
+#[test]
+fn sort_children_by_key() {
+    let e = message("Top")
+        .raise_all(
+            ["C", "A", "B"]
+                .into_iter()
+                .map(|name| message(name).raise_all([message!("{name}-0")])),
+        )
+        .sort_children_by_key(|frame| frame.error().to_string());
+    insta::assert_debug_snapshot!(e, @r"
+    Top
+    |
+    └─ A
+    |   |
+    |   └─ A-0
+    |
+    └─ B
+    |   |
+    |   └─ B-0
+    |
+    └─ C
+        |
+        └─ C-0
+    ");
+}
+
+#[test]
+fn frames_are_depth_first_like_debug_output() {
+    fn frames_from_debug_output(e: &impl std::fmt::Debug) -> Vec<(usize, String)> {
+        format!("{e:#?}")
+            .lines()
+            .filter(|line| !line.trim_end().ends_with('|'))
+            .map(|line| match line.find("└─ ") {
+                Some(pos) => (pos / 4 + 1, line[pos + "└─ ".len()..].to_owned()),
+                None => (0, line.to_owned()),
+            })
+            .collect()
+    }
+    fn frames(e: &Exn<Message>) -> Vec<(usize, String)> {
+        e.frames()
+            .map(|(depth, frame)| (depth, frame.error().to_string()))
+            .collect()
+    }
+
+    let chain = message("A").raise().raise(message("B")).raise(message("C"));
+    assert_eq!(
+        frames(&chain),
+        [(0, "C".into()), (1, "B".into()), (1, "A".into())],
+        "linearized chains have the same depth"
+    );
+    assert_eq!(frames(&chain), frames_from_debug_output(&chain));
+
+    let tree = new_tree_error().raise(message("topmost"));
+    let expected = frames_from_debug_output(&tree);
+    assert_eq!(expected.len(), 13, "all frames are present");
+    assert_eq!(frames(&tree), expected, "order and depth match what users see");
+    #[cfg(any(feature = "tree-error", not(feature = "auto-chain-error")))]
+    assert_eq!(
+        gix_error::Error::from(tree)
+            .frames()
+            .map(|(depth, frame)| (depth, frame.error().to_string()))
+            .collect::<Vec<_>>(),
+        expected,
+        "`Error` provides the same view"
+    );
+}
+
+#[test]
+fn debug_with_limits() {
+    let e = message("Top").raise_all(
+        (1..5).map(|idx| message!("E{}", idx).raise_all((0..idx).map(|sidx| message!("E{}-{}", idx, sidx)))),
+    );
+    let options = gix_error::FormatOptions {
+        max_depth: None,
+        max_children: Some(2),
+    };
+    insta::assert_debug_snapshot!(e.debug_with(options), @r"
+    Top
+    |
+    └─ E1
+    |   |
+    |   └─ E1-0
+    |
+    └─ E2
+    |   |
+    |   └─ E2-0
+    |   |
+    |   └─ E2-1
+    |
+    └─ … (9 more)
+    ");
+
+    let options = gix_error::FormatOptions {
+        max_depth: Some(1),
+        max_children: Some(3),
+    };
+    insta::assert_debug_snapshot!(e.debug_with(options), @r"
+    Top
+    |
+    └─ E1
+    |   |
+    |   └─ … (1 more)
+    |
+    └─ E2
+    |   |
+    |   └─ … (2 more)
+    |
+    └─ E3
+    |   |
+    |   └─ … (3 more)
+    |
+    └─ … (5 more)
+    ");
+
+    let options = gix_error::FormatOptions {
+        max_depth: Some(0),
+        max_children: None,
+    };
+    insta::assert_debug_snapshot!(e.debug_with(options), @r"
+    Top
+    |
+    └─ … (14 more)
+    ");
+    assert_eq!(
+        format!("{:#?}", e.debug_with(Default::default())),
+        format!("{e:#?}"),
+        "without limits, the output is unchanged"
+    );
+
+    let chain = message("A").raise().raise(message("B")).raise(message("C"));
+    assert_eq!(
+        chain.frames().map(|(depth, _)| depth).max(),
+        Some(1),
+        "linearized chains have the same depth…"
+    );
+    let options = gix_error::FormatOptions {
+        max_depth: Some(1),
+        max_children: None,
+    };
+    assert_eq!(
+        format!("{:#?}", chain.debug_with(options)),
+        format!("{chain:#?}"),
+        "…which is the depth limited by `max_depth`"
+    );
+    let options = gix_error::FormatOptions {
+        max_depth: Some(0),
+        max_children: None,
+    };
+    insta::assert_debug_snapshot!(chain.debug_with(options), @r"
+    C
+    |
+    └─ … (2 more)
+    ");
+}
+
+#[test]
+fn find_source() {
+    let err = gix_error::ValidationError::new_with_input("invalid hash", "abc")
+        .raise()
+        .raise(message("could not parse reference"))
+        .raise(message("could not read refs"));
+    assert_eq!(
+        err.find_source::<gix_error::ValidationError>()
+            .map(|err| err.message.as_ref()),
+        Some("invalid hash"),
+        "typed errors are found at any depth"
+    );
+    assert!(
+        err.find_source::<Message>()
+            .is_some_and(|err| err.to_string() == "could not parse reference"),
+        "the first match in breadth-first order is returned"
+    );
+    assert!(
+        message("top").raise().find_source::<Message>().is_none(),
+        "the error itself isn't a source"
+    );
+
+    let err = Exn::new(ErrorWithSource("top", message("source"))).raise(message("outer"));
+    assert!(
+        err.find_source::<ErrorWithSource>().is_some(),
+        "the error passed to `Exn::new()` is typed"
+    );
+    assert!(
+        err.find_source::<Message>().is_none(),
+        "but its sources were stringified"
+    );
+}
+
+#[test]
+fn serialize_frame_tree() {
+    let err = Exn::raise_all([message("one").raise(), message("two").raise()], message("top"));
+    let frame = err.frame().to_serializable();
+    assert_eq!(frame.message, "top");
+    assert_eq!(fixup_paths(frame.file.clone()), "gix-error/tests/error/exn.rs");
+    assert_eq!(
+        frame.children.iter().map(|f| f.message.as_str()).collect::<Vec<_>>(),
+        ["one", "two"],
+        "children are retained in order"
+    );
+
+    let json = serde_json::to_value(&frame.children[0]).expect("serialization can't fail");
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "message": "one",
+            "file": frame.children[0].file,
+            "line": frame.children[0].line,
+            "column": frame.children[0].column,
+            "children": [],
+        })
+    );
+}
+
+#[test]
+fn frames_are_errors_with_any_feature_set() {
+    let e = message("A").raise().raise(message("B"));
+    let frame: &dyn std::error::Error = e.frame();
+    assert_eq!(frame.to_string(), "B");
+    assert!(frame.source().is_none(), "children aren't sources as there may be many");
+}
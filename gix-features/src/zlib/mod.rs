@@ -33,6 +33,16 @@ impl Decompress {
         self.0.reset(true);
     }
 
+    /// Set the preset `dictionary` the stream was compressed with, which is only possible right after
+    /// [`decompress()`](Self::decompress()) failed with [`DecompressError::NeedDict`].
+    ///
+    /// Decompression continues with the input that wasn't consumed yet, as indicated by [`total_in()`](Self::total_in()).
+    /// It's an error to call this at any other time, or with a dictionary that differs from the one used for compression.
+    pub fn set_dictionary(&mut self, dictionary: &[u8]) -> Result<(), DecompressError> {
+        self.0.set_dictionary(dictionary)?;
+        Ok(())
+    }
+
     /// Decompress `input` and write all decompressed bytes into `output`, with `flush` defining some details about this.
     pub fn decompress(
         &mut self,
@@ -131,6 +141,10 @@ pub mod inflate {
         Inflate(#[from] super::DecompressError),
         #[error("The zlib status indicated an error, status was '{0:?}'")]
         Status(super::Status),
+        #[error("Expected {expected} decompressed bytes, but the stream ended after {actual} bytes")]
+        TooFewBytes { expected: usize, actual: usize },
+        #[error("Expected {expected} decompressed bytes, but the stream produced more")]
+        TooManyBytes { expected: usize },
     }
 }
 
@@ -154,11 +168,61 @@ impl Inflate {
         ))
     }
 
+    /// Decompress the entire zlib stream in `input` into a newly allocated buffer of exactly `expected_size` bytes,
+    /// typically known from an object header, and return it.
+    ///
+    /// It's an error if the stream decompresses into more or fewer bytes than `expected_size`, or if `input` ends before
+    /// the stream does. This instance is [reset](Self::reset()) before use.
+    pub fn decompress_to_vec(&mut self, input: &[u8], expected_size: usize) -> Result<Vec<u8>, inflate::Error> {
+        self.reset();
+        let mut out = vec![0; expected_size];
+        let (status, consumed_in, consumed_out) = self.decompress_finish(input, &mut out)?;
+        match status {
+            Status::StreamEnd if consumed_out == expected_size => Ok(out),
+            Status::StreamEnd => Err(inflate::Error::TooFewBytes {
+                expected: expected_size,
+                actual: consumed_out,
+            }),
+            Status::Ok | Status::BufError if consumed_out == expected_size => {
+                // The output is full, but the stream may still be finished by the remaining input without producing bytes.
+                let mut probe = [0_u8; 1];
+                match self.decompress_finish(&input[consumed_in..], &mut probe)? {
+                    (_, _, produced) if produced != 0 => Err(inflate::Error::TooManyBytes {
+                        expected: expected_size,
+                    }),
+                    (Status::StreamEnd, _, _) => Ok(out),
+                    (status, _, _) => Err(inflate::Error::Status(status)),
+                }
+            }
+            Status::Ok | Status::BufError => Err(inflate::Error::Status(status)),
+        }
+    }
+
+    fn decompress_finish(&mut self, input: &[u8], out: &mut [u8]) -> Result<(Status, usize, usize), inflate::Error> {
+        let before_in = self.state.total_in();
+        let before_out = self.state.total_out();
+        let status = self.state.decompress(input, out, FlushDecompress::Finish)?;
+        Ok((
+            status,
+            (self.state.total_in() - before_in) as usize,
+            (self.state.total_out() - before_out) as usize,
+        ))
+    }
+
     /// Ready this instance for decoding another data stream.
     pub fn reset(&mut self) {
         self.state.reset();
     }
+
+    /// Provide the preset `dictionary` after decompression failed with [`DecompressError::NeedDict`],
+    /// as described in [`Decompress::set_dictionary()`].
+    pub fn set_dictionary(&mut self, dictionary: &[u8]) -> Result<(), inflate::Error> {
+        Ok(self.state.set_dictionary(dictionary)?)
+    }
 }
 
 ///
 pub mod stream;
+
+#[cfg(test)]
+mod tests;
@@ -18,7 +18,7 @@ where
 {
     fn clone(&self) -> Self {
         Write {
-            compressor: impls::new_compress(),
+            compressor: impls::new_compress(self.compressor.level),
             inner: self.inner.clone(),
             buf: self.buf,
         }
@@ -26,7 +26,10 @@ where
 }
 
 /// Hold all state needed for compressing data.
-pub struct Compress(zlib_rs::Deflate);
+pub struct Compress {
+    inner: zlib_rs::Deflate,
+    level: i32,
+}
 
 impl Default for Compress {
     fn default() -> Self {
@@ -37,25 +40,60 @@ impl Default for Compress {
 impl Compress {
     /// The number of bytes that were read from the input.
     pub fn total_in(&self) -> u64 {
-        self.0.total_in()
+        self.inner.total_in()
     }
 
     /// The number of compressed bytes that were written to the output.
     pub fn total_out(&self) -> u64 {
-        self.0.total_out()
+        self.inner.total_out()
+    }
+
+    /// The compression level used for the current stream.
+    pub fn level(&self) -> i32 {
+        self.level
     }
 
-    /// Create a new instance - this allocates so should be done with care.
+    /// Create a new instance with the fastest compression level - this allocates so should be done with care.
     pub fn new() -> Self {
-        let config = zlib_rs::DeflateConfig::best_speed();
+        Self::new_unchecked(zlib_rs::DeflateConfig::best_speed().level)
+    }
+
+    /// Create a new instance compressing with `level`, from `0` for no compression to `9` for the best compression.
+    ///
+    /// Like [`new()`](Self::new()), this allocates.
+    pub fn with_level(level: i32) -> Result<Self, CompressError> {
+        Ok(Self::new_unchecked(validated_level(level)?))
+    }
+
+    fn new_unchecked(level: i32) -> Self {
+        let config = zlib_rs::DeflateConfig::new(level);
         let header = true;
         let inner = zlib_rs::Deflate::new(config.level, header, config.window_bits as u8);
-        Self(inner)
+        Compress { inner, level }
     }
 
-    /// Prepare the instance for a new stream.
+    /// Prepare the instance for a new stream, keeping the current compression level.
     pub fn reset(&mut self) {
-        self.0.reset();
+        self.inner.reset();
+    }
+
+    /// Prepare the instance for a new stream compressed with `level`, from `0` for no compression to `9` for the best one,
+    /// without reallocating.
+    pub fn reset_with(&mut self, level: i32) -> Result<(), CompressError> {
+        let level = validated_level(level)?;
+        self.inner.reset();
+        self.inner.set_level(level)?;
+        self.level = level;
+        Ok(())
+    }
+
+    /// Use `dictionary` as preset dictionary for the current stream, which must happen before the first call
+    /// to [`compress()`](Self::compress()) after creation or a [reset](Self::reset()).
+    ///
+    /// Setting it at any other time is an error, and as it's not retained across resets, it must be set for each stream.
+    pub fn set_dictionary(&mut self, dictionary: &[u8]) -> Result<(), CompressError> {
+        self.inner.set_dictionary(dictionary)?;
+        Ok(())
     }
 
     /// Compress `input` and write compressed bytes to `output`, with `flush` controlling additional characteristics.
@@ -67,7 +105,7 @@ impl Compress {
             FlushCompress::Full => zlib_rs::DeflateFlush::FullFlush,
             FlushCompress::Finish => zlib_rs::DeflateFlush::Finish,
         };
-        let status = self.0.compress(input, output, flush)?;
+        let status = self.inner.compress(input, output, flush)?;
         match status {
             zlib_rs::Status::Ok => Ok(Status::Ok),
             zlib_rs::Status::BufError => Ok(Status::BufError),
@@ -86,6 +124,16 @@ pub enum CompressError {
     DataError,
     #[error("Not enough memory")]
     InsufficientMemory,
+    #[error("The compression level must be between 0 and 9, got {level}")]
+    InvalidLevel { level: i32 },
+}
+
+fn validated_level(level: i32) -> Result<i32, CompressError> {
+    if (0..=9).contains(&level) {
+        Ok(level)
+    } else {
+        Err(CompressError::InvalidLevel { level })
+    }
 }
 
 impl From<zlib_rs::DeflateError> for CompressError {
@@ -148,30 +196,68 @@ mod impls {
     use crate::zlib::Status;
     use crate::zlib::stream::deflate::{self, Compress, FlushCompress};
 
-    pub(crate) fn new_compress() -> Compress {
-        Compress::new()
+    pub(crate) fn new_compress(level: i32) -> Compress {
+        Compress::new_unchecked(level)
     }
 
     impl<W> deflate::Write<W>
     where
         W: io::Write,
     {
-        /// Create a new instance writing compressed bytes to `inner`.
+        /// Create a new instance writing compressed bytes to `inner`, using the fastest compression level.
         pub fn new(inner: W) -> deflate::Write<W> {
+            Self::from_compressor(Compress::new(), inner)
+        }
+
+        /// Create a new instance writing bytes compressed with `level` to `inner`, with `level` ranging from `0`
+        /// for no compression to `9` for the best compression.
+        pub fn with_level(inner: W, level: i32) -> Result<deflate::Write<W>, deflate::CompressError> {
+            Ok(Self::from_compressor(Compress::with_level(level)?, inner))
+        }
+
+        fn from_compressor(compressor: Compress, inner: W) -> deflate::Write<W> {
             deflate::Write {
-                compressor: new_compress(),
+                compressor,
                 inner,
                 buf: [0; deflate::BUF_SIZE],
             }
         }
 
-        /// Reset the compressor, starting a new compression stream.
+        /// Reset the compressor, starting a new compression stream with the same compression level.
         ///
         /// That way multiple streams can be written to the same inner writer.
+        /// Note that this also resets [`total_in()`](Self::total_in()) and [`total_out()`](Self::total_out()) to zero.
         pub fn reset(&mut self) {
             self.compressor.reset();
         }
 
+        /// Like [`reset()`](Self::reset()), but compress the new stream with `level`, reusing the existing compressor.
+        ///
+        /// `level` ranges from `0` for no compression to `9` for the best compression.
+        /// [Clones](Clone) will use this level as well.
+        pub fn reset_with(&mut self, level: i32) -> Result<(), deflate::CompressError> {
+            self.compressor.reset_with(level)
+        }
+
+        /// Use `dictionary` as preset dictionary for the current stream to improve the compression of small inputs
+        /// that are similar to it.
+        ///
+        /// It must be called right after creation or a [reset](Self::reset()), before the first write,
+        /// as it fails otherwise. Decompression requires [the same dictionary](crate::zlib::Decompress::set_dictionary()).
+        pub fn set_dictionary(&mut self, dictionary: &[u8]) -> Result<(), deflate::CompressError> {
+            self.compressor.set_dictionary(dictionary)
+        }
+
+        /// The number of uncompressed bytes that were consumed in the current stream.
+        pub fn total_in(&self) -> u64 {
+            self.compressor.total_in()
+        }
+
+        /// The number of compressed bytes that were written to the inner writer in the current stream.
+        pub fn total_out(&self) -> u64 {
+            self.compressor.total_out()
+        }
+
         /// Consume `self` and return the inner writer.
         pub fn into_inner(self) -> W {
             self.inner
@@ -7,42 +7,14 @@ mod deflate_stream {
     use bstr::ByteSlice;
 
     use crate::zlib::Decompress;
-    use crate::zlib::stream::deflate;
-
-    /// Provide streaming decompression using the `std::io::Read` trait.
-    /// If `std::io::BufReader` is used, an allocation for the input buffer will be performed.
-    struct InflateReader<R> {
-        inner: R,
-        decompressor: Decompress,
-    }
-
-    impl<R> InflateReader<R>
-    where
-        R: io::BufRead,
-    {
-        pub fn from_read(read: R) -> InflateReader<R> {
-            InflateReader {
-                decompressor: Decompress::new(),
-                inner: read,
-            }
-        }
-    }
-
-    impl<R> io::Read for InflateReader<R>
-    where
-        R: io::BufRead,
-    {
-        fn read(&mut self, into: &mut [u8]) -> io::Result<usize> {
-            crate::zlib::stream::inflate::read(&mut self.inner, &mut self.decompressor, into)
-        }
-    }
+    use crate::zlib::stream::{deflate, inflate};
 
     #[test]
     fn small_file_decompress() -> Result<(), Box<dyn std::error::Error>> {
         fn fixture_path(path: &str) -> std::path::PathBuf {
             std::path::PathBuf::from("tests/fixtures").join(path)
         }
-        let r = InflateReader::from_read(io::BufReader::new(std::fs::File::open(fixture_path(
+        let r = inflate::Read::new(io::BufReader::new(std::fs::File::open(fixture_path(
             "objects/37/d4e6c5c48ba0d245164c4e10d5f41140cab980",
         ))?));
         #[allow(clippy::unbuffered_bytes)]
@@ -53,6 +25,53 @@ mod deflate_stream {
         Ok(())
     }
 
+    #[test]
+    fn inflate_read_stops_at_the_end_of_the_stream() -> Result<(), Box<dyn std::error::Error>> {
+        let mut w = deflate::Write::new(Vec::new());
+        w.write_all(b"hello world")?;
+        w.flush()?;
+        let compressed_len = w.inner.len();
+        let mut input = w.inner;
+        input.extend_from_slice(b"trailer");
+
+        let mut r = inflate::Read::new(input.as_slice());
+        let mut out = Vec::new();
+        r.read_to_end(&mut out)?;
+        assert_eq!(out.as_bstr(), b"hello world".as_bstr());
+        assert_eq!((r.total_in(), r.total_out()), (compressed_len as u64, 11));
+        assert_eq!(
+            r.into_inner().as_bstr(),
+            b"trailer".as_bstr(),
+            "data after the stream is left untouched"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn inflate_read_fails_on_truncated_or_corrupt_input() -> Result<(), Box<dyn std::error::Error>> {
+        let mut w = deflate::Write::new(Vec::new());
+        w.write_all(b"hello world")?;
+        w.flush()?;
+
+        let truncated = &w.inner[..w.inner.len() - 4];
+        let err = inflate::Read::new(truncated).read_to_end(&mut Vec::new()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+        let mut corrupt = w.inner.clone();
+        corrupt[0] = 0;
+        let err = inflate::Read::new(corrupt.as_slice())
+            .read_to_end(&mut Vec::new())
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(
+            err.get_ref()
+                .and_then(|err| err.downcast_ref::<crate::zlib::DecompressError>())
+                .is_some(),
+            "the original error is available"
+        );
+        Ok(())
+    }
+
     #[test]
     fn all_at_once() -> Result<(), Box<dyn std::error::Error>> {
         let mut w = deflate::Write::new(Vec::new());
@@ -65,9 +84,135 @@ mod deflate_stream {
         assert_deflate_buffer(out, b"hello")
     }
 
+    #[test]
+    fn total_in_and_out_per_stream() -> Result<(), Box<dyn std::error::Error>> {
+        let mut w = deflate::Write::new(Vec::new());
+        assert_eq!((w.total_in(), w.total_out()), (0, 0), "nothing was written yet");
+        w.write_all(b"hello world")?;
+        w.flush()?;
+        assert_eq!(w.total_in(), 11);
+        assert_eq!(w.total_out(), w.inner.len() as u64, "all compressed bytes were written");
+        let first_stream_len = w.inner.len();
+
+        w.reset();
+        assert_eq!((w.total_in(), w.total_out()), (0, 0), "a reset starts a new stream");
+        w.write_all(b"hello")?;
+        w.flush()?;
+        assert_eq!(w.total_in(), 5);
+        assert_eq!(
+            first_stream_len + w.total_out() as usize,
+            w.inner.len(),
+            "only the compressed bytes of the current stream are counted"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn reset_with_changes_the_level_of_the_next_stream() -> Result<(), Box<dyn std::error::Error>> {
+        let input = b"hello hello hello hello hello hello hello hello".repeat(64);
+        let mut w = deflate::Write::new(Vec::new());
+        w.reset_with(0)?;
+        w.write_all(&input)?;
+        w.flush()?;
+        let stored_len = w.inner.len();
+        assert!(stored_len > input.len(), "level 0 stores the data uncompressed");
+        assert_deflate_buffer(std::mem::take(&mut w.inner), &input)?;
+
+        w.reset_with(9)?;
+        w.write_all(&input)?;
+        w.flush()?;
+        assert!(
+            w.inner.len() < stored_len / 10,
+            "the best level compresses repetitive data well"
+        );
+        assert_deflate_buffer(std::mem::take(&mut w.inner), &input)?;
+
+        w.reset();
+        w.write_all(&input)?;
+        w.flush()?;
+        assert!(w.inner.len() < stored_len / 10, "a plain reset keeps the level");
+        assert_deflate_buffer(w.inner, &input)?;
+        Ok(())
+    }
+
+    #[test]
+    fn reset_with_rejects_invalid_levels() {
+        let mut w = deflate::Write::new(Vec::new());
+        assert!(w.reset_with(10).is_err());
+        assert_eq!(w.compressor.level(), 1, "the previous level is kept");
+    }
+
+    #[test]
+    fn with_level_validates_and_is_preserved_by_clone() -> Result<(), Box<dyn std::error::Error>> {
+        for invalid in [-1, 10] {
+            assert!(matches!(
+                deflate::Write::with_level(Vec::<u8>::new(), invalid),
+                Err(deflate::CompressError::InvalidLevel { level }) if level == invalid
+            ));
+        }
+
+        let input = b"hello hello hello hello hello hello hello hello".repeat(64);
+        let w = deflate::Write::with_level(Vec::new(), 0)?;
+        let mut clone = w.clone();
+        assert_eq!(clone.compressor.level(), 0, "the level is cloned as well");
+        clone.write_all(&input)?;
+        clone.flush()?;
+        assert!(clone.inner.len() > input.len(), "level 0 stores the data uncompressed");
+        assert_deflate_buffer(clone.inner, &input)?;
+
+        let mut w = deflate::Write::with_level(Vec::new(), 9)?;
+        w.write_all(&input)?;
+        w.flush()?;
+        assert!(w.inner.len() < input.len() / 10);
+        assert_deflate_buffer(w.inner, &input)
+    }
+
+    #[test]
+    fn preset_dictionary_roundtrip() -> Result<(), Box<dyn std::error::Error>> {
+        let dictionary = br#"{"name": "", "kind": "", "size": }"#;
+        let input = br#"{"name": "a", "kind": "blob", "size": 42}"#;
+
+        let mut w = deflate::Write::new(Vec::new());
+        w.write_all(input)?;
+        w.flush()?;
+        let len_without_dictionary = w.inner.len();
+
+        w.inner.clear();
+        w.reset();
+        w.set_dictionary(dictionary)?;
+        w.write_all(input)?;
+        assert!(
+            w.set_dictionary(dictionary).is_err(),
+            "it's an error to set the dictionary once the stream was started"
+        );
+        w.flush()?;
+        assert!(
+            w.inner.len() < len_without_dictionary,
+            "the dictionary helps with small inputs"
+        );
+
+        let compressed = w.inner;
+        let mut decompress = Decompress::new();
+        let mut out = vec![0; input.len()];
+        assert!(matches!(
+            decompress.decompress(&compressed, &mut out, crate::zlib::FlushDecompress::Finish),
+            Err(crate::zlib::DecompressError::NeedDict)
+        ));
+        assert!(
+            decompress.set_dictionary(b"wrong").is_err(),
+            "the dictionary must match the one used for compression"
+        );
+        decompress.set_dictionary(dictionary)?;
+        let consumed = decompress.total_in() as usize;
+        let status = decompress.decompress(&compressed[consumed..], &mut out, crate::zlib::FlushDecompress::Finish)?;
+        assert_eq!(status, crate::zlib::Status::StreamEnd);
+        assert_eq!(out.as_bstr(), input.as_bstr());
+        Ok(())
+    }
+
     fn assert_deflate_buffer(out: Vec<u8>, expected: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
         let mut actual = Vec::new();
-        InflateReader::from_read(out.as_slice()).read_to_end(&mut actual)?;
+        inflate::Read::new(out.as_slice()).read_to_end(&mut actual)?;
         assert_eq!(actual, expected);
         Ok(())
     }
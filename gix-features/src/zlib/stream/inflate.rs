@@ -3,7 +3,12 @@ use std::{io, io::BufRead};
 use crate::zlib::{Decompress, FlushDecompress, Status};
 
 /// Read bytes from `rd` and decompress them using `state` into a pre-allocated fitting buffer `dst`, returning the amount of bytes written.
-pub fn read(rd: &mut impl BufRead, state: &mut Decompress, mut dst: &mut [u8]) -> io::Result<usize> {
+pub fn read(rd: &mut impl BufRead, state: &mut Decompress, dst: &mut [u8]) -> io::Result<usize> {
+    read_inner(rd, state, dst).map(|(written, _stream_ended)| written)
+}
+
+/// Like [`read()`], but also return `true` if the end of the zlib stream was reached.
+fn read_inner(rd: &mut impl BufRead, state: &mut Decompress, mut dst: &mut [u8]) -> io::Result<(usize, bool)> {
     let mut total_written = 0;
     loop {
         let (written, consumed, ret, eof);
@@ -27,14 +32,82 @@ pub fn read(rd: &mut impl BufRead, state: &mut Decompress, mut dst: &mut [u8]) -
 
         match ret {
             // The stream has officially ended, nothing more to do here.
-            Ok(Status::StreamEnd) => return Ok(total_written),
+            Ok(Status::StreamEnd) => return Ok((total_written, true)),
             // Either input our output are depleted even though the stream is not depleted yet.
-            Ok(Status::Ok | Status::BufError) if eof || dst.is_empty() => return Ok(total_written),
+            Ok(Status::Ok | Status::BufError) if eof || dst.is_empty() => return Ok((total_written, false)),
             // Some progress was made in both the input and the output, it must continue to reach the end.
             Ok(Status::Ok | Status::BufError) if consumed != 0 || written != 0 => continue,
             // A strange state, where zlib makes no progress but isn't done either. Call it out.
             Ok(Status::Ok | Status::BufError) => unreachable!("Definitely a bug somewhere"),
-            Err(..) => return Err(io::Error::new(io::ErrorKind::InvalidInput, "corrupt deflate stream")),
+            Err(err) => return Err(io::Error::new(io::ErrorKind::InvalidInput, err)),
+        }
+    }
+}
+
+/// A utility to decompress a zlib stream read from a [`BufRead`] via its [Read][std::io::Read] implementation.
+///
+/// The end of the zlib stream is the end of the data returned by it, even if there is more data in the inner reader.
+/// If the inner reader is depleted before the zlib stream ends, an error of kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof)
+/// is returned, while a corrupt stream causes an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput)
+/// with the [`DecompressError`](crate::zlib::DecompressError) as source.
+pub struct Read<R> {
+    inner: R,
+    decompressor: Decompress,
+    stream_ended: bool,
+}
+
+impl<R> Read<R>
+where
+    R: BufRead,
+{
+    /// Create a new instance reading compressed bytes from `inner`.
+    pub fn new(inner: R) -> Self {
+        Self::from_decompressor(Decompress::new(), inner)
+    }
+
+    /// Create a new instance reading compressed bytes from `inner` and decompressing them with `decompressor`,
+    /// which is [reset](Decompress::reset()) to start a new stream. This avoids allocating a new decompressor.
+    pub fn from_decompressor(mut decompressor: Decompress, inner: R) -> Self {
+        decompressor.reset();
+        Read {
+            inner,
+            decompressor,
+            stream_ended: false,
+        }
+    }
+
+    /// The number of compressed bytes that were consumed from the inner reader.
+    pub fn total_in(&self) -> u64 {
+        self.decompressor.total_in()
+    }
+
+    /// The number of decompressed bytes that were produced so far.
+    pub fn total_out(&self) -> u64 {
+        self.decompressor.total_out()
+    }
+
+    /// Consume `self` and return the inner reader, positioned right after the end of the zlib stream if it was read entirely.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R> io::Read for Read<R>
+where
+    R: BufRead,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.stream_ended || buf.is_empty() {
+            return Ok(0);
+        }
+        let (written, stream_ended) = read_inner(&mut self.inner, &mut self.decompressor, buf)?;
+        self.stream_ended = stream_ended;
+        if written == 0 && !stream_ended {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "the input ended before the end of the zlib stream",
+            ));
         }
+        Ok(written)
     }
 }
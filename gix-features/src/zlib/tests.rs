@@ -0,0 +1,45 @@
+mod inflate_decompress_to_vec {
+    use std::io::Write;
+
+    use crate::zlib::{Inflate, inflate, stream::deflate};
+
+    #[test]
+    fn decompress_to_vec_with_exact_size() -> Result<(), Box<dyn std::error::Error>> {
+        let mut w = deflate::Write::new(Vec::new());
+        w.write_all(b"hello world")?;
+        w.flush()?;
+        let compressed = w.into_inner();
+
+        let mut inflate = Inflate::default();
+        assert_eq!(inflate.decompress_to_vec(&compressed, 11)?, b"hello world");
+        assert!(
+            matches!(
+                inflate.decompress_to_vec(&compressed, 12),
+                Err(inflate::Error::TooFewBytes {
+                    expected: 12,
+                    actual: 11
+                })
+            ),
+            "streams that end early are detected"
+        );
+        assert!(
+            matches!(
+                inflate.decompress_to_vec(&compressed, 10),
+                Err(inflate::Error::TooManyBytes { expected: 10 })
+            ),
+            "streams that produce more than expected are detected"
+        );
+        assert!(
+            inflate
+                .decompress_to_vec(&compressed[..compressed.len() - 5], 11)
+                .is_err(),
+            "truncated input is an error"
+        );
+        assert_eq!(
+            inflate.decompress_to_vec(&compressed, 11)?,
+            b"hello world",
+            "the instance can be reused after errors"
+        );
+        Ok(())
+    }
+}
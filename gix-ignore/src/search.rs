@@ -17,6 +17,10 @@ pub struct Match<'a> {
     pub source: Option<&'a Path>,
     /// The kind of pattern this match represents.
     pub kind: crate::Kind,
+    /// If `true`, the `pattern` is negated like in `!pattern`, so the matching path is re-included, i.e. not ignored.
+    ///
+    /// This is the same as [`pattern.is_negative()`](gix_glob::Pattern::is_negative()).
+    pub is_negative: bool,
     /// The line at which the pattern was found in its `source` file, or the occurrence in which it was provided.
     pub sequence_number: usize,
 }
@@ -152,6 +156,7 @@ pub fn pattern_matching_relative_path<'a>(
                 .then_some(Match {
                     pattern,
                     kind: *kind,
+                    is_negative: pattern.is_negative(),
                     source: list.source.as_deref(),
                     sequence_number: *sequence_number,
                 })
@@ -200,4 +205,30 @@ impl Search {
             .rev()
             .find_map(|pl| pattern_matching_relative_path(pl, relative_path, basename_pos, is_dir, case))
     }
+
+    /// Remember `case` for use in [`Cased::pattern_matching_relative_path_cased()`], typically derived from `core.ignoreCase`.
+    pub fn with_case(self, case: gix_glob::pattern::Case) -> Cased {
+        Cased { search: self, case }
+    }
+}
+
+/// A [`Search`] along with the case-sensitivity to use when matching, as created by [`Search::with_case()`].
+#[derive(PartialEq, Eq, Debug, Hash, Ord, PartialOrd, Clone, Default)]
+pub struct Cased {
+    /// The search to perform.
+    pub search: Search,
+    /// Whether cases should be folded during matching or not.
+    pub case: gix_glob::pattern::Case,
+}
+
+impl Cased {
+    /// Like [`Search::pattern_matching_relative_path()`], but uses the case-sensitivity stored in this instance.
+    pub fn pattern_matching_relative_path_cased(
+        &self,
+        relative_path: &BStr,
+        is_dir: Option<bool>,
+    ) -> Option<Match<'_>> {
+        self.search
+            .pattern_matching_relative_path(relative_path, is_dir, self.case)
+    }
 }
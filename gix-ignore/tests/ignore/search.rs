@@ -106,6 +106,7 @@ fn baseline_from_git_dir() -> crate::Result {
                         pattern: _,
                         source,
                         kind: gix_ignore::Kind::Expendable,
+                        is_negative: _,
                     }),
                     Some((expected_source, line, _expected_pattern)),
                 ) => {
@@ -153,6 +154,38 @@ fn from_overrides_with_excludes() {
         )),
         "Now the negative pattern matches - the sequence numbers are 1-based"
     );
+    assert!(
+        group
+            .pattern_matching_relative_path("simple".into(), None, gix_glob::pattern::Case::Sensitive)
+            .is_some_and(|m| m.is_negative),
+        "the last matching pattern wins, and it re-includes the path"
+    );
+}
+
+#[test]
+fn stored_case_is_used_by_cased_lookups() {
+    let group = gix_ignore::Search::from_overrides(["simple"], Default::default());
+    let cased = group.clone().with_case(Default::default());
+    assert_eq!(cased.case, gix_glob::pattern::Case::Sensitive, "the default");
+    assert!(
+        cased
+            .pattern_matching_relative_path_cased("Simple".into(), None)
+            .is_none()
+    );
+
+    let cased = group.with_case(gix_glob::pattern::Case::Fold);
+    assert!(
+        cased
+            .pattern_matching_relative_path_cased("Simple".into(), None)
+            .is_some()
+    );
+    assert!(
+        cased
+            .search
+            .pattern_matching_relative_path("Simple".into(), None, gix_glob::pattern::Case::Sensitive)
+            .is_none(),
+        "the case can still be overridden per lookup"
+    );
 }
 
 #[test]
@@ -187,5 +220,6 @@ fn pattern_to_match(pattern: &gix_glob::Pattern, sequence_number: usize, kind: g
         source: None,
         sequence_number,
         kind,
+        is_negative: pattern.is_negative(),
     }
 }
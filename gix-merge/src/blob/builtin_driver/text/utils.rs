@@ -419,10 +419,9 @@ pub fn take_intersecting(
     input.push(iter.next()?);
     intersecting.clear();
 
+    /// Like Git, we consider hunks that merely touch each other as overlapping, so changes to adjacent lines conflict.
     fn left_overlaps_right(left: &Hunk, right: &Hunk) -> bool {
-        left.side != right.side
-            && (right.before.contains(&left.before.start)
-                || (right.before.is_empty() && right.before.start == left.before.start))
+        left.side != right.side && (right.before.contains(&left.before.start) || right.before.end == left.before.start)
     }
 
     loop {
@@ -11,6 +11,13 @@ pub struct Options {
     pub is_virtual_ancestor: bool,
     /// Determine how to resolve conflicts. If `None`, no conflict resolution is possible, and it picks a side.
     pub resolve_binary_with: Option<builtin_driver::binary::ResolveWith>,
+    /// If `Some(true)`, always perform a [binary merge](crate::blob::BuiltinDriver::Binary) with the builtin drivers,
+    /// which picks a side and never produces conflict markers.
+    /// If `Some(false)`, merge buffers as configured even if they contain NUL bytes and would be considered binary otherwise.
+    /// Note that resources that are too large to be loaded are always merged as binary.
+    ///
+    /// If `None`, buffers are merged as binary if one of them seems to be binary.
+    pub treat_as_binary: Option<bool>,
     /// Options for the builtin [text driver](crate::blob::BuiltinDriver::Text).
     pub text: builtin_driver::text::Options,
 }
@@ -326,14 +333,20 @@ pub(super) mod inner {
                 let base = self.ancestor.data.as_slice().unwrap_or_default();
                 let ours = self.current.data.as_slice().unwrap_or_default();
                 let theirs = self.other.data.as_slice().unwrap_or_default();
-                let driver = if driver != BuiltinDriver::Binary
-                    && (is_binary_buf(self.ancestor.data)
-                        || is_binary_buf(self.other.data)
-                        || is_binary_buf(self.current.data))
-                {
-                    BuiltinDriver::Binary
-                } else {
-                    driver
+                let driver = match self.options.treat_as_binary {
+                    Some(true) => BuiltinDriver::Binary,
+                    treat_as_binary => {
+                        let check_content = treat_as_binary.is_none();
+                        if driver != BuiltinDriver::Binary
+                            && (is_binary_buf(self.ancestor.data, check_content)
+                                || is_binary_buf(self.other.data, check_content)
+                                || is_binary_buf(self.current.data, check_content))
+                        {
+                            BuiltinDriver::Binary
+                        } else {
+                            driver
+                        }
+                    }
                 };
                 match driver {
                     BuiltinDriver::Text => {
@@ -377,9 +390,11 @@ pub(super) mod inner {
             }
         }
 
-        fn is_binary_buf(data: resource::Data<'_>) -> bool {
+        /// Return `true` if `data` must be merged as binary, and if `check_content` is `true`, also if it seems binary.
+        fn is_binary_buf(data: resource::Data<'_>, check_content: bool) -> bool {
             match data {
                 Data::Missing => false,
+                Data::Buffer(_) if !check_content => false,
                 Data::Buffer(buf) => {
                     let buf = &buf[..buf.len().min(8000)];
                     buf.contains(&0)
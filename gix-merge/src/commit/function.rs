@@ -18,7 +18,8 @@ use crate::{
 /// merge-bases of `our_commit` and `their_commit`.
 ///
 /// The `graph` is used to find the merge-base between `our_commit` and `their_commit`, and can also act as cache
-/// to speed up subsequent merge-base queries.
+/// to speed up subsequent merge-base queries. It's not used if [`merge_base`](Options::merge_base) is set, unless
+/// merge-bases need to be merged recursively.
 ///
 /// Use `abbreviate_hash(id)` to shorten the given `id` according to standard git shortening rules. It's used in case
 /// the ancestor-label isn't explicitly set so that the merge base label becomes the shortened `id`.
@@ -53,7 +54,10 @@ pub fn commit<'objects>(
     abbreviate_hash: &mut dyn FnMut(&gix_hash::oid) -> String,
     options: Options,
 ) -> Result<super::Outcome<'objects>, Error> {
-    let merge_bases = gix_revision::merge_base(our_commit, &[their_commit], graph)?;
+    let merge_bases = match options.merge_base {
+        Some(merge_base) => Some(nonempty::NonEmpty::new(merge_base)),
+        None => gix_revision::merge_base(our_commit, &[their_commit], graph)?,
+    };
     let mut virtual_merge_bases = Vec::new();
     let mut state = gix_diff::tree::State::default();
     let mut commit_to_tree =
@@ -65,13 +69,13 @@ pub fn commit<'objects>(
             abbreviate_hash(base_commit.first()).into(),
         ),
         Some(base_commits) => {
-            let virtual_base_tree = if options.use_first_merge_base {
+            let virtual_base_tree = if options.use_first_merge_base || options.max_recursion == Some(0) {
                 commit_to_tree(*base_commits.first())?
             } else {
                 let mut base_commits: Vec<_> = base_commits.into();
                 let first = base_commits.pop().expect("at least two");
                 let second = base_commits.pop().expect("at least one left");
-                let out = crate::commit::virtual_merge_base(
+                let out = crate::commit::virtual_merge_base::function::virtual_merge_base_with_max_recursion(
                     first,
                     second,
                     base_commits,
@@ -81,6 +85,7 @@ pub fn commit<'objects>(
                     objects,
                     abbreviate_hash,
                     options.tree_merge.clone(),
+                    options.max_recursion.map(|depth| depth - 1),
                 )?;
                 virtual_merge_bases = Vec::from(out.virtual_merge_bases);
                 out.tree_id
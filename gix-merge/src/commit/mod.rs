@@ -30,6 +30,16 @@ pub struct Options {
     // TODO: test
     #[doc(alias = "no_recursive", alias = "git2")]
     pub use_first_merge_base: bool,
+    /// If `Some(commit_id)`, use `commit_id` as the only merge-base instead of computing the merge-bases of both commits.
+    ///
+    /// This is useful if the merge-base is already known, for instance when rebasing onto a known commit.
+    pub merge_base: Option<gix_hash::ObjectId>,
+    /// If `Some(depth)`, limit how deeply merge-bases are merged recursively if merging them requires merging their
+    /// own merge-bases. Once the limit is reached, the first merge-base is used instead, just like
+    /// [`use_first_merge_base`](Self::use_first_merge_base) does.
+    ///
+    /// `Some(0)` thus never creates virtual merge-bases, while `None` allows unbounded recursion.
+    pub max_recursion: Option<usize>,
 }
 
 /// The result of [`commit()`](crate::commit()).
@@ -53,6 +63,52 @@ pub struct Outcome<'a> {
     pub virtual_merge_bases: Vec<gix_hash::ObjectId>,
 }
 
+/// The merge-base that was used by [`commit()`](crate::commit()), as returned by [`Outcome::merge_base()`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MergeBase {
+    /// An actual commit was used as merge-base, either because it was the only one,
+    /// or because it was the first of multiple merge-bases which weren't merged.
+    Real(gix_hash::ObjectId),
+    /// A virtual merge-base was created by recursively merging multiple merge-bases.
+    Virtual {
+        /// The merge-bases that were merged into one.
+        from: Vec<gix_hash::ObjectId>,
+        /// The merged tree of all merge-bases, which was used as base of the merge.
+        tree: gix_hash::ObjectId,
+    },
+}
+
+impl MergeBase {
+    /// Determine the merge-base from the `merge_bases`, `virtual_merge_bases` and `merge_base_tree_id` fields of an [`Outcome`],
+    /// or return `None` if there was no merge-base.
+    pub fn from_outcome_parts(
+        merge_bases: Option<&nonempty::NonEmpty<gix_hash::ObjectId>>,
+        virtual_merge_bases: &[gix_hash::ObjectId],
+        merge_base_tree_id: gix_hash::ObjectId,
+    ) -> Option<Self> {
+        let merge_bases = merge_bases?;
+        Some(if virtual_merge_bases.is_empty() {
+            MergeBase::Real(*merge_bases.first())
+        } else {
+            MergeBase::Virtual {
+                from: merge_bases.iter().copied().collect(),
+                tree: merge_base_tree_id,
+            }
+        })
+    }
+}
+
+impl Outcome<'_> {
+    /// Return the merge-base that was used, or `None` if there was no merge-base and an empty tree was used instead.
+    pub fn merge_base(&self) -> Option<MergeBase> {
+        MergeBase::from_outcome_parts(
+            self.merge_bases.as_ref(),
+            &self.virtual_merge_bases,
+            self.merge_base_tree_id,
+        )
+    }
+}
+
 pub(super) mod function;
 
 ///
@@ -47,6 +47,33 @@ pub(super) mod function {
     /// Note that most of `options` are overwritten to match the requirements of a merge-base merge.
     #[allow(clippy::too_many_arguments)]
     pub fn virtual_merge_base<'objects>(
+        first_commit: gix_hash::ObjectId,
+        second_commit: gix_hash::ObjectId,
+        others: Vec<gix_hash::ObjectId>,
+        graph: &mut gix_revwalk::Graph<'_, '_, gix_revwalk::graph::Commit<gix_revision::merge_base::Flags>>,
+        diff_resource_cache: &mut gix_diff::blob::Platform,
+        blob_merge: &mut crate::blob::Platform,
+        objects: &'objects (impl gix_object::FindObjectOrHeader + gix_object::Write),
+        abbreviate_hash: &mut dyn FnMut(&gix_hash::oid) -> String,
+        options: crate::tree::Options,
+    ) -> Result<super::Outcome, crate::commit::Error> {
+        virtual_merge_base_with_max_recursion(
+            first_commit,
+            second_commit,
+            others,
+            graph,
+            diff_resource_cache,
+            blob_merge,
+            objects,
+            abbreviate_hash,
+            options,
+            None,
+        )
+    }
+
+    /// Like [`virtual_merge_base()`], but pass `max_recursion` to limit how deeply merge-bases of merge-bases are merged.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn virtual_merge_base_with_max_recursion<'objects>(
         first_commit: gix_hash::ObjectId,
         second_commit: gix_hash::ObjectId,
         mut others: Vec<gix_hash::ObjectId>,
@@ -56,6 +83,7 @@ pub(super) mod function {
         objects: &'objects (impl gix_object::FindObjectOrHeader + gix_object::Write),
         abbreviate_hash: &mut dyn FnMut(&gix_hash::oid) -> String,
         mut options: crate::tree::Options,
+        max_recursion: Option<usize>,
     ) -> Result<super::Outcome, crate::commit::Error> {
         let mut merged_commit_id = first_commit;
         others.push(second_commit);
@@ -88,6 +116,8 @@ pub(super) mod function {
                     allow_missing_merge_base: false,
                     tree_merge: options.clone(),
                     use_first_merge_base: false,
+                    merge_base: None,
+                    max_recursion,
                 },
             )?;
             // This shouldn't happen, but if for some buggy reason it does, we rather bail.
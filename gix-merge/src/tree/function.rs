@@ -11,7 +11,7 @@ use gix_object::{
 use crate::tree::{
     Conflict, ConflictIndexEntry, ConflictIndexEntryPathHint, ConflictMapping,
     ConflictMapping::{Original, Swapped},
-    ContentMerge, Error, Options, Outcome, Resolution, ResolutionFailure, ResolveWith,
+    ContentMerge, Error, Options, Outcome, Resolution, ResolutionFailure, ResolveWith, Rewrite,
     utils::{
         ChangeList, ChangeListRef, PossibleConflict, TrackedChange, TreeNodes, apply_change, perform_blob_merge,
         possibly_rewritten_location, rewrite_location_with_renamed_directory, to_components, track,
@@ -129,6 +129,9 @@ where
         their_tree.track_change(&change.inner, idx);
     }
 
+    let our_rewrites = rewrites(&our_changes);
+    let their_rewrites = rewrites(&their_changes);
+
     let mut conflicts = Vec::new();
     let mut failed_on_first_conflict = false;
     let mut should_fail_on_conflict = |mut conflict: Conflict| -> bool {
@@ -1267,9 +1270,31 @@ where
         tree: editor,
         conflicts,
         failed_on_first_unresolved_conflict: failed_on_first_conflict,
+        our_rewrites,
+        their_rewrites,
     })
 }
 
+fn rewrites(changes: &ChangeListRef) -> Vec<Rewrite> {
+    changes
+        .iter()
+        .filter_map(|change| match &change.inner {
+            Change::Rewrite {
+                source_location,
+                location,
+                diff,
+                copy: false,
+                ..
+            } => Some(Rewrite {
+                source_location: source_location.clone(),
+                location: location.clone(),
+                similarity: diff.map_or(1.0, |diff| diff.similarity),
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
 fn apply_our_resolution(
     local_ours: &Change,
     local_theirs: &Change,
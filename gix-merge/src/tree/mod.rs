@@ -43,6 +43,25 @@ pub struct Outcome<'a> {
     /// This also makes this outcome a very partial merge that cannot be completed.
     /// Only set if [`fail_on_conflict`](Options::fail_on_conflict) is `true`.
     pub failed_on_first_unresolved_conflict: bool,
+    /// The renames detected between the *base* tree and *our* tree.
+    /// Only set if [`rewrites`](Options::rewrites) is `Some(_)`.
+    pub our_rewrites: Vec<Rewrite>,
+    /// The renames detected between the *base* tree and *their* tree.
+    /// Only set if [`rewrites`](Options::rewrites) is `Some(_)`.
+    pub their_rewrites: Vec<Rewrite>,
+}
+
+/// A rename of a file or directory on one side of the merge, as detected by rename tracking.
+///
+/// Note that copies aren't listed as they are treated like additions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rewrite {
+    /// The location in the *base* tree.
+    pub source_location: BString,
+    /// The location in the tree of the side that performed the rename.
+    pub location: BString,
+    /// The similarity of the content at `source_location` and `location`, from `0.0` to `1.0` if they are identical.
+    pub similarity: f32,
 }
 
 /// Determine what should be considered an unresolved conflict.
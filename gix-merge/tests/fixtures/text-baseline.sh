@@ -384,6 +384,22 @@ C
 EOF
 )
 
+# all sides share the trailing newline and the lines around the conflict
+mkdir zdiff3-common-prefix-and-suffix
+(cd zdiff3-common-prefix-and-suffix
+  write_lines 1 2 3 4 5 >base.blob
+  write_lines 1 2 X A B C 4 5 >ours.blob
+  write_lines 1 2 Y A B C 4 5 >theirs.blob
+)
+
+# one side removes everything that the other side changes
+mkdir zdiff3-one-side-empty
+(cd zdiff3-one-side-empty
+  write_lines 1 2 3 >base.blob
+  write_lines 1 2 3 4 >ours.blob
+  : >theirs.blob
+)
+
 mkdir no-change-add
 (cd no-change-add
 
@@ -666,6 +682,8 @@ for dir in  simple-conflict-3 \
             zdiff3-middlecommon \
             zdiff3-interesting \
             zdiff3-evil \
+            zdiff3-common-prefix-and-suffix \
+            zdiff3-one-side-empty \
             no-change-add \
             no-change-remove \
             line-ending-change \
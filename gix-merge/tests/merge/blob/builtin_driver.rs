@@ -204,8 +204,8 @@ mod text {
     fn run_baseline() -> crate::Result {
         let root = gix_testtools::scripted_fixture_read_only("text-baseline.sh")?;
         for (baseline, diverging, expected_percentage) in [
-            ("baseline.cases", DIVERGING, 10),
-            ("baseline-reversed.cases", DIVERGING_REVERSED, 10),
+            ("baseline.cases", DIVERGING, 9),
+            ("baseline-reversed.cases", DIVERGING_REVERSED, 9),
         ] {
             let cases = std::fs::read_to_string(root.join(baseline))?;
             let mut out = Vec::new();
@@ -357,6 +357,81 @@ mod text {
         }
     }
 
+    #[test]
+    fn union_only_applies_to_conflicts() {
+        let options = builtin_driver::text::Options {
+            conflict: builtin_driver::text::Conflict::ResolveWithUnion,
+            ..Default::default()
+        };
+        let mut input = imara_diff::InternedInput::default();
+        let mut out = Vec::new();
+        let actual = builtin_driver::text(
+            &mut out,
+            &mut input,
+            Default::default(),
+            b"1\n2\n3\n",
+            b"1\n2\n3\n",
+            b"1\n2\n3\ntheirs\n",
+            options,
+        );
+        assert_eq!(
+            (actual, out.as_bstr()),
+            (Resolution::Complete, "1\n2\n3\ntheirs\n".into()),
+            "changes of only one side are taken as is"
+        );
+
+        let actual = builtin_driver::text(
+            &mut out,
+            &mut input,
+            Default::default(),
+            b"1\n2\n3\nours\n",
+            b"1\n2\n3\n",
+            b"1\n2\n3\ntheirs\n",
+            options,
+        );
+        assert_eq!(
+            (actual, out.as_bstr()),
+            (
+                Resolution::CompleteWithAutoResolvedConflict,
+                "1\n2\n3\nours\ntheirs\n".into()
+            ),
+            "conflicting lines are concatenated, ours first"
+        );
+    }
+
+    #[test]
+    fn custom_marker_size_and_labels() {
+        let options = builtin_driver::text::Options {
+            conflict: builtin_driver::text::Conflict::Keep {
+                style: ConflictStyle::Diff3,
+                marker_size: 3.try_into().unwrap(),
+            },
+            ..Default::default()
+        };
+        let labels = builtin_driver::text::Labels {
+            ancestor: Some("base".into()),
+            current: Some("ours (feature-x)".into()),
+            other: Some("theirs".into()),
+        };
+        let mut input = imara_diff::InternedInput::default();
+        let mut out = Vec::new();
+        let actual = builtin_driver::text(
+            &mut out,
+            &mut input,
+            labels,
+            b"1\nours\n3\n",
+            b"1\n2\n3\n",
+            b"1\ntheirs\n3\n",
+            options,
+        );
+        assert_eq!(actual, Resolution::Conflict);
+        assert_eq!(
+            out.as_bstr(),
+            "1\n<<< ours (feature-x)\nours\n||| base\n2\n===\ntheirs\n>>> theirs\n3\n",
+            "markers have the configured size and carry the given labels"
+        );
+    }
+
     mod false_conflict {
         use gix_merge::blob::{Resolution, builtin_driver, builtin_driver::text::Conflict};
         use imara_diff::InternedInput;
@@ -68,6 +68,49 @@ mod merge {
         Ok(())
     }
 
+    #[test]
+    fn binary_detection_can_be_overridden() -> crate::Result {
+        let mut platform = new_platform(None, pipeline::Mode::ToGit);
+        let mut db = ObjectDb::default();
+        for (content, kind) in [
+            ("a\0\nours\n", ResourceKind::CurrentOrOurs),
+            ("a\0\nbase\n", ResourceKind::CommonAncestorOrBase),
+            ("a\0\ntheirs\n", ResourceKind::OtherOrTheirs),
+        ] {
+            let id = db.insert(content)?;
+            platform.set_resource(id, EntryKind::Blob, "a".into(), kind, &db)?;
+        }
+
+        let mut platform_ref = platform.prepare_merge(&db, Default::default())?;
+        platform_ref.options.treat_as_binary = Some(false);
+        let mut buf = Vec::new();
+        let res = platform_ref.merge(&mut buf, default_labels(), &Default::default())?;
+        assert_eq!(
+            res,
+            (Pick::Buffer, Resolution::Conflict),
+            "NUL bytes don't matter if text is enforced, so the text driver produces conflict markers"
+        );
+        assert!(buf.contains_str("<<<<<<<"));
+
+        platform_ref.options.treat_as_binary = Some(true);
+        platform_ref.options.text.conflict = builtin_driver::text::Conflict::ResolveWithUnion;
+        let res = platform_ref.merge(&mut buf, default_labels(), &Default::default())?;
+        assert_eq!(
+            res,
+            (Pick::Ours, Resolution::Conflict),
+            "binary merges pick a side even if text merges would have resolved the conflict"
+        );
+
+        platform_ref.options.resolve_binary_with = Some(builtin_driver::binary::ResolveWith::Theirs);
+        let res = platform_ref.merge(&mut buf, default_labels(), &Default::default())?;
+        assert_eq!(
+            res,
+            (Pick::Theirs, Resolution::CompleteWithAutoResolvedConflict),
+            "the side to pick is configurable as usual"
+        );
+        Ok(())
+    }
+
     #[test]
     fn same_binaries_do_not_count_as_conflicted() -> crate::Result {
         let mut platform = new_platform(None, pipeline::Mode::ToGit);
@@ -5,7 +5,7 @@ use gix_merge::{
     commit::Options,
     tree::{TreatAsUnresolved, apply_index_entries::RemovalMode, treat_as_unresolved},
 };
-use gix_object::Write;
+use gix_object::{FindExt, Write};
 use gix_worktree::stack::state::attributes;
 
 use crate::tree::baseline::Deviation;
@@ -215,10 +215,156 @@ fn run_baseline() -> crate::Result {
     Ok(())
 }
 
+#[test]
+fn rewrites_of_each_side_are_reported() -> crate::Result {
+    let root = gix_testtools::scripted_fixture_read_only("tree-baseline.sh")?;
+    let cases = std::fs::read_to_string(root.join("baseline.cases"))?;
+    let case = baseline::Expectations::new(&root, &cases)
+        .find(|case| case.case_name == "rename-rename-plus-content-A-B")
+        .expect("case exists");
+
+    let mut options = basic_merge_options();
+    for rewrites_enabled in [true, false] {
+        if !rewrites_enabled {
+            options.tree_merge.rewrites = None;
+        }
+        let mut graph = gix_revwalk::Graph::new(&case.odb, None);
+        let outcome = gix_merge::commit(
+            case.our_commit_id,
+            case.their_commit_id,
+            Default::default(),
+            &mut graph,
+            &mut new_diff_resource_cache(&case.root),
+            &mut new_blob_merge_platform(&case.root, None),
+            &case.odb,
+            &mut |id| id.to_hex_with_len(7).to_string(),
+            options.clone(),
+        )?
+        .tree_merge;
+
+        if !rewrites_enabled {
+            assert!(
+                outcome.our_rewrites.is_empty() && outcome.their_rewrites.is_empty(),
+                "without rename tracking, there is nothing to report"
+            );
+            continue;
+        }
+        let paths = |rewrites: &[gix_merge::tree::Rewrite]| {
+            rewrites
+                .iter()
+                .map(|r| (r.source_location.to_string(), r.location.to_string()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(paths(&outcome.our_rewrites), [("foo".into(), "bar".into())]);
+        assert_eq!(paths(&outcome.their_rewrites), [("foo".into(), "baz".into())]);
+        for rewrite in outcome.our_rewrites.iter().chain(&outcome.their_rewrites) {
+            assert!(
+                rewrite.similarity > 0.5 && rewrite.similarity < 1.0,
+                "both sides also changed the content: {}",
+                rewrite.similarity
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn precomputed_merge_base_is_used_as_is() -> crate::Result {
+    let root = gix_testtools::scripted_fixture_read_only("tree-baseline.sh")?;
+    let cases = std::fs::read_to_string(root.join("baseline.cases"))?;
+    let case = baseline::Expectations::new(&root, &cases)
+        .find(|case| case.case_name == "rename-rename-plus-content-A-B")
+        .expect("case exists");
+
+    let mut options = basic_merge_options();
+    options.merge_base = Some(case.their_commit_id);
+    let mut graph = gix_revwalk::Graph::new(&case.odb, None);
+    let mut outcome = gix_merge::commit(
+        case.our_commit_id,
+        case.their_commit_id,
+        Default::default(),
+        &mut graph,
+        &mut new_diff_resource_cache(&case.root),
+        &mut new_blob_merge_platform(&case.root, None),
+        &case.odb,
+        &mut |id| id.to_hex_with_len(7).to_string(),
+        options,
+    )?;
+
+    assert_eq!(
+        outcome.merge_bases.map(Vec::from),
+        Some(vec![case.their_commit_id]),
+        "the given merge-base is reported"
+    );
+    let our_tree_id = case.odb.find_commit(&case.our_commit_id, &mut Vec::new())?.tree();
+    assert!(outcome.tree_merge.conflicts.is_empty());
+    assert_eq!(
+        outcome.tree_merge.tree.write(|tree| case.odb.write(tree))?,
+        our_tree_id,
+        "with their commit as merge-base, there is nothing to merge from their side"
+    );
+    Ok(())
+}
+
+#[test]
+fn virtual_merge_base_creation_is_reported_and_can_be_limited() -> crate::Result {
+    let root = gix_testtools::scripted_fixture_read_only("tree-baseline.sh")?;
+    let cases = std::fs::read_to_string(root.join("baseline.cases"))?;
+    let case = baseline::Expectations::new(&root, &cases)
+        .find(|case| case.case_name == "multiple-merge-bases-A-B")
+        .expect("case exists");
+
+    let merge = |max_recursion| -> Result<gix_merge::commit::Outcome<'_>, gix_merge::commit::Error> {
+        let mut options = basic_merge_options();
+        options.max_recursion = max_recursion;
+        let mut graph = gix_revwalk::Graph::new(&case.odb, None);
+        gix_merge::commit(
+            case.our_commit_id,
+            case.their_commit_id,
+            Default::default(),
+            &mut graph,
+            &mut new_diff_resource_cache(&case.root),
+            &mut new_blob_merge_platform(&case.root, None),
+            &case.odb,
+            &mut |id| id.to_hex_with_len(7).to_string(),
+            options,
+        )
+    };
+
+    let outcome = merge(None)?;
+    let merge_bases: Vec<_> = outcome.merge_bases.clone().expect("multiple merge-bases").into();
+    assert!(
+        merge_bases.len() > 1,
+        "the fixture is expected to have multiple merge-bases"
+    );
+    assert_eq!(
+        outcome.merge_base(),
+        Some(gix_merge::commit::MergeBase::Virtual {
+            from: merge_bases.clone(),
+            tree: outcome.merge_base_tree_id,
+        }),
+        "without limit, all merge-bases are merged into a virtual one"
+    );
+
+    let outcome = merge(Some(0))?;
+    assert!(
+        outcome.virtual_merge_bases.is_empty(),
+        "no virtual merge-base is created"
+    );
+    assert_eq!(
+        outcome.merge_base(),
+        Some(gix_merge::commit::MergeBase::Real(merge_bases[0])),
+        "with the recursion limit reached, the first merge-base is used"
+    );
+    Ok(())
+}
+
 fn basic_merge_options() -> Options {
     gix_merge::commit::Options {
         allow_missing_merge_base: true,
         use_first_merge_base: false,
+        merge_base: None,
+        max_recursion: None,
         tree_merge: gix_merge::tree::Options {
             symlink_conflicts: None,
             tree_conflicts: None,
@@ -247,6 +247,23 @@ impl EntryMode {
         }
     }
 
+    /// Like [`kind()`](Self::kind()), but return `None` if the type of the entry is unknown instead of
+    /// assuming it's a [commit](EntryKind::Commit).
+    ///
+    /// Note that non-canonical blob modes, like `100664`, are normalized just like Git does.
+    pub const fn try_kind(&self) -> Option<EntryKind> {
+        let etype = self.value() & IFMT;
+        if etype == 0o100000
+            || etype == EntryKind::Link as u16
+            || etype == EntryKind::Tree as u16
+            || etype == EntryKind::Commit as u16
+        {
+            Some(self.kind())
+        } else {
+            None
+        }
+    }
+
     /// Return true if this entry mode represents a Tree/directory
     pub const fn is_tree(&self) -> bool {
         self.value() & IFMT == EntryKind::Tree as u16
@@ -50,6 +50,35 @@ fn is_methods() {
     );
 }
 
+#[test]
+fn try_kind() {
+    assert_eq!(
+        EntryMode::from_bytes(b"100664").unwrap().try_kind(),
+        Some(EntryKind::Blob),
+        "group-writable blobs are normalized like Git does"
+    );
+    assert_eq!(
+        EntryMode::from_bytes(b"100775").unwrap().try_kind(),
+        Some(EntryKind::BlobExecutable)
+    );
+    for kind in [
+        EntryKind::Tree,
+        EntryKind::Blob,
+        EntryKind::BlobExecutable,
+        EntryKind::Link,
+        EntryKind::Commit,
+    ] {
+        assert_eq!(EntryMode::from(kind).try_kind(), Some(kind));
+    }
+    for unknown in [b"000000".as_slice(), b"10644", b"170000"] {
+        assert_eq!(
+            EntryMode::from_bytes(unknown).unwrap().try_kind(),
+            None,
+            "unknown entry types aren't misclassified as commit"
+        );
+    }
+}
+
 #[test]
 fn as_bytes() {
     let mut buf = Default::default();
@@ -0,0 +1,66 @@
+use gix_hash::ObjectId;
+
+use crate::bitmap::File;
+
+/// Access
+impl File {
+    /// Return `true` if there is a reachability bitmap for `commit`.
+    pub fn contains(&self, commit: impl AsRef<gix_hash::oid>) -> bool {
+        self.entry_index(commit.as_ref()).is_some()
+    }
+
+    /// Return the ids of all objects in the pack that are reachable from `commit`, including `commit` itself,
+    /// or `None` if there is no bitmap for `commit`.
+    ///
+    /// Objects are returned in the order they are stored in the pack.
+    pub fn reachable_objects(&self, commit: impl AsRef<gix_hash::oid>) -> Option<impl Iterator<Item = ObjectId> + '_> {
+        let mut entry_index = self.entry_index(commit.as_ref())?;
+        let mut words = vec![0u64; self.objects.len().div_ceil(64)];
+        loop {
+            let entry = &self.entries[entry_index];
+            xor_into(&mut words, &entry.bitmap);
+            if entry.xor_offset == 0 {
+                break;
+            }
+            entry_index -= usize::from(entry.xor_offset);
+        }
+        Some(self.ids_of_set_bits(words))
+    }
+
+    /// Return the ids of all objects in the pack of the given `kind`, in the order they are stored in the pack.
+    pub fn objects_of_kind(&self, kind: gix_object::Kind) -> impl Iterator<Item = ObjectId> + '_ {
+        use gix_object::Kind::*;
+        let bitmap = &self.types[match kind {
+            Commit => 0,
+            Tree => 1,
+            Blob => 2,
+            Tag => 3,
+        }];
+        let mut words = vec![0u64; self.objects.len().div_ceil(64)];
+        xor_into(&mut words, bitmap);
+        self.ids_of_set_bits(words)
+    }
+
+    fn entry_index(&self, commit: &gix_hash::oid) -> Option<usize> {
+        self.lookup
+            .binary_search_by(|idx| self.entries[*idx].commit.as_ref().cmp(commit))
+            .ok()
+            .map(|pos| self.lookup[pos])
+    }
+
+    fn ids_of_set_bits(&self, words: Vec<u64>) -> impl Iterator<Item = ObjectId> + '_ {
+        words.into_iter().enumerate().flat_map(move |(word_index, word)| {
+            (0..64)
+                .filter(move |bit| word & (1 << bit) != 0)
+                .map(move |bit| self.objects[word_index * 64 + bit])
+        })
+    }
+}
+
+/// XOR all set bits of `bitmap` into `words`, which was validated on load to fit.
+fn xor_into(words: &mut [u64], bitmap: &gix_bitmap::ewah::Vec) {
+    bitmap.for_each_set_bit(|pos| {
+        words[pos / 64] ^= 1 << (pos % 64);
+        Some(())
+    });
+}
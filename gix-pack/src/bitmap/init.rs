@@ -0,0 +1,185 @@
+use std::path::{Path, PathBuf};
+
+use gix_hash::ObjectId;
+
+use crate::bitmap::{Entry, File, Version};
+
+mod error {
+    /// The error returned by [File::at()][super::File::at()].
+    #[derive(Debug, thiserror::Error)]
+    #[allow(missing_docs)]
+    pub enum Error {
+        #[error("Could not open bitmap file at '{path}'")]
+        Io {
+            source: std::io::Error,
+            path: std::path::PathBuf,
+        },
+        #[error("{message}")]
+        Corrupt { message: &'static str },
+        #[error("Unsupported bitmap version: {version}")]
+        UnsupportedVersion { version: u16 },
+        #[error("The bitmap was written for pack {actual}, but the index belongs to pack {expected}")]
+        PackChecksumMismatch {
+            expected: gix_hash::ObjectId,
+            actual: gix_hash::ObjectId,
+        },
+        #[error("The {kind} bitmap could not be decoded")]
+        BitmapDecode {
+            err: gix_bitmap::ewah::decode::Error,
+            kind: &'static str,
+        },
+    }
+}
+
+pub use error::Error;
+
+pub(crate) const FLAG_HASH_CACHE: u16 = 0x4;
+
+/// Initialization
+impl File {
+    /// Open the bitmap file at the given `path`, which belongs to the pack of `index`.
+    pub fn at<T>(path: impl AsRef<Path>, index: &crate::index::File<T>) -> Result<Self, Error>
+    where
+        T: crate::FileData + Sync,
+    {
+        Self::at_inner(path.as_ref(), index)
+    }
+
+    fn at_inner<T>(path: &Path, index: &crate::index::File<T>) -> Result<Self, Error>
+    where
+        T: crate::FileData + Sync,
+    {
+        let data = crate::mmap::read_only(path).map_err(|source| Error::Io {
+            source,
+            path: path.to_owned(),
+        })?;
+        Self::from_data(&data, path.to_owned(), index)
+    }
+
+    /// Instantiate a bitmap file from `data` as assumed to be read or memory-mapped from `path`,
+    /// for the pack that belongs to `index`.
+    ///
+    /// `index` is used to validate that the bitmap belongs to the pack, and to learn the ids of the objects
+    /// referred to by each bit.
+    pub fn from_data<T>(data: &[u8], path: PathBuf, index: &crate::index::File<T>) -> Result<Self, Error>
+    where
+        T: crate::FileData + Sync,
+    {
+        let hash_len = index.object_hash().len_in_bytes();
+        if data.len() < Self::HEADER_LEN + hash_len {
+            return Err(Error::Corrupt {
+                message: "bitmap file is truncated and too short",
+            });
+        }
+        let (signature, data) = data.split_at(4);
+        if signature != Self::SIGNATURE {
+            return Err(Error::Corrupt {
+                message: "Invalid signature",
+            });
+        }
+        let (version, data) = data.split_at(2);
+        let version = match u16::from_be_bytes([version[0], version[1]]) {
+            1 => Version::V1,
+            version => return Err(Error::UnsupportedVersion { version }),
+        };
+        let (flags, data) = data.split_at(2);
+        let flags = u16::from_be_bytes([flags[0], flags[1]]);
+        let (num_entries, data) = data.split_at(4);
+        let num_entries = crate::read_u32(num_entries);
+        let (pack_checksum, mut data) = data.split_at(hash_len);
+        let pack_checksum = ObjectId::from_bytes_or_panic(pack_checksum);
+        if pack_checksum != index.pack_checksum() {
+            return Err(Error::PackChecksumMismatch {
+                expected: index.pack_checksum(),
+                actual: pack_checksum,
+            });
+        }
+
+        let objects = {
+            let mut entries: Vec<_> = index.iter().map(|e| (e.pack_offset, e.oid)).collect();
+            entries.sort_unstable_by_key(|(ofs, _)| *ofs);
+            entries.into_iter().map(|(_, id)| id).collect::<Vec<_>>()
+        };
+        let num_objects = objects.len();
+
+        let decode = |kind: &'static str, data: &mut &[u8]| -> Result<gix_bitmap::ewah::Vec, Error> {
+            let (bitmap, rest) = gix_bitmap::ewah::decode(data).map_err(|err| Error::BitmapDecode { err, kind })?;
+            *data = rest;
+            let mut in_bounds = true;
+            bitmap
+                .for_each_set_bit(|pos| {
+                    in_bounds = pos < num_objects;
+                    in_bounds.then_some(())
+                })
+                .ok_or(Error::Corrupt {
+                    message: if in_bounds {
+                        "bitmap data is inconsistent"
+                    } else {
+                        "bitmap refers to objects that aren't in the pack"
+                    },
+                })?;
+            Ok(bitmap)
+        };
+
+        let types = [
+            decode("commit type", &mut data)?,
+            decode("tree type", &mut data)?,
+            decode("blob type", &mut data)?,
+            decode("tag type", &mut data)?,
+        ];
+
+        let mut entries = Vec::with_capacity((num_entries as usize).min(data.len() / Self::MIN_ENTRY_LEN));
+        for entry_index in 0..num_entries as usize {
+            if data.len() < Self::ENTRY_HEADER_LEN {
+                return Err(Error::Corrupt {
+                    message: "bitmap file is truncated within its commit bitmaps",
+                });
+            }
+            let (header, rest) = data.split_at(Self::ENTRY_HEADER_LEN);
+            data = rest;
+            let index_position = crate::read_u32(&header[..4]);
+            let xor_offset = header[4];
+            let _flags = header[5];
+            if index_position >= index.num_objects() {
+                return Err(Error::Corrupt {
+                    message: "commit bitmap refers to an object that isn't in the pack index",
+                });
+            }
+            if usize::from(xor_offset) > entry_index {
+                return Err(Error::Corrupt {
+                    message: "commit bitmap refers to a base bitmap before the first one",
+                });
+            }
+            entries.push(Entry {
+                commit: index.oid_at_index(index_position).to_owned(),
+                xor_offset,
+                bitmap: decode("commit", &mut data)?,
+            });
+        }
+
+        let mut lookup: Vec<_> = (0..entries.len()).collect();
+        lookup.sort_unstable_by_key(|idx| entries[*idx].commit);
+
+        Ok(File {
+            path,
+            version,
+            flags,
+            objects,
+            types,
+            entries,
+            lookup,
+        })
+    }
+}
+
+impl File {
+    /// The signature at the start of each bitmap file.
+    pub const SIGNATURE: &'static [u8] = b"BITM";
+    /// The length of the header up to the pack checksum, in bytes.
+    pub const HEADER_LEN: usize = 4 /*signature*/ +
+        2 /*version*/ +
+        2 /*flags*/ +
+        4 /*num commit bitmaps*/;
+    const ENTRY_HEADER_LEN: usize = 4 /*index position*/ + 1 /*xor offset*/ + 1 /*flags*/;
+    const MIN_ENTRY_LEN: usize = Self::ENTRY_HEADER_LEN + 4 /*num bits*/ + 4 /*num words*/ + 4 /*rlw*/;
+}
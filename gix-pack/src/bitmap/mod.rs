@@ -0,0 +1,68 @@
+//! Reachability bitmaps as stored in `.bitmap` files next to a pack, as written by `git repack -b`.
+//!
+//! Each bit of a bitmap refers to an object of the pack, in the order the objects are stored in the pack data file.
+use gix_hash::ObjectId;
+
+/// Known bitmap file versions
+#[derive(Default, PartialEq, Eq, Ord, PartialOrd, Debug, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(missing_docs)]
+pub enum Version {
+    #[default]
+    V1 = 1,
+}
+
+/// A commit along with the bitmap of all objects reachable from it.
+struct Entry {
+    commit: ObjectId,
+    /// If not 0, `bitmap` has to be XORed with the resolved bitmap of the entry this many entries before this one.
+    xor_offset: u8,
+    bitmap: gix_bitmap::ewah::Vec,
+}
+
+/// A representation of a reachability bitmap file for a single pack, typically stored in a file
+/// named `pack-<checksum>.bitmap`.
+///
+/// It is fully decoded on initialization, so the pack index it belongs to is only needed once.
+pub struct File {
+    path: std::path::PathBuf,
+    version: Version,
+    flags: u16,
+    /// The ids of all objects in the pack, sorted by their offset, which is the order the bits in our bitmaps refer to.
+    objects: Vec<ObjectId>,
+    /// The bitmaps for commits, trees, blobs and tags, in that order.
+    types: [gix_bitmap::ewah::Vec; 4],
+    /// All commits with a bitmap in the order they are stored, which is the order that `xor_offset` refers to.
+    entries: Vec<Entry>,
+    /// Indices into `entries`, sorted by commit id for lookups.
+    lookup: Vec<usize>,
+}
+
+/// Basic file information
+impl File {
+    /// The version of the bitmap file.
+    pub fn version(&self) -> Version {
+        self.version
+    }
+    /// The path of the opened bitmap file.
+    pub fn path(&self) -> &std::path::Path {
+        &self.path
+    }
+    /// The amount of objects in the pack, which is the amount of bits each bitmap can have.
+    pub fn num_objects(&self) -> u32 {
+        self.objects.len() as u32
+    }
+    /// The amount of commits that have a reachability bitmap.
+    pub fn num_commits(&self) -> u32 {
+        self.entries.len() as u32
+    }
+    /// Return `true` if the file stores the name-hash of each object, used by git to find good delta bases.
+    pub fn has_name_hash_cache(&self) -> bool {
+        self.flags & init::FLAG_HASH_CACHE != 0
+    }
+}
+
+///
+pub mod init;
+
+mod access;
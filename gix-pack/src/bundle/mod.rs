@@ -12,6 +12,9 @@ pub mod verify {
 
     use gix_features::progress::DynNestedProgress;
 
+    ///
+    pub mod entries;
+
     ///
     pub mod integrity {
         /// Returned by [`Bundle::verify_integrity()`][crate::Bundle::verify_integrity()].
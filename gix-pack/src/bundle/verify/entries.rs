@@ -0,0 +1,252 @@
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+use gix_features::{
+    parallel::{self, in_parallel_if},
+    progress::{self, DynNestedProgress, Progress},
+    zlib,
+};
+
+use crate::{Bundle, data, index};
+
+/// Returned by [`Bundle::verify_entries_parallel()`].
+#[derive(thiserror::Error, Debug)]
+#[allow(missing_docs)]
+pub enum Error {
+    #[error("Object {id} at offset {offset} could not be decoded")]
+    Decode {
+        id: gix_hash::ObjectId,
+        offset: data::Offset,
+        source: data::decode::Error,
+    },
+    #[error("Object at offset {offset} doesn't match the object id {id} stored in the index")]
+    ObjectVerify {
+        id: gix_hash::ObjectId,
+        offset: data::Offset,
+        source: gix_object::data::verify::Error,
+    },
+    #[error(
+        "The CRC32 of {kind} object at offset {offset} didn't match the checksum in the index file: expected {expected}, got {actual}"
+    )]
+    Crc32Mismatch {
+        expected: u32,
+        actual: u32,
+        offset: data::Offset,
+        kind: gix_object::Kind,
+    },
+    #[error("Failed to verify pack file checksum")]
+    PackChecksum(#[from] crate::verify::checksum::Error),
+    #[error("Interrupted by user")]
+    Interrupted,
+}
+
+impl Error {
+    fn offset(&self) -> Option<data::Offset> {
+        match self {
+            Error::Decode { offset, .. } | Error::ObjectVerify { offset, .. } | Error::Crc32Mismatch { offset, .. } => {
+                Some(*offset)
+            }
+            Error::PackChecksum(_) | Error::Interrupted => None,
+        }
+    }
+}
+
+/// The progress ids used in [`Bundle::verify_entries_parallel()`].
+///
+/// Use this information to selectively extract the progress of interest in case the parent application has custom visualization.
+#[derive(Debug, Copy, Clone)]
+pub enum ProgressId {
+    /// The amount of bytes currently processed to generate a checksum of the *pack data file*.
+    HashPackDataBytes,
+    /// Collect all index entries into a vector and sort it by their pack offset.
+    CollectSortedIndexEntries,
+    /// The amount of objects which were decoded and verified.
+    VerifiedObjects,
+}
+
+impl From<ProgressId> for gix_features::progress::Id {
+    fn from(v: ProgressId) -> Self {
+        match v {
+            ProgressId::HashPackDataBytes => *b"PVHP",
+            ProgressId::CollectSortedIndexEntries => *b"PVCE",
+            ProgressId::VerifiedObjects => *b"PVVO",
+        }
+    }
+}
+
+impl Bundle {
+    /// Decode all entries of the pack on up to `thread_limit` threads, or as many as there are logical cores if `None`,
+    /// and assure each object hashes to the id stored for it in the index and that the CRC32 of its entry matches,
+    /// before verifying the pack checksum in its trailer on the calling thread.
+    ///
+    /// If more than one entry is corrupt, the error of the one with the lowest pack offset is returned,
+    /// independently of the amount of threads used.
+    /// Each thread uses its own cache created by `make_pack_lookup_cache()` to avoid decoding the bases of delta-chains repeatedly,
+    /// use existing implementations in the [`crate::cache`] module.
+    /// Set `should_interrupt` to abort the operation with [`Error::Interrupted`] as soon as possible.
+    ///
+    /// Note that unlike [`verify_integrity()`](Bundle::verify_integrity()), this doesn't verify the index checksum
+    /// nor does it collect statistics.
+    pub fn verify_entries_parallel<C, F>(
+        &self,
+        thread_limit: Option<usize>,
+        make_pack_lookup_cache: F,
+        progress: &mut dyn DynNestedProgress,
+        should_interrupt: &AtomicBool,
+    ) -> Result<(), Error>
+    where
+        C: crate::cache::DecodeEntry,
+        F: Fn() -> C + Send + Clone,
+    {
+        let index_entries = index::util::index_entries_sorted_by_offset_ascending(
+            &self.index,
+            &mut progress.add_child_with_id(
+                "collecting sorted index".into(),
+                ProgressId::CollectSortedIndexEntries.into(),
+            ),
+        );
+        let (chunk_size, thread_limit, available_cores) =
+            parallel::optimize_chunk_size_and_thread_limit(1000, Some(index_entries.len()), thread_limit, None);
+        let there_are_enough_entries_to_process = || index_entries.len() > chunk_size * available_cores;
+        let lowest_failing_offset = AtomicU64::new(u64::MAX);
+
+        let mut verify_progress = progress.add_child_with_id("Verifying".into(), ProgressId::VerifiedObjects.into());
+        verify_progress.init(Some(index_entries.len()), progress::count("objects"));
+        let first_error = in_parallel_if(
+            there_are_enough_entries_to_process,
+            index_entries.chunks(chunk_size),
+            thread_limit,
+            move |_| {
+                (
+                    Vec::with_capacity(2048),
+                    zlib::Inflate::default(),
+                    make_pack_lookup_cache(),
+                )
+            },
+            |entries: &[index::Entry], (buf, inflate, cache)| -> (usize, Option<Error>) {
+                for (num_verified, entry) in entries.iter().enumerate() {
+                    if should_interrupt.load(Ordering::Relaxed) {
+                        return (num_verified, Some(Error::Interrupted));
+                    }
+                    // Entries are sorted by offset, so all remaining ones would lose against the known failure.
+                    if entry.pack_offset > lowest_failing_offset.load(Ordering::Relaxed) {
+                        return (num_verified, None);
+                    }
+                    if let Err(err) = self.verify_entry(entry, buf, inflate, cache) {
+                        lowest_failing_offset.fetch_min(entry.pack_offset, Ordering::Relaxed);
+                        return (num_verified, Some(err));
+                    }
+                }
+                (entries.len(), None)
+            },
+            FirstErrorByOffset {
+                progress: &mut verify_progress,
+                error: None,
+            },
+        )
+        .expect("infallible");
+        if let Some(err) = first_error {
+            return Err(err);
+        }
+
+        self.pack.verify_checksum(
+            &mut progress.add_child_with_id(
+                format!("Hash of pack '{}'", crate::source_name(self.pack.path())),
+                ProgressId::HashPackDataBytes.into(),
+            ),
+            should_interrupt,
+        )?;
+        Ok(())
+    }
+
+    fn verify_entry(
+        &self,
+        entry: &index::Entry,
+        buf: &mut Vec<u8>,
+        inflate: &mut zlib::Inflate,
+        cache: &mut dyn crate::cache::DecodeEntry,
+    ) -> Result<(), Error> {
+        let pack_entry = self.pack.entry(entry.pack_offset).map_err(|err| Error::Decode {
+            id: entry.oid,
+            offset: entry.pack_offset,
+            source: err.into(),
+        })?;
+        let header_size = (pack_entry.data_offset - entry.pack_offset) as usize;
+        let outcome = self
+            .pack
+            .decode_entry(
+                pack_entry,
+                buf,
+                inflate,
+                &|id, _| {
+                    let index = self.index.lookup(id)?;
+                    self.pack
+                        .entry(self.index.pack_offset_at_index(index))
+                        .ok()
+                        .map(data::decode::entry::ResolvedBase::InPack)
+                },
+                cache,
+            )
+            .map_err(|err| Error::Decode {
+                id: entry.oid,
+                offset: entry.pack_offset,
+                source: err,
+            })?;
+
+        gix_object::Data::new(buf, outcome.kind, entry.oid.kind())
+            .verify_checksum(&entry.oid)
+            .map_err(|err| Error::ObjectVerify {
+                id: entry.oid,
+                offset: entry.pack_offset,
+                source: err,
+            })?;
+        if let Some(expected) = entry.crc32 {
+            let actual = self
+                .pack
+                .entry_crc32(entry.pack_offset, header_size + outcome.compressed_size);
+            if actual != expected {
+                return Err(Error::Crc32Mismatch {
+                    expected,
+                    actual,
+                    offset: entry.pack_offset,
+                    kind: outcome.kind,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Keep the error with the lowest pack offset, as all chunks may fail independently in any order.
+struct FirstErrorByOffset<'a, P> {
+    progress: &'a mut P,
+    error: Option<Error>,
+}
+
+impl<P: Progress> parallel::Reduce for FirstErrorByOffset<'_, P> {
+    type Input = (usize, Option<Error>);
+    type FeedProduce = ();
+    type Output = Option<Error>;
+    type Error = std::convert::Infallible;
+
+    fn feed(&mut self, (num_verified, error): Self::Input) -> Result<(), Self::Error> {
+        self.progress.inc_by(num_verified);
+        let Some(error) = error else {
+            return Ok(());
+        };
+        let is_lower = match (self.error.as_ref().map(Error::offset), error.offset()) {
+            (None, _) => true,
+            // An interruption is only reported if nothing else went wrong.
+            (Some(_), None) => false,
+            (Some(None), Some(_)) => true,
+            (Some(Some(previous)), Some(offset)) => offset < previous,
+        };
+        if is_lower {
+            self.error = Some(error);
+        }
+        Ok(())
+    }
+
+    fn finalize(self) -> Result<Self::Output, Self::Error> {
+        Ok(self.error)
+    }
+}
@@ -13,6 +13,15 @@ pub mod apply {
         #[error("Delta copy data: byte slices must match")]
         DeltaCopyDataSliceMismatch,
     }
+
+    /// The amount of bytes produced by each kind of delta instruction while applying a delta.
+    #[derive(Default, Debug, Clone, Copy)]
+    pub(crate) struct Outcome {
+        /// The amount of bytes copied from the base object.
+        pub copied_bytes: usize,
+        /// The amount of bytes inserted from the delta instructions themselves.
+        pub inserted_bytes: usize,
+    }
 }
 
 /// Given the decompressed pack delta `d`, decode a size in bytes (either the base object size or the result object size)
@@ -39,7 +48,7 @@ pub(crate) fn decode_header_size(d: &[u8]) -> Result<(u64, usize), apply::Error>
     })
 }
 
-pub(crate) fn apply(base: &[u8], mut target: &mut [u8], data: &[u8]) -> Result<(), apply::Error> {
+pub(crate) fn apply(base: &[u8], mut target: &mut [u8], data: &[u8]) -> Result<apply::Outcome, apply::Error> {
     fn next_byte(data: &[u8], i: &mut usize) -> Result<u8, apply::Error> {
         let byte = *data.get(*i).ok_or(apply::Error::Corrupt {
             message: "delta copy instruction is truncated",
@@ -48,6 +57,7 @@ pub(crate) fn apply(base: &[u8], mut target: &mut [u8], data: &[u8]) -> Result<(
         Ok(byte)
     }
 
+    let mut out = apply::Outcome::default();
     let mut i = 0;
     while let Some(cmd) = data.get(i) {
         i += 1;
@@ -89,6 +99,7 @@ pub(crate) fn apply(base: &[u8], mut target: &mut [u8], data: &[u8]) -> Result<(
                     })?,
                 )
                 .map_err(|_e| apply::Error::DeltaCopyBaseSliceMismatch)?;
+                out.copied_bytes += size as usize;
             }
             0 => {
                 return Err(apply::Error::Corrupt {
@@ -106,6 +117,7 @@ pub(crate) fn apply(base: &[u8], mut target: &mut [u8], data: &[u8]) -> Result<(
                     })?,
                 )
                 .map_err(|_e| apply::Error::DeltaCopyDataSliceMismatch)?;
+                out.inserted_bytes += *size as usize;
                 i = end;
             }
         }
@@ -121,5 +133,5 @@ pub(crate) fn apply(base: &[u8], mut target: &mut [u8], data: &[u8]) -> Result<(
         });
     }
 
-    Ok(())
+    Ok(out)
 }
@@ -49,6 +49,13 @@ pub struct Outcome {
     pub compressed_size: usize,
     /// The total size of the decoded object.
     pub object_size: u64,
+    /// The total amount of bytes copied from base objects while applying all deltas in the chain.
+    ///
+    /// Together with [`delta_insert_bytes`](Self::delta_insert_bytes), this is the amount of bytes produced by all deltas.
+    /// Note that these are not aggregated in the statistics of index traversals.
+    pub delta_copy_bytes: u64,
+    /// The total amount of bytes inserted verbatim from delta instructions while applying all deltas in the chain.
+    pub delta_insert_bytes: u64,
 }
 
 impl Outcome {
@@ -59,6 +66,8 @@ impl Outcome {
             decompressed_size: 0,
             compressed_size: 0,
             object_size: 0,
+            delta_copy_bytes: 0,
+            delta_insert_bytes: 0,
         }
     }
     fn from_object_entry(kind: gix_object::Kind, entry: &data::Entry, compressed_size: usize) -> Self {
@@ -68,6 +77,8 @@ impl Outcome {
             decompressed_size: entry.decompressed_size,
             compressed_size,
             object_size: entry.decompressed_size,
+            delta_copy_bytes: 0,
+            delta_insert_bytes: 0,
         }
     }
 }
@@ -81,6 +92,16 @@ where
         decoded_object_size(size, self.alloc_limit_bytes)
     }
 
+    /// Fail if a delta chain of `len` deltas exceeds the configured maximum chain length.
+    pub(crate) fn assure_delta_chain_len_within_limit(&self, len: usize) -> Result<(), Error> {
+        match self.max_delta_chain_len {
+            Some(max_delta_chain_len) if len > max_delta_chain_len => {
+                Err(Error::DeltaChainTooLong { max_delta_chain_len })
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Decompress the given `entry` into `out` and return the amount of bytes read from the pack data.
     /// Note that `inflate` is not reset after usage, but will be reset before using it.
     ///
@@ -270,6 +291,7 @@ where
                 decompressed_size,
                 data_offset: cursor.data_offset,
             });
+            self.assure_delta_chain_len_within_limit(chain.len())?;
             use crate::data::entry::Header;
             cursor = match cursor.header {
                 Header::OfsDelta { base_distance } => {
@@ -404,6 +426,7 @@ where
         let (mut source_buf, mut target_buf) = buffers.split_at_mut(first_buffer_end);
 
         let mut last_result_size = None;
+        let (mut delta_copy_bytes, mut delta_insert_bytes) = (0, 0);
         for (
             delta_idx,
             Delta {
@@ -418,7 +441,9 @@ where
             if delta_idx + 1 == chain_len {
                 last_result_size = Some(result_size);
             }
-            delta::apply(&source_buf[..base_size], &mut target_buf[..result_size], data)?;
+            let applied = delta::apply(&source_buf[..base_size], &mut target_buf[..result_size], data)?;
+            delta_copy_bytes += applied.copied_bytes as u64;
+            delta_insert_bytes += applied.inserted_bytes as u64;
             // use the target as source for the next delta
             std::mem::swap(&mut source_buf, &mut target_buf);
         }
@@ -457,6 +482,8 @@ where
             decompressed_size: first_entry.decompressed_size,
             compressed_size: consumed_input,
             object_size: last_result_size as u64,
+            delta_copy_bytes,
+            delta_insert_bytes,
         })
     }
 }
@@ -479,7 +506,7 @@ mod tests {
     #[test]
     fn size_of_decode_entry_outcome() {
         let actual = std::mem::size_of::<Outcome>();
-        let expected = 32;
+        let expected = 48;
         assert!(
             size_ok(actual, expected),
             "this shouldn't change without use noticing as it's returned a lot: {actual} <~ {expected}"
@@ -72,6 +72,7 @@ where
                 }
                 OfsDelta { base_distance } => {
                     num_deltas += 1;
+                    self.assure_delta_chain_len_within_limit(num_deltas as usize)?;
                     if first_delta_decompressed_size.is_none() {
                         first_delta_decompressed_size = Some(self.decode_delta_object_size(inflate, &entry)?);
                     }
@@ -83,6 +84,7 @@ where
                 }
                 RefDelta { base_id } => {
                     num_deltas += 1;
+                    self.assure_delta_chain_len_within_limit(num_deltas as usize)?;
                     if first_delta_decompressed_size.is_none() {
                         first_delta_decompressed_size = Some(self.decode_delta_object_size(inflate, &entry)?);
                     }
@@ -92,10 +94,12 @@ where
                             kind,
                             num_deltas: origin_num_deltas,
                         }) => {
+                            let num_deltas = origin_num_deltas.unwrap_or_default() + num_deltas;
+                            self.assure_delta_chain_len_within_limit(num_deltas as usize)?;
                             return Ok(Outcome {
                                 kind,
                                 object_size: first_delta_decompressed_size.unwrap_or(entry.decompressed_size),
-                                num_deltas: origin_num_deltas.unwrap_or_default() + num_deltas,
+                                num_deltas,
                             });
                         }
                         None => return Err(Error::DeltaBaseUnresolved(base_id)),
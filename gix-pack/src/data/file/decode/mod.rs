@@ -19,6 +19,8 @@ pub enum Error {
     EntryType(#[from] crate::data::entry::decode::Error),
     #[error("Entry too large to fit in memory")]
     OutOfMemory,
+    #[error("The delta chain exceeds the maximum length of {max_delta_chain_len}")]
+    DeltaChainTooLong { max_delta_chain_len: usize },
     #[error(transparent)]
     Delta(#[from] crate::data::delta::apply::Error),
 }
@@ -53,6 +53,7 @@ where
             num_objects,
             object_hash,
             alloc_limit_bytes: None,
+            max_delta_chain_len: None,
         })
     }
 
@@ -67,4 +68,17 @@ where
         self.alloc_limit_bytes = alloc_limit_bytes;
         self
     }
+
+    /// Configure the maximum amount of deltas in a chain that will be resolved when decoding entries or their headers,
+    /// to refuse pathologically deep delta chains in untrusted packs.
+    ///
+    /// Use `None` to disable the limit, which is also the default.
+    ///
+    /// Chains that exceed the limit cause [`Error::DeltaChainTooLong`](crate::data::decode::Error::DeltaChainTooLong)
+    /// in [`File::decode_entry()`](crate::data::File::decode_entry()) and [`File::decode_header()`](crate::data::File::decode_header()).
+    /// Note that delta chains are only walked up to the first base that is found in the cache passed to `decode_entry()`.
+    pub fn with_max_delta_chain_len(mut self, max_delta_chain_len: Option<usize>) -> Self {
+        self.max_delta_chain_len = max_delta_chain_len;
+        self
+    }
 }
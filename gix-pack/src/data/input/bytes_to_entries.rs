@@ -275,14 +275,22 @@ where
 {
     /// Returns an iterator over [`Entries`][crate::data::input::Entry], without making use of the memory mapping.
     pub fn streaming_iter(&self) -> Result<BytesToEntriesIter<impl io::BufRead>, input::Error> {
+        self.streaming_iter_with_mode(input::EntryDataMode::KeepAndCrc32)
+    }
+
+    /// Like [`streaming_iter()`](Self::streaming_iter()), but only computes the [CRC32](input::Entry::crc32) of each entry
+    /// while streaming without keeping its compressed bytes, which is all that's needed to build or verify a V2 index.
+    pub fn iter_with_crc(&self) -> Result<BytesToEntriesIter<impl io::BufRead>, input::Error> {
+        self.streaming_iter_with_mode(input::EntryDataMode::Crc32)
+    }
+
+    fn streaming_iter_with_mode(
+        &self,
+        mode: input::EntryDataMode,
+    ) -> Result<BytesToEntriesIter<impl io::BufRead>, input::Error> {
         let reader =
             io::BufReader::with_capacity(4096 * 8, fs::File::open(&self.path).map_err(gix_hash::io::Error::from)?);
-        BytesToEntriesIter::new_from_header(
-            reader,
-            input::Mode::Verify,
-            input::EntryDataMode::KeepAndCrc32,
-            self.object_hash,
-        )
+        BytesToEntriesIter::new_from_header(reader, input::Mode::Verify, mode, self.object_hash)
     }
 }
 
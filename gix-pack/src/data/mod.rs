@@ -103,6 +103,10 @@ pub struct File<T = MMap> {
     ///
     /// If `None`, no additional limit is enforced.
     alloc_limit_bytes: Option<usize>,
+    /// The maximum amount of deltas in a chain to resolve when decoding an entry or its header.
+    ///
+    /// If `None`, chains of any length are resolved.
+    max_delta_chain_len: Option<usize>,
 }
 
 /// Information about the pack data file itself
@@ -132,6 +136,12 @@ where
     pub fn alloc_limit_bytes(&self) -> Option<usize> {
         self.alloc_limit_bytes
     }
+    /// The maximum amount of deltas in a chain to resolve when decoding an entry or its header.
+    ///
+    /// A value of `None` means chains of any length are resolved.
+    pub fn max_delta_chain_len(&self) -> Option<usize> {
+        self.max_delta_chain_len
+    }
     /// The position of the byte one past the last pack entry, or in other terms, the first byte of the trailing hash.
     pub fn pack_end(&self) -> usize {
         self.data.len() - self.object_hash.len_in_bytes()
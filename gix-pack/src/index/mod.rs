@@ -141,7 +141,7 @@ pub use access::Entry;
 pub(crate) mod encode;
 ///
 pub mod traverse;
-mod util;
+pub(crate) mod util;
 ///
 pub mod verify;
 ///
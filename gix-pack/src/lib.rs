@@ -28,6 +28,8 @@ pub trait FileData: Deref<Target = [u8]> {}
 
 impl<T> FileData for T where T: Deref<Target = [u8]> {}
 
+///
+pub mod bitmap;
 ///
 pub mod bundle;
 /// A bundle of pack data and the corresponding pack index
@@ -107,6 +107,14 @@ where
         crate::index::access::lookup(id.as_ref(), &self.fan, &|idx| self.oid_at_index(idx))
     }
 
+    /// Find the pack index and the absolute offset into that pack of the object with `id`, or `None` if it wasn't found.
+    ///
+    /// This is the combination of [`File::lookup()`] and [`File::pack_id_and_pack_offset_at_index()`].
+    pub fn lookup_pack_id_and_offset(&self, id: impl AsRef<gix_hash::oid>) -> Option<(PackIndex, data::Offset)> {
+        self.lookup(id)
+            .map(|index| self.pack_id_and_pack_offset_at_index(index))
+    }
+
     /// Given the `index` ranging from 0 to [File::num_objects()], return the pack index and its absolute offset into the pack.
     ///
     /// The pack-index refers to an entry in the [`index_names`][File::index_names()] list, from which the pack can be derived.
@@ -0,0 +1,22 @@
+#!/usr/bin/env bash
+set -eu -o pipefail
+
+git init -q
+git checkout -q -b main
+
+for round in $(seq 10); do
+  mkdir -p "dir-$((round % 3))"
+  seq "$round" > "dir-$((round % 3))/file-$round"
+  echo "$round" > counter
+  git add .
+  git commit -qm "$round"
+done
+git tag -m "a tag object" annotated
+
+# write a single pack along with its reachability bitmap
+git -c pack.writeBitmapHashCache=true repack -adb -q
+
+# record what git considers reachable to compare against
+git rev-list --objects main | cut -d' ' -f1 > reachable-from-main
+git rev-list --objects main~5 | cut -d' ' -f1 > reachable-from-main~5
+git rev-parse main main~5 > commits
@@ -0,0 +1,97 @@
+use std::path::{Path, PathBuf};
+
+use gix_hash::ObjectId;
+use gix_pack::bitmap::File;
+
+fn bitmap() -> crate::Result<(File, gix_pack::index::File, PathBuf)> {
+    let repo = crate::scripted_fixture_read_only("make_pack_bitmap_repo.sh")?;
+    let mut bitmap_path = None;
+    for entry in std::fs::read_dir(repo.join(".git/objects/pack"))? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "bitmap") {
+            bitmap_path = Some(path);
+        }
+    }
+    let bitmap_path = bitmap_path.expect("the fixture writes a bitmap");
+    let index = gix_pack::index::File::at(bitmap_path.with_extension("idx"), crate::object_hash())?;
+    let bitmap = File::at(&bitmap_path, &index)?;
+    Ok((bitmap, index, repo))
+}
+
+fn ids_in(path: &Path) -> crate::Result<Vec<ObjectId>> {
+    Ok(std::fs::read_to_string(path)?
+        .lines()
+        .map(|hex| ObjectId::from_hex(hex.as_bytes()))
+        .collect::<Result<Vec<_>, _>>()?)
+}
+
+fn sorted(mut ids: Vec<ObjectId>) -> Vec<ObjectId> {
+    ids.sort();
+    ids
+}
+
+#[test]
+fn reachable_objects_match_git() -> crate::Result {
+    let (bitmap, index, repo) = bitmap()?;
+    assert_eq!(bitmap.version(), gix_pack::bitmap::Version::V1);
+    assert_eq!(bitmap.num_objects(), index.num_objects());
+    assert_eq!(
+        bitmap.num_commits(),
+        10,
+        "git writes a bitmap for each commit in small repositories"
+    );
+    assert!(bitmap.has_name_hash_cache(), "it was enabled explicitly");
+
+    let commits = ids_in(&repo.join("commits"))?;
+    for (commit, expected) in commits
+        .into_iter()
+        .zip(["reachable-from-main", "reachable-from-main~5"])
+    {
+        assert!(bitmap.contains(commit));
+        let actual = bitmap
+            .reachable_objects(commit)
+            .expect("every commit has a bitmap")
+            .collect();
+        assert_eq!(
+            sorted(actual),
+            sorted(ids_in(&repo.join(expected))?),
+            "the bitmap of {commit} yields the same objects as `git rev-list --objects`, even if it was XOR-compressed"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn objects_of_kind_and_missing_commits() -> crate::Result {
+    let (bitmap, index, _repo) = bitmap()?;
+    let count = |kind| bitmap.objects_of_kind(kind).count();
+    assert_eq!(count(gix_object::Kind::Commit), 10);
+    assert_eq!(count(gix_object::Kind::Tag), 1, "the annotated tag");
+    assert_eq!(
+        count(gix_object::Kind::Commit)
+            + count(gix_object::Kind::Tree)
+            + count(gix_object::Kind::Blob)
+            + count(gix_object::Kind::Tag),
+        index.num_objects() as usize,
+        "each object in the pack has exactly one type"
+    );
+
+    let tag = bitmap.objects_of_kind(gix_object::Kind::Tag).next().expect("one tag");
+    assert!(!bitmap.contains(tag), "only commits have reachability bitmaps");
+    assert!(bitmap.reachable_objects(tag).is_none());
+    Ok(())
+}
+
+#[test]
+fn mismatching_index_is_rejected() -> crate::Result {
+    let (bitmap, _index, _repo) = bitmap()?;
+    let other_index = gix_pack::index::File::at(crate::fixture_path(crate::SMALL_PACK_INDEX), gix_hash::Kind::Sha1)?;
+    let err = File::at(bitmap.path(), &other_index)
+        .err()
+        .expect("the bitmap belongs to another pack");
+    assert!(matches!(
+        err,
+        gix_pack::bitmap::init::Error::PackChecksumMismatch { .. }
+    ));
+    Ok(())
+}
@@ -179,3 +179,86 @@ mod write_to_directory {
         .map_err(Into::into)
     }
 }
+
+mod verify_entries_parallel {
+    use std::sync::atomic::AtomicBool;
+
+    use gix_features::progress;
+    use gix_odb::pack;
+    use gix_pack::bundle::verify::entries::Error;
+
+    use crate::{PACKS_AND_INDICES, SMALL_PACK, SMALL_PACK_INDEX, fixture_path};
+
+    #[test]
+    fn intact_packs_pass() -> crate::Result {
+        for (_index_path, data_path) in PACKS_AND_INDICES {
+            let bundle = pack::Bundle::at(fixture_path(data_path), gix_hash::Kind::Sha1)?;
+            for thread_limit in [Some(1), Some(4), None] {
+                bundle.verify_entries_parallel(
+                    thread_limit,
+                    || pack::cache::Never,
+                    &mut progress::Discard,
+                    &AtomicBool::default(),
+                )?;
+                bundle.verify_entries_parallel(
+                    thread_limit,
+                    pack::cache::lru::StaticLinkedList::<64>::default,
+                    &mut progress::Discard,
+                    &AtomicBool::default(),
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn the_corrupt_entry_with_the_lowest_offset_is_reported() -> crate::Result {
+        let tmp = gix_testtools::tempfile::TempDir::new()?;
+        let pack_path = tmp.path().join("pack.pack");
+        std::fs::copy(fixture_path(SMALL_PACK_INDEX), tmp.path().join("pack.idx"))?;
+        let mut data = std::fs::read(fixture_path(SMALL_PACK))?;
+        let (first_corrupt_offset, second_corrupt_offset) = (1968, 3033);
+        for offset in [second_corrupt_offset, first_corrupt_offset] {
+            data[offset + 5] ^= 0xff;
+        }
+        std::fs::write(&pack_path, data)?;
+
+        let bundle = pack::Bundle::at(pack_path, gix_hash::Kind::Sha1)?;
+        for thread_limit in [Some(1), Some(4), None] {
+            let err = bundle
+                .verify_entries_parallel(
+                    thread_limit,
+                    pack::cache::lru::StaticLinkedList::<64>::default,
+                    &mut progress::Discard,
+                    &AtomicBool::default(),
+                )
+                .expect_err("corrupt entries are detected");
+            let offset = match err {
+                Error::Decode { offset, .. }
+                | Error::ObjectVerify { offset, .. }
+                | Error::Crc32Mismatch { offset, .. } => offset,
+                err => unreachable!("unexpected error: {err:?}"),
+            };
+            assert_eq!(
+                offset, first_corrupt_offset as u64,
+                "the error of the lowest offset is reported no matter how many threads are used"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn interruption_is_respected() -> crate::Result {
+        let bundle = pack::Bundle::at(fixture_path(SMALL_PACK), gix_hash::Kind::Sha1)?;
+        assert!(matches!(
+            bundle.verify_entries_parallel(
+                None,
+                || pack::cache::Never,
+                &mut progress::Discard,
+                &AtomicBool::new(true)
+            ),
+            Err(Error::Interrupted)
+        ));
+        Ok(())
+    }
+}
@@ -7,11 +7,11 @@ fn pack_at(at: &str) -> pack::data::File {
 }
 
 mod method {
-    use std::sync::atomic::AtomicBool;
+    use std::{collections::BTreeMap, sync::atomic::AtomicBool};
 
     use gix_features::progress;
 
-    use crate::{SMALL_PACK, data::file::pack_at, hex_to_id, pack_from_memory_at};
+    use crate::{SMALL_PACK, SMALL_PACK_INDEX, data::file::pack_at, fixture_path, hex_to_id, pack_from_memory_at};
 
     #[test]
     fn checksum() {
@@ -77,6 +77,56 @@ mod method {
         );
     }
 
+    #[test]
+    fn decode_entry_and_header_respect_max_delta_chain_len() {
+        use gix_odb::pack::data::decode::Error;
+        let blob_with_two_deltas = 3033;
+        let mut buf = Vec::new();
+        let mut inflate = Default::default();
+
+        let pack = pack_at(SMALL_PACK).with_max_delta_chain_len(Some(2));
+        let entry = pack.entry(blob_with_two_deltas).expect("valid object type");
+        let out = pack
+            .decode_entry(
+                entry.clone(),
+                &mut buf,
+                &mut inflate,
+                &|_, _| None,
+                &mut gix_odb::pack::cache::Never,
+            )
+            .expect("chains within the limit can be decoded");
+        assert_eq!(out.num_deltas, 2);
+        assert_eq!(
+            pack.decode_header(entry, &mut inflate, &|_| None)
+                .expect("within limit")
+                .num_deltas,
+            2
+        );
+
+        let pack = pack_at(SMALL_PACK).with_max_delta_chain_len(Some(1));
+        let entry = pack.entry(blob_with_two_deltas).expect("valid object type");
+        assert!(
+            matches!(
+                pack.decode_entry(
+                    entry.clone(),
+                    &mut buf,
+                    &mut inflate,
+                    &|_, _| None,
+                    &mut gix_odb::pack::cache::Never
+                ),
+                Err(Error::DeltaChainTooLong { max_delta_chain_len: 1 })
+            ),
+            "delta chains longer than the limit are rejected"
+        );
+        assert!(
+            matches!(
+                pack.decode_header(entry, &mut inflate, &|_| None),
+                Err(Error::DeltaChainTooLong { max_delta_chain_len: 1 })
+            ),
+            "the same is true when only decoding the header"
+        );
+    }
+
     #[test]
     fn iter() -> Result<(), Box<dyn std::error::Error>> {
         let pack = pack_at(SMALL_PACK);
@@ -84,12 +134,35 @@ mod method {
         assert_eq!(it.count(), pack.num_objects() as usize);
         Ok(())
     }
+
+    #[test]
+    fn iter_with_crc() -> Result<(), Box<dyn std::error::Error>> {
+        let pack = pack_at(SMALL_PACK);
+        let idx = gix_pack::index::File::at(fixture_path(SMALL_PACK_INDEX), gix_hash::Kind::Sha1)?;
+        let mut crc_by_offset: BTreeMap<_, _> = idx.iter().map(|e| (e.pack_offset, e.crc32)).collect();
+        for entry in pack.iter_with_crc()? {
+            let entry = entry?;
+            assert!(entry.compressed.is_none(), "compressed bytes are not kept");
+            assert_eq!(
+                crc_by_offset
+                    .remove(&entry.pack_offset)
+                    .expect("every entry is in the index"),
+                entry.crc32,
+                "the CRC32 computed while streaming matches the one stored in the index"
+            );
+        }
+        assert!(crc_by_offset.is_empty(), "all entries of the pack were seen");
+        Ok(())
+    }
 }
 
 /// All hardcoded offsets are obtained via `git pack-verify --verbose  tests/fixtures/packs/pack-a2bf8e71d8c18879e499335762dd95119d93d9f1.idx`
 mod decode_entry {
     use bstr::ByteSlice;
-    use gix_pack::{cache, data::decode::entry::ResolvedBase};
+    use gix_pack::{
+        cache,
+        data::decode::entry::{Outcome, ResolvedBase},
+    };
 
     use crate::{SMALL_PACK, data::file::pack_at, fixture_path, fixup};
 
@@ -138,7 +211,31 @@ mod decode_entry {
         );
     }
 
+    #[test]
+    fn outcome_statistics() {
+        let (_buf, out) = decode_entry_and_outcome_at_offset(1968);
+        assert_eq!(out.num_deltas, 0);
+        assert_eq!(
+            (out.delta_copy_bytes, out.delta_insert_bytes),
+            (0, 0),
+            "undeltified objects don't apply any delta"
+        );
+
+        let (buf, out) = decode_entry_and_outcome_at_offset(3033);
+        assert_eq!(out.num_deltas, 2);
+        assert_eq!(out.object_size, buf.len() as u64);
+        assert_eq!((out.delta_copy_bytes, out.delta_insert_bytes), (1020, 0));
+        assert!(
+            out.delta_copy_bytes + out.delta_insert_bytes >= out.object_size,
+            "all deltas in the chain together produce at least the final object"
+        );
+    }
+
     fn decode_entry_at_offset(offset: u64) -> Vec<u8> {
+        decode_entry_and_outcome_at_offset(offset).0
+    }
+
+    fn decode_entry_and_outcome_at_offset(offset: u64) -> (Vec<u8>, Outcome) {
         #[allow(clippy::ptr_arg)]
         fn resolve_with_panic(_oid: &gix_hash::oid, _out: &mut Vec<u8>) -> Option<ResolvedBase> {
             panic!("should not want to resolve an id here")
@@ -147,15 +244,16 @@ mod decode_entry {
         let p = pack_at(SMALL_PACK);
         let entry = p.entry(offset).expect("valid object type");
         let mut buf = Vec::new();
-        p.decode_entry(
-            entry,
-            &mut buf,
-            &mut Default::default(),
-            &resolve_with_panic,
-            &mut cache::Never,
-        )
-        .expect("valid offset provides valid entry");
-        buf
+        let out = p
+            .decode_entry(
+                entry,
+                &mut buf,
+                &mut Default::default(),
+                &resolve_with_panic,
+                &mut cache::Never,
+            )
+            .expect("valid offset provides valid entry");
+        (buf, out)
     }
 }
 
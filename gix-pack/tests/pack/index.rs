@@ -329,6 +329,8 @@ fn pack_lookup() -> Result<(), Box<dyn std::error::Error>> {
                     decompressed_size: 3456,
                     compressed_size: 1725,
                     object_size: 9621,
+                    delta_copy_bytes: 0,
+                    delta_insert_bytes: 0,
                 },
                 objects_per_chain_length: btreemap! {
                     0 => 18,
@@ -359,6 +361,8 @@ fn pack_lookup() -> Result<(), Box<dyn std::error::Error>> {
                     decompressed_size: 1982,
                     compressed_size: 729,
                     object_size: 2093,
+                    delta_copy_bytes: 0,
+                    delta_insert_bytes: 0,
                 },
                 objects_per_chain_length: btreemap! {
                     0 => 64,
@@ -384,6 +388,8 @@ fn pack_lookup() -> Result<(), Box<dyn std::error::Error>> {
                     decompressed_size: 118,
                     compressed_size: 85,
                     object_size: 293,
+                    delta_copy_bytes: 0,
+                    delta_insert_bytes: 0,
                 },
                 objects_per_chain_length: btreemap! {
                     0 => 30,
@@ -162,7 +162,13 @@ fn general() {
         let (pack_id, pack_offset) = file.pack_id_and_pack_offset_at_index(*idx);
         assert_eq!(pack_id, 0, "we only have one pack here");
         assert_eq!(pack_offset, *expected_pack_offset);
+        assert_eq!(
+            file.lookup_pack_id_and_offset(actual_oid),
+            Some((pack_id, pack_offset)),
+            "the pack and offset can also be looked up directly"
+        );
     }
+    assert_eq!(file.lookup_pack_id_and_offset(object_hash.null()), None);
 
     let mut count = 0;
     for (idx, entry) in file.iter().enumerate() {
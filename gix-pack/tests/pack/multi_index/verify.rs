@@ -32,7 +32,9 @@ fn integrity() {
                     num_deltas: 1,
                     decompressed_size: 47,
                     compressed_size: 46,
-                    object_size: 152
+                    object_size: 152,
+                    delta_copy_bytes: 0,
+                    delta_insert_bytes: 0,
                 },
                 objects_per_chain_length: btreemap! {
                     0 => 326,
@@ -56,7 +58,9 @@ fn integrity() {
                     num_deltas: 1,
                     decompressed_size: 62,
                     compressed_size: 60,
-                    object_size: 176
+                    object_size: 176,
+                    delta_copy_bytes: 0,
+                    delta_insert_bytes: 0,
                 },
                 objects_per_chain_length: btreemap! {
                     0 => 360,
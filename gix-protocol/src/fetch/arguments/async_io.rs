@@ -8,10 +8,23 @@ use crate::{Command, fetch::Arguments};
 
 impl Arguments {
     /// Send fetch arguments to the server, and indicate this is the end of negotiations only if `add_done_argument` is present.
+    ///
+    /// Errors carry the URL of the remote `transport` is connected to.
     pub async fn send<'a, T: Transport + 'a>(
         &mut self,
         transport: &'a mut T,
         add_done_argument: bool,
+    ) -> Result<Box<dyn ExtendedBufRead<'a> + Unpin + 'a>, client::Error> {
+        let remote_url = transport.to_url().into_owned();
+        self.send_inner(transport, add_done_argument)
+            .await
+            .map_err(|err| err.with_remote_url(remote_url))
+    }
+
+    async fn send_inner<'a, T: Transport + 'a>(
+        &mut self,
+        transport: &'a mut T,
+        add_done_argument: bool,
     ) -> Result<Box<dyn ExtendedBufRead<'a> + Unpin + 'a>, client::Error> {
         if self.haves.is_empty() {
             assert!(add_done_argument, "If there are no haves, is_done must be true.");
@@ -39,6 +39,7 @@ where
             .map(|(k, v)| (k.as_str(), v.as_deref()))
             .collect();
         let supported_versions: Vec<_> = transport.supported_protocol_versions().into();
+        let remote_url = transport.to_url().into_owned();
 
         let result = transport.handshake(service, &extra_parameters).await;
         let SetServiceResponse {
@@ -74,7 +75,8 @@ where
                 }
             }
             Err(err) => Err(err),
-        }?;
+        }
+        .map_err(|err| Error::Transport(err.with_remote_url(remote_url)))?;
 
         if !supported_versions.is_empty() && !supported_versions.contains(&actual_protocol) {
             return Err(Error::TransportProtocolPolicyViolation {
@@ -69,6 +69,83 @@ pub(crate) mod hero {
         pub capabilities: gix_transport::client::Capabilities,
     }
 
+    #[cfg(any(feature = "blocking-client", feature = "async-client"))]
+    mod ls_refs {
+        #[cfg(feature = "async-client")]
+        use crate::transport::client::async_io;
+        #[cfg(feature = "blocking-client")]
+        use crate::transport::client::blocking_io;
+        use crate::{Handshake, handshake::Ref, ls_refs::RefPrefixes};
+        use gix_features::progress::Progress;
+        use std::borrow::Cow;
+
+        impl Handshake {
+            /// Return the refs of a `Protocol::V1` (or `V0`) handshake, or `None` if `Protocol::V2` requires an `ls-refs` command.
+            fn v1_refs(&self) -> Option<Vec<Ref>> {
+                match self.server_protocol_version {
+                    gix_transport::Protocol::V0 | gix_transport::Protocol::V1 => {
+                        Some(self.refs.clone().unwrap_or_default())
+                    }
+                    gix_transport::Protocol::V2 => None,
+                }
+            }
+
+            /// Return all refs advertised by the remote, either by copying those received as part of the `Protocol::V1`
+            /// handshake, or by invoking the `ls-refs` command on `transport` in case of `Protocol::V2`.
+            ///
+            /// `ref_prefixes` limit the refs returned by the server and only apply to `Protocol::V2`,
+            /// `user_agent` identifies us to the server, and `progress` is used to provide feedback.
+            /// If `trace_packetlines` is `true`, all packetlines received or sent will be passed to the facilities
+            /// of the `gix-trace` crate.
+            ///
+            /// Note that [`Handshake::refs`] is left untouched, so this can be called repeatedly no matter the protocol version.
+            #[cfg(feature = "async-client")]
+            pub async fn ls_refs_async(
+                &self,
+                transport: &mut impl async_io::Transport,
+                ref_prefixes: Option<RefPrefixes>,
+                user_agent: (&'static str, Option<Cow<'static, str>>),
+                progress: &mut impl Progress,
+                trace_packetlines: bool,
+            ) -> Result<Vec<Ref>, crate::ls_refs::Error> {
+                if let Some(refs) = self.v1_refs() {
+                    return Ok(refs);
+                }
+                crate::LsRefsCommand::new(ref_prefixes, &self.capabilities, user_agent)
+                    .invoke_async(transport, progress, trace_packetlines)
+                    .await
+            }
+
+            /// Return all refs advertised by the remote, either by copying those received as part of the `Protocol::V1`
+            /// handshake, or by invoking the `ls-refs` command on `transport` in case of `Protocol::V2`.
+            ///
+            /// `ref_prefixes` limit the refs returned by the server and only apply to `Protocol::V2`,
+            /// `user_agent` identifies us to the server, and `progress` is used to provide feedback.
+            /// If `trace_packetlines` is `true`, all packetlines received or sent will be passed to the facilities
+            /// of the `gix-trace` crate.
+            ///
+            /// Note that [`Handshake::refs`] is left untouched, so this can be called repeatedly no matter the protocol version.
+            #[cfg(feature = "blocking-client")]
+            pub fn ls_refs_blocking(
+                &self,
+                transport: &mut impl blocking_io::Transport,
+                ref_prefixes: Option<RefPrefixes>,
+                user_agent: (&'static str, Option<Cow<'static, str>>),
+                progress: &mut impl Progress,
+                trace_packetlines: bool,
+            ) -> Result<Vec<Ref>, crate::ls_refs::Error> {
+                if let Some(refs) = self.v1_refs() {
+                    return Ok(refs);
+                }
+                crate::LsRefsCommand::new(ref_prefixes, &self.capabilities, user_agent).invoke_blocking(
+                    transport,
+                    progress,
+                    trace_packetlines,
+                )
+            }
+        }
+    }
+
     #[cfg(feature = "fetch")]
     mod fetch {
         #[cfg(feature = "async-client")]
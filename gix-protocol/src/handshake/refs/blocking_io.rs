@@ -1,4 +1,4 @@
-use crate::transport::client::blocking_io::ReadlineBufRead;
+use crate::transport::client::blocking_io::{ReadlineBufRead, SetServiceResponse};
 use crate::{
     fetch::response::ShallowUpdate,
     handshake::{Ref, refs, refs::parse::Error},
@@ -39,3 +39,21 @@ pub fn from_v1_refs_received_as_part_of_handshake_and_capabilities<'a>(
     }
     Ok((out_refs.into_iter().map(Into::into).collect(), out_shallow))
 }
+
+/// Parse the refs advertised by the server as part of the `Protocol::V0|V1` handshake in `response`, along with
+/// the shallow updates sent with them.
+///
+/// Returns `None` if the server responded with `Protocol::V2`, which doesn't advertise refs during the handshake.
+/// Use [`LsRefsCommand`](crate::LsRefsCommand) or [`Handshake::ls_refs_blocking()`](crate::Handshake::ls_refs_blocking())
+/// to obtain them instead.
+///
+/// Note that the advertised refs are consumed, so [`SetServiceResponse::refs`] will be `None` afterwards.
+#[allow(clippy::type_complexity)]
+pub fn from_set_service_response(
+    response: &mut SetServiceResponse<'_>,
+) -> Result<Option<(Vec<Ref>, Vec<ShallowUpdate>)>, Error> {
+    let Some(mut in_refs) = response.refs.take() else {
+        return Ok(None);
+    };
+    from_v1_refs_received_as_part_of_handshake_and_capabilities(&mut in_refs, response.capabilities.iter()).map(Some)
+}
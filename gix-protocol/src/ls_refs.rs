@@ -188,6 +188,7 @@ pub(crate) mod function {
 
             progress.step();
             progress.set_name("list refs".into());
+            let remote_url = transport.to_url().into_owned();
             let mut remote_refs = transport
                 .invoke(
                     Command::LsRefs.as_str(),
@@ -199,7 +200,8 @@ pub(crate) mod function {
                     },
                     trace,
                 )
-                .await?;
+                .await
+                .map_err(|err| err.with_remote_url(remote_url))?;
             Ok(from_v2_refs(&mut remote_refs).await?)
         }
 
@@ -224,16 +226,19 @@ pub(crate) mod function {
 
             progress.step();
             progress.set_name("list refs".into());
-            let mut remote_refs = transport.invoke(
-                Command::LsRefs.as_str(),
-                self.features.into_iter(),
-                if self.arguments.is_empty() {
-                    None
-                } else {
-                    Some(self.arguments.into_iter())
-                },
-                trace,
-            )?;
+            let remote_url = transport.to_url().into_owned();
+            let mut remote_refs = transport
+                .invoke(
+                    Command::LsRefs.as_str(),
+                    self.features.into_iter(),
+                    if self.arguments.is_empty() {
+                        None
+                    } else {
+                        Some(self.arguments.into_iter())
+                    },
+                    trace,
+                )
+                .map_err(|err| err.with_remote_url(remote_url))?;
             Ok(from_v2_refs(&mut remote_refs)?)
         }
     }
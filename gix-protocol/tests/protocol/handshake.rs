@@ -180,6 +180,54 @@ shallow dce0ea858eef7ff61ad345cc5cdac62203fb3c10"
     );
 }
 
+#[maybe_async::test(feature = "blocking-client", async(feature = "async-client", async_std::test))]
+async fn extract_references_from_set_service_response() {
+    #[cfg(feature = "async-client")]
+    use gix_transport::client::async_io::SetServiceResponse;
+    #[cfg(feature = "blocking-client")]
+    use gix_transport::client::blocking_io::SetServiceResponse;
+
+    let mut response = SetServiceResponse {
+        actual_protocol: gix_transport::Protocol::V1,
+        capabilities: Capabilities::from_bytes(b"\0symref=HEAD:refs/heads/main")
+            .expect("valid capabilities")
+            .0,
+        refs: Some(Box::new(Fixture(
+            "73a6868963993a3328e7d8fe94e5a6ac5078a944 HEAD
+73a6868963993a3328e7d8fe94e5a6ac5078a944 refs/heads/main"
+                .as_bytes(),
+        ))),
+    };
+    let (out, shallow) = refs::from_set_service_response(&mut response)
+        .await
+        .expect("no failure from valid input")
+        .expect("V1 responses advertise refs");
+    assert!(shallow.is_empty());
+    assert_eq!(
+        out,
+        vec![
+            Ref::Symbolic {
+                full_ref_name: "HEAD".into(),
+                target: "refs/heads/main".into(),
+                tag: None,
+                object: oid("73a6868963993a3328e7d8fe94e5a6ac5078a944")
+            },
+            Ref::Direct {
+                full_ref_name: "refs/heads/main".into(),
+                object: oid("73a6868963993a3328e7d8fe94e5a6ac5078a944")
+            },
+        ]
+    );
+    assert!(response.refs.is_none(), "the advertised refs are consumed");
+    let out = refs::from_set_service_response(&mut response)
+        .await
+        .expect("no failure");
+    assert!(
+        out.is_none(),
+        "without advertised refs, as in V2, there is nothing to return"
+    );
+}
+
 #[cfg(any(feature = "async-client", feature = "blocking-client"))]
 struct Fixture<'a>(&'a [u8]);
 
@@ -291,3 +339,157 @@ impl gix_transport::client::async_io::ReadlineBufRead for Fixture<'_> {
         Ok(len)
     }
 }
+
+mod ls_refs {
+    use bstr::ByteSlice;
+    use gix_features::progress;
+    use gix_protocol::{Handshake, handshake::Ref};
+    use gix_transport::{Protocol, Service, client::git::ConnectMode};
+
+    use crate::fetch::{oid, transport};
+
+    #[maybe_async::maybe_async]
+    async fn handshake(transport: &mut (impl Transport + Send)) -> Result<Handshake, gix_protocol::handshake::Error> {
+        gix_protocol::handshake(
+            transport,
+            Service::UploadPack,
+            |_action| unreachable!("no authentication needed"),
+            Vec::new(),
+            &mut progress::Discard,
+        )
+        .await
+    }
+
+    #[cfg(feature = "blocking-client")]
+    use gix_transport::client::blocking_io::Transport;
+    #[cfg(feature = "blocking-client")]
+    fn ls_refs(
+        handshake: &Handshake,
+        transport: &mut impl Transport,
+    ) -> Result<Vec<Ref>, gix_protocol::ls_refs::Error> {
+        handshake.ls_refs_blocking(transport, None, ("agent", None), &mut progress::Discard, false)
+    }
+
+    #[cfg(feature = "async-client")]
+    use gix_transport::client::async_io::Transport;
+    #[cfg(feature = "async-client")]
+    async fn ls_refs(
+        handshake: &Handshake,
+        transport: &mut impl Transport,
+    ) -> Result<Vec<Ref>, gix_protocol::ls_refs::Error> {
+        handshake
+            .ls_refs_async(transport, None, ("agent", None), &mut progress::Discard, false)
+            .await
+    }
+
+    fn expected_refs() -> Vec<Ref> {
+        vec![
+            Ref::Symbolic {
+                full_ref_name: "HEAD".into(),
+                target: "refs/heads/master".into(),
+                tag: None,
+                object: oid("808e50d724f604f69ab93c6da2919c014667bedb"),
+            },
+            Ref::Direct {
+                full_ref_name: "refs/heads/master".into(),
+                object: oid("808e50d724f604f69ab93c6da2919c014667bedb"),
+            },
+        ]
+    }
+
+    #[maybe_async::test(feature = "blocking-client", async(feature = "async-client", async_std::test))]
+    async fn v1_returns_the_advertised_refs_each_time() -> crate::Result {
+        let mut transport = transport(Vec::new(), "v1/clone.response", Protocol::V1, ConnectMode::Daemon);
+        let handshake = handshake(&mut transport).await?;
+        assert_eq!(handshake.server_protocol_version, Protocol::V1);
+
+        let refs = ls_refs(&handshake, &mut transport).await?;
+        assert_eq!(refs, expected_refs());
+        let refs = ls_refs(&handshake, &mut transport).await?;
+        assert_eq!(
+            refs,
+            expected_refs(),
+            "refs aren't consumed, so calling it again yields the same result"
+        );
+        assert_eq!(handshake.refs, Some(expected_refs()), "the handshake is left untouched");
+        assert!(
+            transport.into_inner().1.find("ls-refs").is_none(),
+            "no V2 command is sent over a V1 connection"
+        );
+        Ok(())
+    }
+
+    #[maybe_async::test(feature = "blocking-client", async(feature = "async-client", async_std::test))]
+    async fn v2_sends_the_ls_refs_command() -> crate::Result {
+        let mut transport = transport(Vec::new(), "v2/clone.response", Protocol::V2, ConnectMode::Daemon);
+        let handshake = handshake(&mut transport).await?;
+        assert_eq!(handshake.server_protocol_version, Protocol::V2);
+        assert_eq!(handshake.refs, None, "V2 doesn't advertise refs in the handshake");
+
+        let refs = ls_refs(&handshake, &mut transport).await?;
+        assert_eq!(refs, expected_refs());
+        assert!(
+            transport.into_inner().1.find("command=ls-refs").is_some(),
+            "the refs are obtained with an ls-refs command"
+        );
+        Ok(())
+    }
+}
+
+#[cfg(all(feature = "blocking-client", unix))]
+mod ssh_failures {
+    use gix_transport::client::blocking_io::{connect, ssh};
+
+    /// Perform a handshake through an `ssh` program that prints `stderr_line` like OpenSSH would, and return the
+    /// kind of the resulting IO error, asserting that no authentication is attempted.
+    fn handshake_io_error_kind(stderr_line: &str) -> std::io::ErrorKind {
+        let transport = connect::connect(
+            "ssh://example.com/repo.git",
+            connect::Options {
+                version: gix_transport::Protocol::V2,
+                ssh: ssh::connect::Options {
+                    command: Some(format!("echo '{stderr_line}' >&2; sleep 1; false").into()),
+                    kind: Some(ssh::ProgramKind::Ssh),
+                    ..Default::default()
+                },
+                trace: false,
+                url_rewrite: None,
+            },
+        )
+        .expect("valid URL and options");
+        let err = gix_protocol::handshake(
+            transport,
+            gix_transport::Service::UploadPack,
+            |action| unreachable!("credentials can't help, so there is no need to ask for them: {action:?}"),
+            Vec::new(),
+            &mut gix_features::progress::Discard,
+        )
+        .expect_err("the ssh program fails");
+        let mut source: Option<&(dyn std::error::Error + 'static)> = Some(&err);
+        while let Some(err) = source {
+            if let Some(err) = err.downcast_ref::<std::io::Error>() {
+                return err.kind();
+            }
+            source = err.source();
+        }
+        unreachable!("an IO error is the cause: {err:?}")
+    }
+
+    #[test]
+    fn host_key_verification_failure_does_not_authenticate() {
+        assert_eq!(
+            handshake_io_error_kind("Host key verification failed."),
+            std::io::ErrorKind::Other
+        );
+    }
+
+    #[test]
+    fn incorrect_passphrase_does_not_authenticate() {
+        assert_eq!(
+            handshake_io_error_kind(
+                "Load key \"/keys/id_ed25519\": incorrect passphrase supplied to decrypt private key"
+            ),
+            std::io::ErrorKind::InvalidInput
+        );
+    }
+}
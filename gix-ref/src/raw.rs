@@ -12,8 +12,12 @@ pub struct Reference {
     pub target: Target,
     /// The fully peeled object to which this reference ultimately points to after following all symbolic refs and all annotated
     /// tags. Only guaranteed to be set after
-    /// [`Reference::peel_to_id()`](crate::file::ReferenceExt::peel_to_id) was called or if this reference originated
-    /// from a packed ref.
+    /// [`Reference::peel_to_id()`](crate::file::ReferenceExt::peel_to_id) was called, if this reference originated
+    /// from a packed ref, or if it was obtained from an iterator adapted with
+    /// [`LooseThenPacked::peeled()`](crate::file::iter::LooseThenPacked::peeled()).
+    ///
+    /// Packed refs provide the peeled value recorded in the `packed-refs` file, whether they are found or iterated,
+    /// which makes peeling them possible without accessing the object database.
     pub peeled: Option<ObjectId>,
 }
 
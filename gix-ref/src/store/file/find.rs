@@ -43,6 +43,20 @@ impl file::Store {
         self.find_one_with_verified_input(partial.try_into()?, packed.as_ref().map(|b| &***b))
     }
 
+    /// Find multiple references by their partial `names` at once, returning one entry for each of the `names` in the same order,
+    /// with `None` for references that don't exist.
+    ///
+    /// Unlike calling [`file::Store::try_find()`] repeatedly, the packed buffer is checked for modifications only once
+    /// and then reused for all lookups.
+    pub fn try_find_many(&self, names: &[&PartialNameRef]) -> Result<Vec<Option<Reference>>, Error> {
+        let packed = self.assure_packed_refs_uptodate()?;
+        let packed = packed.as_ref().map(|b| &***b);
+        names
+            .iter()
+            .map(|name| self.find_one_with_verified_input(name, packed))
+            .collect()
+    }
+
     /// Similar to [`file::Store::find()`] but a non-existing ref is treated as error.
     ///
     /// Find only loose references, that is references that aren't in the packed-refs buffer.
@@ -386,6 +400,24 @@ pub mod existing {
             self.find_existing_inner(partial, packed)
         }
 
+        /// Find the reference partially named `name` and follow all of its symbolic targets, returning the reference
+        /// that ultimately points to an object.
+        ///
+        /// Like `git`, at most 5 levels of symbolic references are followed, and cycles are detected and reported
+        /// as [`Cycle`](crate::peel::to_object::Error::Cycle), separately from
+        /// [exceeding the depth limit](crate::peel::to_object::Error::DepthLimitExceeded).
+        pub fn resolve(&self, name: &PartialNameRef) -> Result<Reference, crate::peel::to_object::Error> {
+            use crate::file::ReferenceExt;
+
+            let packed = self
+                .assure_packed_refs_uptodate()
+                .map_err(|err| Error::Find(find::Error::PackedOpen(err)))?;
+            let packed = packed.as_ref().map(|b| &***b);
+            let mut reference = self.find_existing_inner(name, packed)?;
+            reference.follow_to_object_packed(self, packed)?;
+            Ok(reference)
+        }
+
         /// Similar to [`file::Store::find()`] won't handle packed-refs.
         pub fn find_loose<'a, Name, E>(&self, partial: Name) -> Result<loose::Reference, Error>
         where
@@ -97,6 +97,22 @@ pub mod create_or_update {
     use crate::store_impl::{file, file::WriteReflog};
 
     impl file::Store {
+        /// Return `true` if a line would be appended to the reflog of `name` by
+        /// [`reflog_create_or_append()`](Self::reflog_create_or_append()), without touching the file system beyond
+        /// checking for the existence of the reflog.
+        pub(crate) fn reflog_would_be_appended(&self, name: &crate::FullNameRef, force_create_reflog: bool) -> bool {
+            match self.write_reflog {
+                WriteReflog::Disable => false,
+                WriteReflog::Always => true,
+                WriteReflog::Normal => {
+                    let (reflog_base, full_name) = self.reflog_base_and_relative_path(name);
+                    force_create_reflog
+                        || self.should_autocreate_reflog(&full_name)
+                        || reflog_base.join(&full_name).is_file()
+                }
+            }
+        }
+
         #[allow(clippy::too_many_arguments)]
         pub(crate) fn reflog_create_or_append(
             &self,
@@ -230,6 +246,179 @@ pub mod create_or_update {
     use crate::FullNameRef;
 }
 
+///
+pub mod expire {
+    use std::{
+        collections::{HashSet, VecDeque},
+        io::Write,
+    };
+
+    use gix_actor::date::Time;
+    use gix_hash::ObjectId;
+    use gix_object::{FindExt, bstr::ByteSlice};
+
+    use crate::{
+        FullNameRef,
+        file::ReferenceExt,
+        store_impl::{file, file::log},
+    };
+
+    /// Information about the entries of a reflog after [expiring](file::Store::reflog_expire()) it.
+    #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Statistics {
+        /// The amount of entries that remain in the reflog.
+        pub kept: usize,
+        /// The amount of entries that were removed from the reflog.
+        pub dropped: usize,
+    }
+
+    impl file::Store {
+        /// Remove all entries from the reflog of `name` that are older than `expire`, similar to
+        /// `git reflog expire --expire=<time>`, and return how many entries were kept and dropped.
+        ///
+        /// If `expire_unreachable` is set, entries older than it are also removed if the object they recorded isn't
+        /// reachable from the commit the reference currently points to, similar to `--expire-unreachable=<time>`.
+        /// `objects` are used to traverse the commit graph for this, and will not be used otherwise.
+        ///
+        /// The reflog is locked with `lock_mode` while it is rewritten, and it is only changed if entries are dropped.
+        /// Non-existing reflogs are not an error, and yield empty statistics.
+        pub fn reflog_expire(
+            &self,
+            name: &FullNameRef,
+            expire: Time,
+            expire_unreachable: Option<Time>,
+            objects: &dyn gix_object::Find,
+            lock_mode: gix_lock::acquire::Fail,
+        ) -> Result<Statistics, Error> {
+            let path = self.reflog_path(name);
+            if !path.is_file() {
+                return Ok(Statistics::default());
+            }
+            let mut lock = gix_lock::File::acquire_to_update_resource(&path, lock_mode, None)?;
+            let buf = match std::fs::read(&path) {
+                Ok(buf) => buf,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Statistics::default()),
+                Err(err) => return Err(Error::Io(err)),
+            };
+
+            let mut entries = Vec::new();
+            for (line_number, line) in buf.lines_with_terminator().enumerate() {
+                let entry = log::LineRef::from_bytes(line.trim_end_with(|c| c == '\n')).map_err(|err| {
+                    log::iter::decode::Error::new(err, log::iter::decode::LineNumber::FromStart(line_number))
+                })?;
+                let seconds = entry.signature.seconds();
+                let needs_reachability =
+                    expire_unreachable.is_some_and(|expire_unreachable| seconds < expire_unreachable.seconds);
+                entries.push((
+                    line,
+                    seconds >= expire.seconds,
+                    needs_reachability.then(|| entry.new_oid()),
+                ));
+            }
+
+            let candidates: HashSet<_> = entries
+                .iter()
+                .filter_map(|(_, newer_than_expire, id)| id.filter(|_| *newer_than_expire))
+                .collect();
+            let reachable = if candidates.is_empty() {
+                candidates
+            } else {
+                self.reachable_from_tip(name, objects, candidates)?
+            };
+
+            let mut stats = Statistics::default();
+            let mut kept_lines = Vec::with_capacity(buf.len());
+            for (line, newer_than_expire, id) in entries {
+                let keep = newer_than_expire && id.is_none_or(|id| reachable.contains(&id));
+                if keep {
+                    stats.kept += 1;
+                    kept_lines.extend_from_slice(line);
+                } else {
+                    stats.dropped += 1;
+                }
+            }
+
+            if stats.dropped != 0 {
+                lock.write_all(&kept_lines)?;
+                lock.commit()?;
+            }
+            Ok(stats)
+        }
+
+        /// Return those of `candidates` that are reachable from the object `name` currently points to,
+        /// or nothing if it doesn't exist.
+        ///
+        /// The traversal stops once all `candidates` were found, and missing commits are treated as boundary,
+        /// as they are expected in shallow clones.
+        fn reachable_from_tip(
+            &self,
+            name: &FullNameRef,
+            objects: &dyn gix_object::Find,
+            mut candidates: HashSet<ObjectId>,
+        ) -> Result<HashSet<ObjectId>, Error> {
+            let mut reachable = HashSet::new();
+            let Some(mut tip) = self.try_find(name)? else {
+                return Ok(reachable);
+            };
+            let tip = tip.follow_to_object_packed(self, self.cached_packed_buffer()?.as_ref().map(|b| &***b))?;
+
+            let mut buf = Vec::new();
+            let mut seen = HashSet::new();
+            let mut queue = VecDeque::from([tip]);
+            while let Some(id) = queue.pop_front() {
+                if !seen.insert(id) {
+                    continue;
+                }
+                if candidates.remove(&id) {
+                    reachable.insert(id);
+                    if candidates.is_empty() {
+                        break;
+                    }
+                }
+                match objects.find_commit_iter(&id, &mut buf) {
+                    Ok(commit) => queue.extend(commit.parent_ids()),
+                    Err(
+                        gix_object::find::existing_iter::Error::ObjectKind { .. }
+                        | gix_object::find::existing_iter::Error::NotFound { .. },
+                    ) => {}
+                    Err(err) => return Err(Error::FindCommit(err)),
+                }
+            }
+            Ok(reachable)
+        }
+    }
+
+    mod error {
+        use crate::{
+            peel,
+            store_impl::{file, file::log, packed},
+        };
+
+        /// The error returned by [`file::Store::reflog_expire()`].
+        #[derive(Debug, thiserror::Error)]
+        #[allow(missing_docs)]
+        pub enum Error {
+            #[error("The reflog could not be locked for rewriting")]
+            Lock(#[from] gix_lock::acquire::Error),
+            #[error("The reflog could not be read or written")]
+            Io(#[from] std::io::Error),
+            #[error("The rewritten reflog could not be committed")]
+            Commit(#[from] gix_lock::commit::Error<gix_lock::File>),
+            #[error("A reflog entry could not be parsed")]
+            Decode(#[from] log::iter::decode::Error),
+            #[error("The reference to determine reachable entries from could not be found")]
+            FindTip(#[from] file::find::Error),
+            #[error("The packed-refs buffer could not be opened")]
+            PackedOpen(#[from] packed::buffer::open::Error),
+            #[error("The reference to determine reachable entries from could not be peeled")]
+            PeelTip(#[from] peel::to_object::Error),
+            #[error("A commit reachable from the reference tip could not be read")]
+            FindCommit(#[source] gix_object::find::existing_iter::Error),
+        }
+    }
+    pub use error::Error;
+}
+
 mod error {
     /// The error returned by [`crate::file::Store::reflog_iter()`].
     #[derive(Debug, thiserror::Error)]
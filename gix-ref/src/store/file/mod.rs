@@ -116,6 +116,8 @@ pub struct Transaction<'s, 'p> {
     packed_transaction: Option<crate::store_impl::packed::Transaction>,
     updates: Option<Vec<transaction::Edit>>,
     packed_refs: transaction::PackedRefs<'p>,
+    /// If `true`, the transaction was prepared without locks and can't be committed.
+    read_only: bool,
 }
 
 ///
@@ -124,7 +126,7 @@ mod overlay_iter;
 
 ///
 pub mod iter {
-    pub use super::overlay_iter::{LooseThenPacked, Platform};
+    pub use super::overlay_iter::{LooseThenPacked, Peeled, Platform};
 
     ///
     pub mod loose_then_packed {
@@ -144,5 +146,8 @@ pub mod transaction;
 ///
 pub mod packed;
 
-mod raw_ext;
+///
+pub mod pack_refs;
+
+pub(crate) mod raw_ext;
 pub use raw_ext::ReferenceExt;
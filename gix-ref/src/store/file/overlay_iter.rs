@@ -1,4 +1,4 @@
-use gix_object::bstr::ByteSlice;
+use gix_object::bstr::{BString, ByteSlice};
 use gix_path::RelativePath;
 use std::{
     borrow::Cow,
@@ -9,7 +9,7 @@ use std::{
 };
 
 use crate::{
-    BStr, FullName, Namespace, Reference,
+    BStr, FullName, Namespace, Reference, Target,
     file::loose::{self, iter::SortedLoosePaths},
     store_impl::{file, packed},
 };
@@ -28,6 +28,8 @@ pub struct LooseThenPacked<'p, 's> {
     #[allow(dead_code)]
     iter_common_dir: Option<Peekable<SortedLoosePaths>>,
     buf: Vec<u8>,
+    /// If set, only references whose full name matches this glob pattern will be returned.
+    glob: Option<BString>,
 }
 
 enum IterKind {
@@ -115,10 +117,8 @@ impl<'p> LooseThenPacked<'p, '_> {
     }
 }
 
-impl Iterator for LooseThenPacked<'_, '_> {
-    type Item = Result<Reference, Error>;
-
-    fn next(&mut self) -> Option<Self::Item> {
+impl LooseThenPacked<'_, '_> {
+    fn next_overlaid(&mut self) -> Option<Result<Reference, Error>> {
         fn advance_to_non_private(iter: &mut Peekable<SortedLoosePaths>) {
             while let Some(Ok((_path, name))) = iter.peek() {
                 if name.category().is_some_and(|cat| cat.is_worktree_private()) {
@@ -190,6 +190,71 @@ impl Iterator for LooseThenPacked<'_, '_> {
     }
 }
 
+impl Iterator for LooseThenPacked<'_, '_> {
+    type Item = Result<Reference, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let res = self.next_overlaid()?;
+            match (&self.glob, &res) {
+                (Some(glob), Ok(r))
+                    if !gix_glob::wildmatch(
+                        glob.as_bstr(),
+                        r.name.as_bstr(),
+                        gix_glob::wildmatch::Mode::NO_MATCH_SLASH_LITERAL,
+                    ) =>
+                {
+                    continue;
+                }
+                _ => return Some(res),
+            }
+        }
+    }
+}
+
+/// An iterator adapter to set the [`peeled`](Reference::peeled) field of references pointing to an object,
+/// as returned by [`LooseThenPacked::peeled()`].
+pub struct Peeled<'p, 's, 'o> {
+    inner: LooseThenPacked<'p, 's>,
+    objects: &'o dyn gix_object::FindObjectOrHeader,
+    buf: Vec<u8>,
+}
+
+impl<'p, 's> LooseThenPacked<'p, 's> {
+    /// Peel each reference pointing to an object to the end of its chain of annotated tags using `objects`, and store
+    /// the result in its [`peeled`](Reference::peeled) field while leaving its `target` untouched.
+    ///
+    /// This way, annotated tags provide both the id of the tag object and the id of the object it ultimately points to.
+    /// The kind of each object is determined by its header, so only annotated tags are decoded.
+    /// Packed references whose peeled value is recorded in `packed-refs` don't need an object lookup, and
+    /// symbolic references are returned as is.
+    pub fn peeled<'o>(self, objects: &'o dyn gix_object::FindObjectOrHeader) -> Peeled<'p, 's, 'o> {
+        Peeled {
+            inner: self,
+            objects,
+            buf: Vec::new(),
+        }
+    }
+}
+
+impl Iterator for Peeled<'_, '_, '_> {
+    type Item = Result<Reference, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut reference = match self.inner.next()? {
+            Ok(reference) => reference,
+            Err(err) => return Some(Err(err)),
+        };
+        if let (None, Target::Object(id)) = (reference.peeled, &reference.target) {
+            match file::raw_ext::peel_tags_by_header(*id, reference.name.as_bstr(), self.objects, &mut self.buf) {
+                Ok(peeled) => reference.peeled = Some(peeled),
+                Err(err) => return Some(Err(Error::Peel(err))),
+            }
+        }
+        Some(Ok(reference))
+    }
+}
+
 impl<'repo> Platform<'repo> {
     /// Return an iterator over all references, loose or packed, sorted by their name.
     ///
@@ -211,6 +276,17 @@ impl<'repo> Platform<'repo> {
             .iter_prefixed_packed(prefix, self.packed.as_ref().map(|b| &***b))
     }
 
+    /// As [`iter(…)`](file::Store::iter()), but only returns references whose full name matches the glob `pattern`,
+    /// i.e. `refs/heads/feature/*` or `refs/remotes/**/main`.
+    ///
+    /// Within `pattern`, `*` doesn't match `/` and thus stays within a single path component, while `**` matches across them.
+    /// Traversal starts at the directory spelled out literally before the first wildcard, so the more specific the beginning
+    /// of the pattern, the fewer references have to be looked at.
+    pub fn globbed<'p>(&'p self, pattern: &BStr) -> std::io::Result<LooseThenPacked<'p, 'repo>> {
+        self.store
+            .iter_globbed_packed(pattern, self.packed.as_ref().map(|b| &***b))
+    }
+
     /// Return an iterator over the pseudo references, like `HEAD` or `FETCH_HEAD`, or anything else suffixed with `HEAD`
     /// in the root of the `.git` directory, sorted by name.
     pub fn pseudo<'p>(&'p self) -> std::io::Result<LooseThenPacked<'p, 'repo>> {
@@ -424,6 +500,31 @@ impl file::Store {
         }
     }
 
+    /// As [`iter(…)`](file::Store::iter()), but only returns references whose full name matches the glob `pattern`,
+    /// i.e. `refs/heads/feature/*`, with `*` matching within a path component and `**` matching across components.
+    ///
+    /// The literal portion of `pattern` up to the last `/` before the first wildcard is used as
+    /// [prefix](Self::iter_prefixed_packed()) to limit the traversal.
+    pub fn iter_globbed_packed<'s, 'p>(
+        &'s self,
+        pattern: &BStr,
+        packed: Option<&'p packed::Buffer>,
+    ) -> std::io::Result<LooseThenPacked<'p, 's>> {
+        let literal_end = pattern.find_byteset(b"*?[\\").unwrap_or(pattern.len());
+        let iter = match pattern[..literal_end].rfind_byte(b'/') {
+            Some(slash_pos) => {
+                let prefix = pattern[..=slash_pos].as_bstr();
+                let prefix = prefix.try_into().map_err(std::io::Error::other)?;
+                self.iter_prefixed_packed(prefix, packed)?
+            }
+            None => self.iter_packed(packed)?,
+        };
+        Ok(LooseThenPacked {
+            glob: Some(pattern.to_owned()),
+            ..iter
+        })
+    }
+
     fn iter_from_info<'s, 'p>(
         &'s self,
         git_dir_info: IterInfo<'_>,
@@ -449,6 +550,7 @@ impl file::Store {
             iter_common_dir: common_dir_info.map(IterInfo::into_iter),
             buf: Vec::new(),
             namespace: self.namespace.as_ref(),
+            glob: None,
         })
     }
 }
@@ -475,6 +577,8 @@ mod error {
         },
         #[error("Invalid reference in line {line_number}: {invalid_line:?}")]
         PackedReference { invalid_line: BString, line_number: usize },
+        #[error("The object a reference points to could not be peeled")]
+        Peel(#[from] crate::peel::to_id::Error),
     }
 }
 pub use error::Error;
@@ -0,0 +1,103 @@
+use gix_object::bstr::BString;
+
+use crate::{
+    store_impl::{file, file::transaction::PackedRefs},
+    transaction::{Change, LogChange, PreviousValue, RefEdit},
+};
+
+/// Options for use in [`file::Store::pack_refs()`].
+#[derive(Debug, Clone)]
+pub struct Options {
+    /// If set, only loose references whose full name starts with this prefix, like `refs/tags/`, are packed.
+    /// Otherwise, all loose references are packed, similar to `git pack-refs --all`.
+    pub prefix: Option<BString>,
+    /// If `true`, loose references are removed once they were written to the `packed-refs` file, like `git pack-refs` does
+    /// by default. Otherwise they are kept, similar to `git pack-refs --no-prune`.
+    pub prune: bool,
+    /// How to deal with locks that are already held by another process, both for the loose references and `packed-refs`.
+    pub lock_fail_mode: gix_lock::acquire::Fail,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            prefix: None,
+            prune: true,
+            lock_fail_mode: gix_lock::acquire::Fail::Immediately,
+        }
+    }
+}
+
+impl file::Store {
+    /// Write loose references into the `packed-refs` file, configured by `options`, and return the amount of references
+    /// that were packed.
+    ///
+    /// `objects` are used to peel annotated tags, so their peeled object is recorded alongside them.
+    /// Like `git pack-refs`, symbolic references and worktree-private references are never packed, and neither are pseudo-refs
+    /// like `HEAD` as they aren't part of the loose reference iteration. Loose references that can't be parsed are skipped
+    /// as well, just like `git` ignores broken references.
+    ///
+    /// References that already exist in `packed-refs` are updated, and `packed-refs` is written atomically.
+    pub fn pack_refs<'a>(&self, objects: impl gix_object::Find + 'a, options: Options) -> Result<usize, Error> {
+        let mut edits = Vec::new();
+        for reference in self.loose_iter()? {
+            let reference = match reference {
+                Ok(reference) => reference,
+                Err(file::iter::loose_then_packed::Error::ReferenceCreation { .. }) => continue,
+                Err(err) => return Err(err.into()),
+            };
+            let is_eligible = reference.kind() == crate::Kind::Object
+                && !reference.name.category().is_some_and(|c| c.is_worktree_private())
+                && options
+                    .prefix
+                    .as_ref()
+                    .is_none_or(|prefix| reference.name.as_bstr().starts_with(prefix));
+            if !is_eligible {
+                continue;
+            }
+            edits.push(RefEdit {
+                change: Change::Update {
+                    log: LogChange::default(),
+                    expected: PreviousValue::MustExistAndMatch(reference.target.clone()),
+                    new: reference.target,
+                },
+                name: reference.name,
+                deref: false,
+            });
+        }
+        if edits.is_empty() {
+            return Ok(0);
+        }
+
+        let objects: Box<dyn gix_object::Find + 'a> = Box::new(objects);
+        let num_edits = edits.len();
+        self.transaction()
+            .packed_refs(if options.prune {
+                PackedRefs::DeletionsAndNonSymbolicUpdatesRemoveLooseSourceReference(objects)
+            } else {
+                PackedRefs::DeletionsAndNonSymbolicUpdates(objects)
+            })
+            .prepare(edits, options.lock_fail_mode, options.lock_fail_mode)?
+            .commit(None)?;
+        Ok(num_edits)
+    }
+}
+
+mod error {
+    use crate::store_impl::file;
+
+    /// The error returned by [`file::Store::pack_refs()`].
+    #[derive(Debug, thiserror::Error)]
+    #[allow(missing_docs)]
+    pub enum Error {
+        #[error("The loose references could not be traversed")]
+        Traversal(#[from] std::io::Error),
+        #[error("A loose reference could not be read")]
+        Iteration(#[from] file::iter::loose_then_packed::Error),
+        #[error("The transaction to write the packed-refs file could not be prepared")]
+        Prepare(#[from] file::transaction::prepare::Error),
+        #[error("The transaction to write the packed-refs file could not be committed")]
+        Commit(#[from] file::transaction::commit::Error),
+    }
+}
+pub use error::Error;
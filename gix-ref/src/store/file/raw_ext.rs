@@ -1,6 +1,7 @@
 use std::collections::BTreeSet;
 
 use gix_hash::ObjectId;
+use gix_object::bstr::BStr;
 
 use crate::{
     Target, packed, peel,
@@ -156,31 +157,8 @@ impl ReferenceExt for Reference {
                 Ok(peeled)
             }
             None => {
-                let mut oid = self.follow_to_object_packed(store, packed)?;
-                let mut buf = Vec::new();
-                let peeled_id = loop {
-                    let gix_object::Data {
-                        kind,
-                        data,
-                        object_hash: hash_kind,
-                    } = objects
-                        .try_find(&oid, &mut buf)?
-                        .ok_or_else(|| peel::to_id::Error::NotFound {
-                            oid,
-                            name: self.name.0.clone(),
-                        })?;
-                    match kind {
-                        gix_object::Kind::Tag => {
-                            oid = gix_object::TagRefIter::from_bytes(data, hash_kind)
-                                .target_id()
-                                .map_err(|_err| peel::to_id::Error::NotFound {
-                                    oid,
-                                    name: self.name.0.clone(),
-                                })?;
-                        }
-                        _ => break oid,
-                    }
-                };
+                let oid = self.follow_to_object_packed(store, packed)?;
+                let peeled_id = peel_tags(oid, self.name.as_bstr(), objects, &mut Vec::new())?;
                 self.peeled = Some(peeled_id);
                 self.target = Target::Object(peeled_id);
                 Ok(peeled_id)
@@ -256,3 +234,67 @@ impl ReferenceExt for Reference {
         }
     }
 }
+
+/// Like [`peel_tags()`], but look up the kind of each object in the chain with the header of `objects` first,
+/// so only annotated tags are decoded.
+pub(crate) fn peel_tags_by_header(
+    mut oid: ObjectId,
+    name: &BStr,
+    objects: &dyn gix_object::FindObjectOrHeader,
+    buf: &mut Vec<u8>,
+) -> Result<ObjectId, peel::to_id::Error> {
+    loop {
+        let header = objects.try_header(&oid)?.ok_or_else(|| peel::to_id::Error::NotFound {
+            oid,
+            name: name.to_owned(),
+        })?;
+        if header.kind != gix_object::Kind::Tag {
+            break Ok(oid);
+        }
+        let tag = objects
+            .try_find(&oid, buf)?
+            .ok_or_else(|| peel::to_id::Error::NotFound {
+                oid,
+                name: name.to_owned(),
+            })?;
+        oid = gix_object::TagRefIter::from_bytes(tag.data, tag.object_hash)
+            .target_id()
+            .map_err(|_err| peel::to_id::Error::NotFound {
+                oid,
+                name: name.to_owned(),
+            })?;
+    }
+}
+
+/// Follow the chain of annotated tags starting at `oid` to the first non-tag object and return its id,
+/// using `objects` for lookup with `buf` as backing storage, and `name` for error messages.
+pub(crate) fn peel_tags(
+    mut oid: ObjectId,
+    name: &BStr,
+    objects: &dyn gix_object::Find,
+    buf: &mut Vec<u8>,
+) -> Result<ObjectId, peel::to_id::Error> {
+    loop {
+        let gix_object::Data {
+            kind,
+            data,
+            object_hash: hash_kind,
+        } = objects
+            .try_find(&oid, buf)?
+            .ok_or_else(|| peel::to_id::Error::NotFound {
+                oid,
+                name: name.to_owned(),
+            })?;
+        match kind {
+            gix_object::Kind::Tag => {
+                oid = gix_object::TagRefIter::from_bytes(data, hash_kind)
+                    .target_id()
+                    .map_err(|_err| peel::to_id::Error::NotFound {
+                        oid,
+                        name: name.to_owned(),
+                    })?;
+            }
+            _ => break Ok(oid),
+        }
+    }
+}
@@ -25,11 +25,19 @@ impl Transaction<'_, '_> {
     ///   along with empty parent directories
     ///
     /// Note that transactions will be prepared automatically as needed.
+    ///
+    /// # Panics
+    ///
+    /// If the transaction was [prepared for preview](Transaction::prepare_for_preview()) only.
     pub fn commit<'a>(self, committer: impl Into<Option<gix_actor::SignatureRef<'a>>>) -> Result<Vec<RefEdit>, Error> {
         self.commit_inner(committer.into())
     }
 
     fn commit_inner(self, committer: Option<gix_actor::SignatureRef<'_>>) -> Result<Vec<RefEdit>, Error> {
+        assert!(
+            !self.read_only,
+            "BUG: transactions prepared for preview can't be committed"
+        );
         let mut updates = self.updates.expect("BUG: must call prepare before commit");
         let delete_loose_refs = matches!(
             self.packed_refs,
@@ -41,49 +49,21 @@ impl Transaction<'_, '_> {
             assert!(!change.update.deref, "Deref mode is turned into splits and turned off");
             match &change.update.change {
                 // reflog first, then reference
-                Change::Update { log, new, expected } => {
+                Change::Update { log, new, .. } => {
                     let lock = change.lock.take();
-                    let (update_ref, update_reflog) = match log.mode {
-                        RefLog::Only => (false, true),
-                        RefLog::AndReference => (true, true),
+                    let update_ref = match log.mode {
+                        RefLog::Only => false,
+                        RefLog::AndReference => true,
                     };
-                    if update_reflog {
-                        let log_update = match new {
-                            Target::Symbolic(_) => {
-                                // Special HACK: no reflog for symref changes as there is no OID involved which the reflog needs.
-                                // Unless, the ref is new and we can obtain a peeled id
-                                // identified by the expectation of what could be there, as is the case when cloning.
-                                match expected {
-                                    PreviousValue::ExistingMustMatch(Target::Object(oid)) => {
-                                        Some((Some(gix_hash::ObjectId::null(oid.kind())), oid))
-                                    }
-                                    _ => None,
-                                }
-                            }
-                            Target::Object(new_oid) => {
-                                let previous = match expected {
-                                    // Here, this means that the ref already existed, and that it will receive (even transitively)
-                                    // the given value
-                                    PreviousValue::MustExistAndMatch(Target::Object(oid)) => Some(oid.to_owned()),
-                                    _ => None,
-                                }
-                                .or(change.leaf_referent_previous_oid);
-                                Some((previous, new_oid))
-                            }
-                        };
-                        if let Some((previous, new_oid)) = log_update {
-                            let do_update = previous.as_ref() != Some(new_oid);
-                            if do_update {
-                                self.store.reflog_create_or_append(
-                                    change.update.name.as_ref(),
-                                    previous,
-                                    new_oid,
-                                    committer,
-                                    log.message.as_ref(),
-                                    log.force_create_reflog,
-                                )?;
-                            }
-                        }
+                    if let Some((previous, new_oid)) = change.reflog_update() {
+                        self.store.reflog_create_or_append(
+                            change.update.name.as_ref(),
+                            previous,
+                            &new_oid,
+                            committer,
+                            log.message.as_ref(),
+                            log.force_create_reflog,
+                        )?;
                     }
                     // Don't do anything else while keeping the lock after potentially updating the reflog.
                     // We delay deletion of the reference and dropping the lock to after the packed-refs were
@@ -200,5 +180,3 @@ mod error {
     }
 }
 pub use error::Error;
-
-use crate::transaction::PreviousValue;
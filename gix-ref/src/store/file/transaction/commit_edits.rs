@@ -0,0 +1,85 @@
+use gix_object::bstr::BStr;
+
+use crate::{store_impl::file, transaction::RefEdit};
+
+use super::prepare;
+
+/// An edit that was rejected by [`file::Store::commit_edits()`] as the reference it should change wasn't in the expected state.
+#[derive(Debug)]
+pub struct Rejected {
+    /// The edit that was not applied.
+    pub edit: RefEdit,
+    /// The reason for the rejection, providing details about the expected and the actual state of the reference.
+    pub reason: prepare::Error,
+}
+
+impl file::Store {
+    /// Apply all `edits` in a single transaction, using `committer` for reflog entries, and return those that were rejected
+    /// because the reference they should change wasn't in the expected state.
+    ///
+    /// If `reject_partial` is `true`, nothing will be changed if any edit is rejected, and all rejected edits are returned.
+    /// Otherwise, all edits that are not rejected are still committed, leaving it to the caller to possibly retry the rejected ones.
+    ///
+    /// Locks are acquired without waiting, so edits to references that are currently locked will fail the whole operation.
+    /// Note that conflicts of symbolic references which are dereferenced can't be attributed to an edit, and are returned as error.
+    pub fn commit_edits(
+        &self,
+        mut edits: Vec<RefEdit>,
+        committer: Option<gix_actor::SignatureRef<'_>>,
+        reject_partial: bool,
+    ) -> Result<Vec<Rejected>, Error> {
+        let mut rejected = Vec::new();
+        while !edits.is_empty() {
+            match self.transaction().prepare(
+                edits.iter().cloned(),
+                gix_lock::acquire::Fail::Immediately,
+                gix_lock::acquire::Fail::Immediately,
+            ) {
+                Ok(transaction) => {
+                    if !reject_partial || rejected.is_empty() {
+                        transaction.commit(committer)?;
+                    }
+                    break;
+                }
+                Err(err) => {
+                    let Some(edit_index) = conflicting_name(&err)
+                        .and_then(|full_name| edits.iter().position(|edit| edit.name.as_bstr() == full_name))
+                    else {
+                        return Err(err.into());
+                    };
+                    rejected.push(Rejected {
+                        edit: edits.remove(edit_index),
+                        reason: err,
+                    });
+                }
+            }
+        }
+        Ok(rejected)
+    }
+}
+
+/// Return the name of the reference that wasn't in the expected state, if `err` is about such a conflict.
+fn conflicting_name(err: &prepare::Error) -> Option<&BStr> {
+    match err {
+        prepare::Error::DeleteReferenceMustExist { full_name }
+        | prepare::Error::MustNotExist { full_name, .. }
+        | prepare::Error::MustExist { full_name, .. }
+        | prepare::Error::ReferenceOutOfDate { full_name, .. } => Some(full_name.as_ref()),
+        _ => None,
+    }
+}
+
+mod error {
+    use crate::store_impl::file::transaction::{commit, prepare};
+
+    /// The error returned by [`file::Store::commit_edits()`](crate::file::Store::commit_edits()).
+    #[derive(Debug, thiserror::Error)]
+    #[allow(missing_docs)]
+    pub enum Error {
+        #[error("The transaction could not be prepared")]
+        Prepare(#[from] prepare::Error),
+        #[error("The transaction could not be committed")]
+        Commit(#[from] commit::Error),
+    }
+}
+pub use error::Error;
@@ -4,8 +4,9 @@ use gix_hash::ObjectId;
 use gix_object::bstr::BString;
 
 use crate::{
+    Target,
     store_impl::{file, file::Transaction},
-    transaction::RefEdit,
+    transaction::{Change, PreviousValue, RefEdit},
 };
 
 /// How to handle packed refs during a transaction
@@ -45,6 +46,46 @@ impl Edit {
     fn name(&self) -> BString {
         self.update.name.0.clone()
     }
+
+    /// Return the previous and the new object id to write into the reflog, or `None` if the reflog isn't touched by this edit.
+    fn reflog_update(&self) -> Option<(Option<ObjectId>, ObjectId)> {
+        let Change::Update { new, expected, .. } = &self.update.change else {
+            return None;
+        };
+        let (previous, new_oid) = match new {
+            Target::Symbolic(_) => {
+                // Special HACK: no reflog for symref changes as there is no OID involved which the reflog needs.
+                // Unless, the ref is new and we can obtain a peeled id
+                // identified by the expectation of what could be there, as is the case when cloning.
+                match expected {
+                    PreviousValue::ExistingMustMatch(Target::Object(oid)) => (Some(ObjectId::null(oid.kind())), *oid),
+                    _ => return None,
+                }
+            }
+            Target::Object(new_oid) => {
+                let previous = match expected {
+                    // Here, this means that the ref already existed, and that it will receive (even transitively)
+                    // the given value
+                    PreviousValue::MustExistAndMatch(Target::Object(oid)) => Some(oid.to_owned()),
+                    _ => None,
+                }
+                .or(self.leaf_referent_previous_oid);
+                (previous, *new_oid)
+            }
+        };
+        (previous != Some(new_oid)).then_some((previous, new_oid))
+    }
+}
+
+/// What committing a [prepared](Transaction::prepare()) transaction would do to a single reference,
+/// as returned by [`Transaction::preview()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Preview<'a> {
+    /// The edit as adjusted during preparation, which knows the previous value of the reference if it existed.
+    pub edit: &'a RefEdit,
+    /// The previous and the new object id of the line that would be appended to the reflog,
+    /// or `None` if no reflog line would be written.
+    pub reflog_line: Option<(Option<ObjectId>, ObjectId)>,
 }
 
 impl std::borrow::Borrow<RefEdit> for Edit {
@@ -73,6 +114,7 @@ impl file::Store {
             packed_transaction: None,
             updates: None,
             packed_refs: PackedRefs::default(),
+            read_only: false,
         }
     }
 }
@@ -85,6 +127,35 @@ impl<'p> Transaction<'_, 'p> {
     }
 }
 
+impl Transaction<'_, '_> {
+    /// Return what committing this [prepared](Transaction::prepare()) transaction would do to each reference, without
+    /// making any change to the repository.
+    ///
+    /// This makes it possible to implement a dry-run by dropping or [rolling back](Transaction::rollback()) the transaction
+    /// afterwards, or to commit it after inspection.
+    /// Note that a preparation was already needed to obtain the previous values, so the respective locks are still held.
+    /// Use [`prepare_for_preview()`](Transaction::prepare_for_preview()) instead to obtain previous values without locking.
+    ///
+    /// Return `None` if this transaction wasn't [prepared](Transaction::prepare()) yet.
+    pub fn preview(&self) -> Option<Vec<Preview<'_>>> {
+        let updates = self.updates.as_ref()?;
+        Some(
+            updates
+                .iter()
+                .map(|change| Preview {
+                    edit: &change.update,
+                    reflog_line: change.reflog_update().filter(|_| match &change.update.change {
+                        Change::Update { log, .. } => self
+                            .store
+                            .reflog_would_be_appended(change.update.name.as_ref(), log.force_create_reflog),
+                        Change::Delete { .. } => false,
+                    }),
+                })
+                .collect(),
+        )
+    }
+}
+
 impl std::fmt::Debug for Transaction<'_, '_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Transaction")
@@ -99,3 +170,6 @@ pub mod prepare;
 
 ///
 pub mod commit;
+
+///
+pub mod commit_edits;
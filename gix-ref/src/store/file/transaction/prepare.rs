@@ -61,9 +61,11 @@ impl Transaction<'_, '_> {
         }
     }
 
+    /// Acquire the lock for `change` unless `read_only` is set, and adjust it to know the current value of the reference.
     fn lock_ref_and_apply_change(
         store: &file::Store,
         lock_fail_mode: gix_lock::acquire::Fail,
+        read_only: bool,
         packed: Option<&packed::Buffer>,
         change: &mut Edit,
         direct_to_packed_refs: bool,
@@ -83,12 +85,18 @@ impl Transaction<'_, '_> {
         let lock = match &mut change.update.change {
             Change::Delete { expected, .. } => {
                 let (base, relative_path) = store.reference_path_with_base(change.update.name.as_ref());
-                let lock = gix_lock::Marker::acquire_to_hold_resource(
-                    base.join(relative_path.as_ref()),
-                    lock_fail_mode,
-                    Some(base.clone().into_owned()),
-                )
-                .map_err(|err| Self::lock_acquire_error(err, "borrowcheck won't allow change.name()"))?;
+                let lock = if read_only {
+                    None
+                } else {
+                    Some(
+                        gix_lock::Marker::acquire_to_hold_resource(
+                            base.join(relative_path.as_ref()),
+                            lock_fail_mode,
+                            Some(base.clone().into_owned()),
+                        )
+                        .map_err(|err| Self::lock_acquire_error(err, "borrowcheck won't allow change.name()"))?,
+                    )
+                };
 
                 let existing_ref = Self::read_existing_ref(store, change.update.name.as_ref(), packed)?;
 
@@ -124,7 +132,7 @@ impl Transaction<'_, '_> {
                     *expected = PreviousValue::MustExistAndMatch(existing.target);
                 }
 
-                Some(lock)
+                lock
             }
             Change::Update { expected, new, .. } => {
                 let (base, relative_path) = store.reference_path_with_base(change.update.name.as_ref());
@@ -141,7 +149,7 @@ impl Transaction<'_, '_> {
                         )
                     })
                 };
-                let mut lock = obtain_lock()?;
+                let lock = if read_only { None } else { Some(obtain_lock()?) };
 
                 let existing_ref = Self::read_existing_ref(store, change.update.name.as_ref(), packed)?;
 
@@ -205,16 +213,16 @@ impl Transaction<'_, '_> {
                 };
 
                 let keep_lock_for_loose_source_delete = direct_to_packed_refs && matches!(new, Target::Object(_));
-                if (is_effective && !direct_to_packed_refs) || is_symbolic {
-                    lock.with_mut(|file| match new {
-                        Target::Object(oid) => writeln!(file, "{oid}"),
-                        Target::Symbolic(name) => writeln!(file, "ref: {}", name.0),
-                    })?;
-                    Some(lock.close()?)
-                } else if keep_lock_for_loose_source_delete {
-                    Some(lock.close()?)
-                } else {
-                    None
+                match lock {
+                    Some(mut lock) if (is_effective && !direct_to_packed_refs) || is_symbolic => {
+                        lock.with_mut(|file| match new {
+                            Target::Object(oid) => writeln!(file, "{oid}"),
+                            Target::Symbolic(name) => writeln!(file, "ref: {}", name.0),
+                        })?;
+                        Some(lock.close()?)
+                    }
+                    Some(lock) if keep_lock_for_loose_source_delete => Some(lock.close()?),
+                    _ => None,
                 }
             }
         };
@@ -239,6 +247,23 @@ impl Transaction<'_, '_> {
             &mut edits.into_iter(),
             ref_files_lock_fail_mode,
             packed_refs_lock_fail_mode,
+            false,
+        )
+    }
+
+    /// Like [`prepare(…)`](Transaction::prepare()), but only read the current values of the references affected by `edits`
+    /// without acquiring any lock, so the result can be [previewed](Transaction::preview()) even while other
+    /// processes hold locks.
+    ///
+    /// As nothing is locked, the previewed values may be outdated by the time a transaction is actually performed.
+    /// The returned transaction can't be [committed](Transaction::commit()), but only be previewed and dropped
+    /// or [rolled back](Transaction::rollback()).
+    pub fn prepare_for_preview(self, edits: impl IntoIterator<Item = RefEdit>) -> Result<Self, Error> {
+        self.prepare_inner(
+            &mut edits.into_iter(),
+            gix_lock::acquire::Fail::Immediately,
+            gix_lock::acquire::Fail::Immediately,
+            true,
         )
     }
 
@@ -247,8 +272,10 @@ impl Transaction<'_, '_> {
         edits: &mut dyn Iterator<Item = RefEdit>,
         ref_files_lock_fail_mode: gix_lock::acquire::Fail,
         packed_refs_lock_fail_mode: gix_lock::acquire::Fail,
+        read_only: bool,
     ) -> Result<Self, Error> {
         assert!(self.updates.is_none(), "BUG: Must not call prepare(…) multiple times");
+        self.read_only = read_only;
         let store = self.store;
         let mut updates: Vec<_> = edits
             .map(|update| Edit {
@@ -281,9 +308,16 @@ impl Transaction<'_, '_> {
             | PackedRefs::DeletionsAndNonSymbolicUpdatesRemoveLooseSourceReference(_) => Some(0_usize),
             PackedRefs::DeletionsOnly => None,
         };
-        if maybe_updates_for_packed_refs.is_some()
-            || self.store.packed_refs_path().is_file()
-            || self.store.packed_refs_lock_path().is_file()
+        // Without locks, packed refs are only needed for looking up current values.
+        let packed_snapshot = if read_only {
+            self.store.assure_packed_refs_uptodate()?
+        } else {
+            None
+        };
+        if !read_only
+            && (maybe_updates_for_packed_refs.is_some()
+                || self.store.packed_refs_path().is_file()
+                || self.store.packed_refs_lock_path().is_file())
         {
             let mut edits_for_packed_transaction = Vec::<RefEdit>::new();
             let mut needs_packed_refs_lookups = false;
@@ -382,7 +416,11 @@ impl Transaction<'_, '_> {
             if let Err(err) = Self::lock_ref_and_apply_change(
                 self.store,
                 ref_files_lock_fail_mode,
-                self.packed_transaction.as_ref().and_then(packed::Transaction::buffer),
+                read_only,
+                match &packed_snapshot {
+                    Some(snapshot) => Some(&***snapshot),
+                    None => self.packed_transaction.as_ref().and_then(packed::Transaction::buffer),
+                },
                 change,
                 matches!(
                     self.packed_refs,
@@ -0,0 +1,11 @@
+#!/usr/bin/env bash
+set -eu -o pipefail
+
+git init -q
+git checkout -q -b main
+
+GIT_COMMITTER_DATE="1000000000 +0000" git commit -q --allow-empty -m c1
+GIT_COMMITTER_DATE="1000000100 +0000" git commit -q --allow-empty -m c2
+GIT_COMMITTER_DATE="1000000200 +0000" git commit -q --allow-empty -m unreachable
+GIT_COMMITTER_DATE="1000000300 +0000" git reset -q --hard HEAD~1
+GIT_COMMITTER_DATE="1000000400 +0000" git commit -q --allow-empty -m c3
@@ -22,6 +22,91 @@ mod existing {
         Ok(())
     }
 
+    #[test]
+    fn many_at_once() -> crate::Result {
+        let store = store_at("make_packed_ref_repository_for_overlay.sh")?;
+        let names: Vec<&gix_ref::PartialNameRef> = vec![
+            "newer-as-loose".try_into()?,
+            "does-not-exist".try_into()?,
+            "main".try_into()?,
+            "origin".try_into()?,
+            "refs/prefix/feature-suffix".try_into()?,
+        ];
+        let refs = store.try_find_many(&names)?;
+        assert_eq!(refs.len(), names.len(), "there is one result per input name");
+        for (name, actual) in names.iter().zip(&refs) {
+            assert_eq!(
+                actual.as_ref().map(|r| r.name.as_bstr()),
+                store.try_find(*name)?.as_ref().map(|r| r.name.as_bstr()),
+                "the output order matches the input order, and each lookup yields the same as a single lookup"
+            );
+        }
+        assert_eq!(
+            refs.iter()
+                .map(|r| r.as_ref().map(|r| r.name.as_bstr().to_string()))
+                .collect::<Vec<_>>(),
+            [
+                Some("refs/heads/newer-as-loose"),
+                None,
+                Some("refs/heads/main"),
+                Some("refs/remotes/origin/HEAD"),
+                Some("refs/prefix/feature-suffix"),
+            ]
+            .map(|name| name.map(ToOwned::to_owned))
+        );
+        assert_eq!(
+            refs[0].as_ref().map(|r| r.target.clone().into_id()),
+            Some(hex_to_id("9902e3c3e8f0c569b4ab295ddf473e6de763e1e7")),
+            "loose refs are preferred over their packed counterpart"
+        );
+        assert!(store.try_find_many(&[])?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn resolve() -> crate::Result {
+        use gix_ref::peel::to_object::Error;
+
+        let store = crate::file::store()?;
+        let c1 = hex_to_id("134385f6d781b7e97062102c6a483440bfda2a03");
+        for (name, expected) in [
+            ("main", "refs/heads/main"),
+            ("origin", "refs/remotes/origin/main"),
+            ("multi-link", "refs/remotes/origin/multi-link-target3"),
+        ] {
+            let r = store.resolve(name.try_into()?)?;
+            assert_eq!(r.name.as_bstr(), expected, "{name}: all symbolic refs are followed");
+            assert_eq!(r.target.into_id(), c1);
+        }
+
+        assert!(matches!(
+            store.resolve("loop-a".try_into()?).unwrap_err(),
+            Error::Cycle { .. }
+        ));
+        assert!(matches!(
+            store.resolve("does-not-exist".try_into()?).unwrap_err(),
+            Error::Follow(gix_ref::file::find::existing::Error::NotFound { .. })
+        ));
+
+        let (_tmp, store) = crate::file::store_writable("make_ref_repository.sh")?;
+        for hop in 1..6 {
+            std::fs::write(
+                store.git_dir().join("refs").join(format!("hop-{hop}")),
+                format!("ref: refs/hop-{}\n", hop + 1),
+            )?;
+        }
+        std::fs::write(store.git_dir().join("refs").join("hop-6"), "ref: refs/heads/main\n")?;
+        assert!(
+            matches!(
+                store.resolve("hop-1".try_into()?).unwrap_err(),
+                Error::DepthLimitExceeded { max_depth: 5 }
+            ),
+            "long chains are distinguished from cycles"
+        );
+        assert_eq!(store.resolve("hop-3".try_into()?)?.name.as_bstr(), "refs/heads/main");
+        Ok(())
+    }
+
     mod convert {
         use gix_ref::{PartialName, PartialNameRef};
 
@@ -440,6 +440,25 @@ fn overlay_iter() -> crate::Result {
     Ok(())
 }
 
+#[test]
+fn overlay_iter_provides_peeled_ids_of_packed_refs() -> crate::Result {
+    let store = store_at("make_packed_ref_repository_for_overlay.sh")?;
+    let peeled = store
+        .iter()?
+        .prefixed(b"refs/tags/".try_into().unwrap())?
+        .map(|r| r.map(|r| (r.name.as_bstr().to_owned(), r.peeled)))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(
+        peeled,
+        vec![(
+            b"refs/tags/tag-object".as_bstr().to_owned(),
+            Some(hex_to_id("134385f6d781b7e97062102c6a483440bfda2a03"))
+        )],
+        "the peeled value recorded in packed-refs is passed on, without the need for an object lookup"
+    );
+    Ok(())
+}
+
 #[test]
 fn overlay_iter_reproduce_1850() -> crate::Result {
     let store = store_at("make_repo_for_1850_repro.sh")?;
@@ -667,3 +686,138 @@ fn overlay_partial_prefix_iter_when_prefix_is_dir() -> crate::Result {
 
     Ok(())
 }
+
+#[test]
+fn overlay_globbed_iter() -> crate::Result {
+    use gix_ref::Target::*;
+
+    let store = store_at("make_packed_ref_repository_for_overlay.sh")?;
+    let globbed = |pattern: &str| -> crate::Result<Vec<_>> {
+        Ok(store
+            .iter()?
+            .globbed(pattern.into())?
+            .map(|r| r.map(|r| (r.name.as_bstr().to_string(), r.target)))
+            .collect::<Result<Vec<_>, _>>()?)
+    };
+    let c1 = hex_to_id("134385f6d781b7e97062102c6a483440bfda2a03");
+    let c2 = hex_to_id("9902e3c3e8f0c569b4ab295ddf473e6de763e1e7");
+
+    assert_eq!(
+        globbed("refs/heads/*")?,
+        vec![
+            ("refs/heads/A".into(), Object(c1)),
+            ("refs/heads/main".into(), Object(c1)),
+            ("refs/heads/newer-as-loose".into(), Object(c2)),
+        ],
+        "packed refs are overlaid by loose ones, just like with prefixed iteration"
+    );
+    assert_eq!(
+        globbed("refs/heads/[mn]*")?,
+        vec![
+            ("refs/heads/main".into(), Object(c1)),
+            ("refs/heads/newer-as-loose".into(), Object(c2)),
+        ]
+    );
+    assert_eq!(
+        globbed("refs/prefix/*")?,
+        vec![("refs/prefix/feature-suffix".into(), Object(c1))],
+        "a single star doesn't match across path components"
+    );
+    assert_eq!(
+        globbed("refs/prefix/**")?,
+        vec![
+            ("refs/prefix/feature-suffix".into(), Object(c1)),
+            ("refs/prefix/feature/sub/dir/algo".into(), Object(c1)),
+        ],
+        "a double star matches across path components"
+    );
+    assert_eq!(
+        globbed("refs/**/main")?,
+        vec![
+            ("refs/heads/main".into(), Object(c1)),
+            ("refs/remotes/origin/main".into(), Object(c1)),
+        ]
+    );
+    assert_eq!(globbed("refs/heads/does-not-exist-*")?, vec![]);
+    Ok(())
+}
+
+#[test]
+fn overlay_peeled_iter() -> crate::Result {
+    let tag = hex_to_id("4c3f4cce493d7beb45012e478021b5f65295e5a3");
+    let commit = hex_to_id("134385f6d781b7e97062102c6a483440bfda2a03");
+    for fixture in ["make_ref_repository.sh", "make_packed_ref_repository.sh"] {
+        let store = store_at(fixture)?;
+        let odb = crate::file::odb_at(store.git_dir().join("objects"))?;
+        let refs = store
+            .iter()?
+            .all()?
+            .peeled(&odb)
+            .filter_map(Result::ok)
+            .map(|r| (r.name.as_bstr().to_string(), r))
+            .collect::<std::collections::BTreeMap<_, _>>();
+
+        let dt1 = &refs["refs/tags/dt1"];
+        assert_eq!(
+            dt1.target.try_id(),
+            Some(tag.as_ref()),
+            "{fixture}: the target of annotated tags is the tag object"
+        );
+        assert_eq!(dt1.peeled, Some(commit), "{fixture}: …and it's peeled to the commit");
+
+        let main = &refs["refs/heads/main"];
+        assert_eq!(main.target.try_id(), Some(commit.as_ref()));
+        assert_eq!(
+            main.peeled,
+            Some(commit),
+            "{fixture}: commits are their own peeled value"
+        );
+
+        assert_eq!(
+            refs["refs/remotes/origin/HEAD"].peeled, None,
+            "{fixture}: symbolic refs aren't peeled"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn overlay_peeled_iter_only_decodes_tags() -> crate::Result {
+    /// An object database which refuses to provide the data of objects that aren't tags.
+    struct TagsOnly<T>(T);
+
+    impl<T: gix_object::Find> gix_object::Find for TagsOnly<T> {
+        fn try_find<'a>(
+            &self,
+            id: &gix_hash::oid,
+            buffer: &'a mut Vec<u8>,
+        ) -> Result<Option<gix_object::Data<'a>>, gix_object::find::Error> {
+            let data = self.0.try_find(id, buffer)?;
+            assert!(
+                data.as_ref().is_none_or(|data| data.kind == gix_object::Kind::Tag),
+                "only tags are decoded, {id} isn't one"
+            );
+            Ok(data)
+        }
+    }
+
+    impl<T: gix_object::FindHeader> gix_object::FindHeader for TagsOnly<T> {
+        fn try_header(&self, id: &gix_hash::oid) -> Result<Option<gix_object::Header>, gix_object::find::Error> {
+            self.0.try_header(id)
+        }
+    }
+
+    let commit = hex_to_id("134385f6d781b7e97062102c6a483440bfda2a03");
+    let store = store_at("make_ref_repository.sh")?;
+    let odb = TagsOnly(crate::file::odb_at(store.git_dir().join("objects"))?);
+    let refs = store
+        .iter()?
+        .all()?
+        .peeled(&odb)
+        .filter_map(Result::ok)
+        .map(|r| (r.name.as_bstr().to_string(), r.peeled))
+        .collect::<std::collections::BTreeMap<_, _>>();
+    assert_eq!(refs["refs/tags/dt1"], Some(commit));
+    assert_eq!(refs["refs/heads/main"], Some(commit));
+    Ok(())
+}
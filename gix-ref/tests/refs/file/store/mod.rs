@@ -15,6 +15,7 @@ use crate::file::{
 mod access;
 mod find;
 mod iter;
+mod pack_refs;
 mod reflog;
 
 #[test]
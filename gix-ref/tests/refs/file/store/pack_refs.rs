@@ -0,0 +1,72 @@
+use gix_object::bstr::BString;
+use gix_ref::file::pack_refs::Options;
+
+use crate::file::{store_with_packed_refs, store_writable};
+
+#[test]
+fn all_with_pruning_matches_git() -> crate::Result {
+    let (_keep, store) = store_writable("make_ref_repository.sh")?;
+    assert!(
+        store.open_packed_buffer()?.is_none(),
+        "there should be no packed refs to start out with"
+    );
+    let odb = crate::file::odb_at(store.git_dir().join("objects"))?;
+    let num_packed = store.pack_refs(odb, Options::default())?;
+    assert_eq!(num_packed, 11, "all loose refs pointing to an object are packed");
+
+    assert!(
+        store
+            .loose_iter()?
+            .filter_map(Result::ok)
+            .all(|r| r.kind() == gix_ref::Kind::Symbolic),
+        "only symbolic refs are left"
+    );
+    assert!(store.try_find_loose("HEAD")?.is_some(), "HEAD is never packed");
+
+    let expected_pack_data: BString = std::fs::read(store_with_packed_refs()?.packed_refs_path())?.into();
+    let actual_packed_data: BString = std::fs::read(store.packed_refs_path())?.into();
+    assert_eq!(
+        actual_packed_data, expected_pack_data,
+        "both gitoxide and git must agree on the packed refs file perfectly, including peeled tags"
+    );
+
+    let odb = crate::file::odb_at(store.git_dir().join("objects"))?;
+    assert_eq!(
+        store.pack_refs(odb, Options::default())?,
+        0,
+        "there is nothing left to pack"
+    );
+    Ok(())
+}
+
+#[test]
+fn prefixed_without_pruning() -> crate::Result {
+    let (_keep, store) = store_writable("make_ref_repository.sh")?;
+    let num_loose_refs = store.loose_iter()?.count();
+    let odb = crate::file::odb_at(store.git_dir().join("objects"))?;
+    let num_packed = store.pack_refs(
+        odb,
+        Options {
+            prefix: Some("refs/tags/".into()),
+            prune: false,
+            ..Default::default()
+        },
+    )?;
+
+    let packed = store.open_packed_buffer()?.expect("packed-refs was written");
+    let packed_names = packed
+        .iter()?
+        .map(|r| r.map(|r| r.name.as_bstr().to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(packed_names.len(), num_packed);
+    assert!(
+        packed_names.iter().all(|name| name.starts_with("refs/tags/")),
+        "only references matching the prefix are packed: {packed_names:?}"
+    );
+    assert_eq!(
+        store.loose_iter()?.count(),
+        num_loose_refs,
+        "without pruning, all loose refs remain"
+    );
+    Ok(())
+}
@@ -68,3 +68,99 @@ mod iter_rev {
         Ok(())
     }
 }
+
+mod expire {
+    use gix_date::Time;
+    use gix_ref::file::log::expire::Statistics;
+
+    fn messages(store: &gix_ref::file::Store) -> crate::Result<Vec<String>> {
+        let mut buf = Vec::new();
+        Ok(store
+            .reflog_iter("refs/heads/main", &mut buf)?
+            .expect("exists")
+            .map(|line| line.map(|line| line.message.to_string()))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn expire(store: &gix_ref::file::Store, expire: i64, expire_unreachable: Option<i64>) -> crate::Result<Statistics> {
+        let odb = crate::file::odb_at(store.git_dir().join("objects"))?;
+        Ok(store.reflog_expire(
+            "refs/heads/main".try_into()?,
+            Time::new(expire, 0),
+            expire_unreachable.map(|seconds| Time::new(seconds, 0)),
+            &odb,
+            gix_lock::acquire::Fail::Immediately,
+        )?)
+    }
+
+    #[test]
+    fn by_time() -> crate::Result {
+        let (_tmp, store) = crate::file::store_writable("make_repo_for_reflog_expire.sh")?;
+        assert_eq!(
+            expire(&store, 1000000000, None)?,
+            Statistics { kept: 5, dropped: 0 },
+            "nothing is older than the cutoff"
+        );
+        assert_eq!(messages(&store)?.len(), 5);
+
+        assert_eq!(expire(&store, 1000000150, None)?, Statistics { kept: 3, dropped: 2 });
+        assert_eq!(
+            messages(&store)?,
+            ["commit: unreachable", "reset: moving to HEAD~1", "commit: c3"],
+            "only entries newer than the cutoff remain, in order"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unreachable_by_time() -> crate::Result {
+        let (_tmp, store) = crate::file::store_writable("make_repo_for_reflog_expire.sh")?;
+        assert_eq!(expire(&store, 0, Some(1000000350))?, Statistics { kept: 4, dropped: 1 });
+        assert_eq!(
+            messages(&store)?,
+            [
+                "commit (initial): c1",
+                "commit: c2",
+                "reset: moving to HEAD~1",
+                "commit: c3"
+            ],
+            "only the entry for the commit that isn't reachable from the tip anymore is dropped"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unreachable_by_time_with_missing_commits() -> crate::Result {
+        let (_tmp, store) = crate::file::store_writable("make_repo_for_reflog_expire.sh")?;
+        let mut buf = Vec::new();
+        let hex = store
+            .reflog_iter("refs/heads/main", &mut buf)?
+            .expect("exists")
+            .next()
+            .expect("first entry")?
+            .new_oid
+            .to_string();
+        std::fs::remove_file(store.git_dir().join("objects").join(&hex[..2]).join(&hex[2..]))?;
+
+        assert_eq!(
+            expire(&store, 0, Some(1000000350))?,
+            Statistics { kept: 4, dropped: 1 },
+            "missing commits end the traversal like in shallow clones, instead of failing it"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn non_existing_reflog() -> crate::Result {
+        let (_tmp, store) = crate::file::store_writable("make_repo_for_reflog_expire.sh")?;
+        let stats = store.reflog_expire(
+            "refs/heads/does-not-exist".try_into()?,
+            Time::new(i64::MAX, 0),
+            None,
+            &gix_object::find::Never,
+            gix_lock::acquire::Fail::Immediately,
+        )?;
+        assert_eq!(stats, Statistics::default());
+        Ok(())
+    }
+}
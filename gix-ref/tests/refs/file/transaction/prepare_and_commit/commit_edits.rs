@@ -0,0 +1,101 @@
+use gix_date::parse::TimeBuf;
+use gix_ref::{
+    Target,
+    file::transaction::prepare,
+    transaction::{Change, LogChange, PreviousValue, RefEdit},
+};
+
+use crate::{
+    file::transaction::prepare_and_commit::{committer, create_at, empty_store},
+    hex_to_id,
+};
+
+fn update_expecting(name: &str, expected: PreviousValue) -> RefEdit {
+    RefEdit {
+        change: Change::Update {
+            log: LogChange::default(),
+            expected,
+            new: Target::Object(hex_to_id("134385f6d781b7e97062102c6a483440bfda2a03")),
+        },
+        name: name.try_into().expect("valid"),
+        deref: false,
+    }
+}
+
+fn conflicting_batch() -> Vec<RefEdit> {
+    vec![
+        create_at("refs/heads/c"),
+        update_expecting("refs/heads/a", PreviousValue::MustNotExist),
+        update_expecting(
+            "refs/heads/b",
+            PreviousValue::MustExistAndMatch(Target::Object(hex_to_id("9902e3c3e8f0c569b4ab295ddf473e6de763e1e7"))),
+        ),
+    ]
+}
+
+#[test]
+fn all_edits_are_applied_without_conflicts() -> crate::Result {
+    let (_keep, store) = empty_store()?;
+    let mut buf = TimeBuf::default();
+    let rejected = store.commit_edits(
+        vec![create_at("refs/heads/a"), create_at("refs/heads/b")],
+        Some(committer().to_ref(&mut buf)),
+        true,
+    )?;
+    assert!(rejected.is_empty());
+    assert!(store.try_find("a")?.is_some());
+    assert!(store.try_find("b")?.is_some());
+    Ok(())
+}
+
+#[test]
+fn conflicting_edits_are_returned_and_others_are_applied() -> crate::Result {
+    let (_keep, store) = empty_store()?;
+    let mut buf = TimeBuf::default();
+    store.commit_edits(
+        vec![create_at("refs/heads/a"), create_at("refs/heads/b")],
+        Some(committer().to_ref(&mut buf)),
+        false,
+    )?;
+
+    let rejected = store.commit_edits(conflicting_batch(), Some(committer().to_ref(&mut buf)), false)?;
+    assert_eq!(
+        rejected
+            .iter()
+            .map(|r| r.edit.name.as_bstr().to_string())
+            .collect::<Vec<_>>(),
+        ["refs/heads/a", "refs/heads/b"],
+        "edits whose expectation doesn't match are rejected"
+    );
+    assert!(matches!(rejected[0].reason, prepare::Error::MustNotExist { .. }));
+    assert!(matches!(rejected[1].reason, prepare::Error::ReferenceOutOfDate { .. }));
+    assert!(
+        store.try_find("c")?.is_some(),
+        "non-conflicting edits are still applied"
+    );
+    assert_eq!(
+        store.find("b")?.target.into_id(),
+        hex_to_id("e69de29bb2d1d6434b8b29ae775ad8c2e48c5391"),
+        "rejected edits are not applied"
+    );
+    Ok(())
+}
+
+#[test]
+fn nothing_is_applied_when_rejecting_partial_batches() -> crate::Result {
+    let (_keep, store) = empty_store()?;
+    let mut buf = TimeBuf::default();
+    store.commit_edits(
+        vec![create_at("refs/heads/a"), create_at("refs/heads/b")],
+        Some(committer().to_ref(&mut buf)),
+        false,
+    )?;
+
+    let rejected = store.commit_edits(conflicting_batch(), Some(committer().to_ref(&mut buf)), true)?;
+    assert_eq!(rejected.len(), 2, "all conflicts are reported");
+    assert!(
+        store.try_find("c")?.is_none(),
+        "the non-conflicting edit isn't applied either"
+    );
+    Ok(())
+}
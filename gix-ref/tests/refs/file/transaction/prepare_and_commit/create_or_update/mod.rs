@@ -388,6 +388,98 @@ fn cancellation_after_preparation_leaves_no_change() -> crate::Result {
     Ok(())
 }
 
+#[test]
+fn preview_after_preparation_shows_changes_without_performing_them() -> crate::Result {
+    for (write_reflog, expect_reflog_line) in [(WriteReflog::Normal, true), (WriteReflog::Disable, false)] {
+        let (dir, mut store) = empty_store()?;
+        store.write_reflog = write_reflog;
+
+        assert_eq!(
+            store.transaction().preview(),
+            None,
+            "there is nothing to preview without preparation"
+        );
+        let tx = store.transaction().prepare(
+            [
+                create_at("refs/heads/main"),
+                create_symbolic_at("HEAD", "refs/heads/main"),
+            ],
+            Fail::Immediately,
+            Fail::Immediately,
+        )?;
+        let preview = tx.preview().expect("prepared");
+        assert_eq!(preview.len(), 2, "no split was performed");
+        assert_eq!(preview[0].edit.name.as_bstr(), "refs/heads/main");
+        assert_eq!(
+            preview[0].reflog_line,
+            expect_reflog_line.then(|| { (None, hex_to_id("e69de29bb2d1d6434b8b29ae775ad8c2e48c5391"),) }),
+            "the reflog is only written if enabled, with the previous value unknown as the ref is new"
+        );
+        assert_eq!(preview[1].edit.name.as_bstr(), "HEAD");
+        assert_eq!(
+            preview[1].reflog_line, None,
+            "symbolic refs don't have a reflog line unless their peeled value is known"
+        );
+
+        drop(tx);
+        assert_eq!(
+            std::fs::read_dir(dir.path())?.count(),
+            0,
+            "previewing doesn't cause any change"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn preview_without_locks_reads_previous_values_even_if_locked() -> crate::Result {
+    let (_keep, store) = store_writable("make_packed_ref_repository.sh")?;
+    let main_lock = store.git_dir().join("refs/heads/main.lock");
+    std::fs::create_dir_all(main_lock.parent().expect("parent"))?;
+    std::fs::write(&main_lock, b"")?;
+    std::fs::write(store.git_dir().join("packed-refs.lock"), b"")?;
+
+    let old_id = hex_to_id("134385f6d781b7e97062102c6a483440bfda2a03");
+    let new_id = hex_to_id("28ce6a8b26aa170e1de65536fe8abe1832bd3242");
+    let edit = RefEdit {
+        change: Change::Update {
+            log: LogChange::default(),
+            expected: PreviousValue::Any,
+            new: Target::Object(new_id),
+        },
+        name: "refs/heads/main".try_into()?,
+        deref: false,
+    };
+    assert!(
+        store
+            .transaction()
+            .prepare(Some(edit.clone()), Fail::Immediately, Fail::Immediately)
+            .is_err(),
+        "a regular preparation needs the locks"
+    );
+
+    let tx = store.transaction().prepare_for_preview(Some(edit))?;
+    let preview = tx.preview().expect("prepared");
+    assert_eq!(preview.len(), 1);
+    assert_eq!(
+        preview[0].edit.change.previous_value(),
+        Some(gix_ref::TargetRef::Object(&old_id)),
+        "the previous value is read from packed refs"
+    );
+    assert_eq!(preview[0].reflog_line, Some((Some(old_id), new_id)));
+    assert_eq!(
+        tx.rollback().len(),
+        1,
+        "the edit can be obtained without having changed anything"
+    );
+    assert!(main_lock.is_file(), "locks held by others are untouched");
+    assert!(
+        store.try_find_loose("main")?.is_none(),
+        "nothing was written, main is still only packed"
+    );
+    Ok(())
+}
+
 #[test]
 fn symbolic_reference_writes_reflog_if_previous_value_is_set() -> crate::Result {
     let (_keep, store) = empty_store()?;
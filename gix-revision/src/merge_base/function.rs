@@ -33,12 +33,120 @@ pub fn merge_base(
     }
 
     graph.clear_commit_data(|f| *f = Flags::empty());
-    let bases = paint_down_to_common(first, others, graph)?;
+    let bases = paint_down_to_common(first, others, None, graph)?;
 
     let bases = remove_redundant(&bases, graph)?;
     Ok(nonempty::NonEmpty::from_vec(bases))
 }
 
+/// Like [`merge_base()`], but don't traverse into commits whose generation number is lower than `min_generation`,
+/// which is useful if it's known that no merge-base can be older than that, to avoid walking deep into history.
+/// The result is the same as the one of [`merge_base()`] if all merge-bases have a generation of at least `min_generation`.
+///
+/// Generation numbers are only available if `graph` is backed by a commit-graph, and without it, or for commits
+/// not contained in it, this function behaves exactly like [`merge_base()`].
+pub fn with_min_generation(
+    first: ObjectId,
+    others: &[ObjectId],
+    min_generation: gix_revwalk::graph::Generation,
+    graph: &mut Graph<'_, '_, graph::Commit<Flags>>,
+) -> Result<Option<nonempty::NonEmpty<ObjectId>>, Error> {
+    let _span = gix_trace::coarse!(
+        "gix_revision::merge_base::with_min_generation()",
+        ?first,
+        ?others,
+        min_generation
+    );
+    if others.is_empty() || others.contains(&first) {
+        return Ok(Some(nonempty::NonEmpty::new(first)));
+    }
+
+    graph.clear_commit_data(|f| *f = Flags::empty());
+    let bases = paint_down_to_common(first, others, Some(min_generation), graph)?;
+
+    let bases = remove_redundant(&bases, graph)?;
+    Ok(nonempty::NonEmpty::from_vec(bases))
+}
+
+/// Return `true` if `ancestor` is reachable from `descendant` by traversing the commit `graph`, or if both are the same commit.
+///
+/// Unlike [`merge_base()`], this stops as soon as `ancestor` is found, or if a commit-graph is available, once the
+/// generation numbers of all commits left to traverse are lower than the one of `ancestor`, which is when they can't reach it anymore.
+/// Commit-times aren't used to stop early as they might be skewed, which is the same in Git.
+///
+/// # Performance
+///
+/// For repeated calls, be sure to re-use `graph` as its content will be kept and reused for a great speed-up. The contained flags
+/// will automatically be cleared.
+pub fn is_ancestor(
+    ancestor: ObjectId,
+    descendant: ObjectId,
+    graph: &mut Graph<'_, '_, graph::Commit<Flags>>,
+) -> Result<bool, Error> {
+    let _span = gix_trace::coarse!("gix_revision::merge_base::is_ancestor()", ?ancestor, ?descendant);
+    if ancestor == descendant {
+        return Ok(true);
+    }
+
+    graph.clear_commit_data(|f| *f = Flags::empty());
+    let ancestor_info = graph
+        .get_or_insert_full_commit(ancestor, |_| {})
+        .map_err(|_| Simple("could not insert commit into graph"))?
+        .map(|commit| GenThenTime::from(&*commit))
+        .ok_or(Simple("could not find ancestor commit"))?;
+
+    let mut queue = PriorityQueue::<GenThenTime, ObjectId>::new();
+    graph
+        .get_or_insert_full_commit(descendant, |commit| {
+            commit.data |= Flags::COMMIT2;
+            queue.insert(GenThenTime::from(&*commit), descendant);
+        })
+        .map_err(|_| Simple("could not insert commit into graph"))?;
+
+    while let Some((info, commit_id)) = queue.pop() {
+        if commit_id == ancestor {
+            return Ok(true);
+        }
+        if info.generation < ancestor_info.generation {
+            // The queue is sorted by generation first, so all remaining commits can't reach `ancestor` either.
+            break;
+        }
+        let parents = graph
+            .get(&commit_id)
+            .expect("everything queued is in graph")
+            .parents
+            .clone();
+        for parent_id in parents {
+            graph
+                .get_or_insert_full_commit(parent_id, |parent| {
+                    if !parent.data.contains(Flags::COMMIT2) {
+                        parent.data |= Flags::COMMIT2;
+                        queue.insert(GenThenTime::from(&*parent), parent_id);
+                    }
+                })
+                .map_err(|_| Simple("could not insert parent commit into graph"))?;
+        }
+    }
+    Ok(false)
+}
+
+/// Return only those of `commits` that aren't in the history of another commit in `commits`, in their original order.
+pub(crate) fn independent(
+    commits: &[ObjectId],
+    graph: &mut Graph<'_, '_, graph::Commit<Flags>>,
+) -> Result<Vec<ObjectId>, Error> {
+    let mut commits_with_info = Vec::with_capacity(commits.len());
+    for id in commits {
+        let info = graph
+            .get_or_insert_full_commit(*id, |_| {})
+            .map_err(|_| Simple("could not insert commit into graph"))?
+            .map(|commit| GenThenTime::from(&*commit))
+            .ok_or(Simple("could not find commit"))?;
+        commits_with_info.push((*id, info));
+    }
+    remove_redundant(&commits_with_info, graph)
+}
+
 /// Remove all those commits from `commits` if they are in the history of another commit in `commits`.
 /// That way, we return only the topologically most recent commits in `commits`.
 fn remove_redundant(
@@ -151,6 +259,7 @@ fn remove_redundant(
 fn paint_down_to_common(
     first: ObjectId,
     others: &[ObjectId],
+    min_generation: Option<gix_revwalk::graph::Generation>,
     graph: &mut Graph<'_, '_, graph::Commit<Flags>>,
 ) -> Result<Vec<(ObjectId, GenThenTime)>, Error> {
     let mut queue = PriorityQueue::<GenThenTime, ObjectId>::new();
@@ -176,6 +285,10 @@ fn paint_down_to_common(
         .any(|id| graph.get(id).is_some_and(|commit| !commit.data.contains(Flags::STALE)))
     {
         let (info, commit_id) = queue.pop().expect("we have non-stale");
+        if min_generation.is_some_and(|min_generation| info.generation < min_generation) {
+            // The queue is sorted by generation first, so all remaining commits are too old as well.
+            break;
+        }
         let commit = graph.get_mut(&commit_id).expect("everything queued is in graph");
         let mut flags_without_result = commit.data & (Flags::COMMIT1 | Flags::COMMIT2 | Flags::STALE);
         if flags_without_result == (Flags::COMMIT1 | Flags::COMMIT2) {
@@ -30,10 +30,14 @@ impl std::fmt::Display for Simple {
 impl std::error::Error for Simple {}
 
 pub(crate) mod function;
+pub use function::{is_ancestor, with_min_generation};
 
 mod octopus {
+    use std::{collections::HashMap, hash::BuildHasher};
+
     use gix_hash::ObjectId;
     use gix_revwalk::{Graph, graph};
+    use nonempty::NonEmpty;
 
     use crate::merge_base::{Error, Flags};
 
@@ -41,25 +45,102 @@ mod octopus {
     /// sorted from best to worst. Returns `None` if there is no common merge-base as `first` and `others` don't *all* share history.
     /// If `others` is empty, `Some(first)` is returned.
     ///
+    /// This is the first of the merge-bases returned by [`octopus_all()`].
+    ///
     /// # Performance
     ///
     /// For repeated calls, be sure to re-use `graph` as its content will be kept and reused for a great speed-up. The contained flags
     /// will automatically be cleared.
+    ///
+    /// Note that as all merge-bases are needed to find the best one, this allocates to keep them.
     pub fn octopus(
-        mut first: ObjectId,
+        first: ObjectId,
         others: &[ObjectId],
         graph: &mut Graph<'_, '_, graph::Commit<Flags>>,
     ) -> Result<Option<ObjectId>, Error> {
+        Ok(octopus_all(first, others, graph)?.map(|bases| *bases.first()))
+    }
+
+    /// Like [`octopus()`], but return *all* merge-bases between `first` and `others`, best first, just like
+    /// `git merge-base --octopus --all` does. Returns `None` if `first` and `others` don't *all* share history.
+    ///
+    /// Each commit of `others` is merged with all merge-bases found so far, and the merge-bases that are ancestors
+    /// of other merge-bases are removed in a single traversal once all `others` were processed.
+    ///
+    /// # Performance
+    ///
+    /// For repeated calls, be sure to re-use `graph` as its content will be kept and reused for a great speed-up. The contained flags
+    /// will automatically be cleared.
+    pub fn octopus_all(
+        first: ObjectId,
+        others: &[ObjectId],
+        graph: &mut Graph<'_, '_, graph::Commit<Flags>>,
+    ) -> Result<Option<NonEmpty<ObjectId>>, Error> {
+        octopus_all_with(first, others, graph, |first, other, graph| {
+            crate::merge_base(first, std::slice::from_ref(&other), graph)
+        })
+    }
+
+    /// Like [`octopus()`], but memoize the merge-bases of each pair of commits in `cache`, so that repeated computations
+    /// of the same pair, like in batches of octopus merge-bases that share heads, are answered without traversing `graph` again.
+    ///
+    /// The key of `cache` is `(first, other)` in the order in which they are passed to [`merge_base()`](crate::merge_base()),
+    /// and the value are all merge-bases between them, if there are any.
+    /// The result is always the same as the one of [`octopus()`].
+    ///
+    /// # Performance
+    ///
+    /// `cache` is never cleared and grows with each new pair of commits, trading memory for speed.
+    pub fn octopus_cached<S: BuildHasher>(
+        first: ObjectId,
+        others: &[ObjectId],
+        graph: &mut Graph<'_, '_, graph::Commit<Flags>>,
+        cache: &mut HashMap<(ObjectId, ObjectId), Option<NonEmpty<ObjectId>>, S>,
+    ) -> Result<Option<ObjectId>, Error> {
+        let bases = octopus_all_with(first, others, graph, |first, other, graph| {
+            Ok(match cache.get(&(first, other)) {
+                Some(bases) => bases.clone(),
+                None => {
+                    let bases = crate::merge_base(first, std::slice::from_ref(&other), graph)?;
+                    cache.insert((first, other), bases.clone());
+                    bases
+                }
+            })
+        })?;
+        Ok(bases.map(|bases| *bases.first()))
+    }
+
+    /// The algorithm of [`octopus_all()`], which obtains the merge-bases of each pair of commits from `merge_base(first, other, graph)`.
+    fn octopus_all_with(
+        first: ObjectId,
+        others: &[ObjectId],
+        graph: &mut Graph<'_, '_, graph::Commit<Flags>>,
+        mut merge_base: impl FnMut(
+            ObjectId,
+            ObjectId,
+            &mut Graph<'_, '_, graph::Commit<Flags>>,
+        ) -> Result<Option<NonEmpty<ObjectId>>, Error>,
+    ) -> Result<Option<NonEmpty<ObjectId>>, Error> {
+        let mut bases = vec![first];
         for other in others {
-            if let Some(next) =
-                crate::merge_base(first, std::slice::from_ref(other), graph)?.map(|bases| *bases.first())
-            {
-                first = next;
-            } else {
+            let mut next = Vec::new();
+            for base in &bases {
+                for id in merge_base(*base, *other, graph)?.into_iter().flatten() {
+                    if !next.contains(&id) {
+                        next.push(id);
+                    }
+                }
+            }
+            if next.is_empty() {
                 return Ok(None);
             }
+            bases = next;
+        }
+
+        if bases.len() > 1 {
+            bases = crate::merge_base::function::independent(&bases, graph)?;
         }
-        Ok(Some(first))
+        Ok(NonEmpty::from_vec(bases))
     }
 }
-pub use octopus::octopus;
+pub use octopus::{octopus, octopus_all, octopus_cached};
@@ -41,3 +41,24 @@ git init three-forked-commits
   git checkout B
   git commit -m "B" --allow-empty
 )
+
+git init criss-cross
+(cd criss-cross
+  git commit -m "BASE" --allow-empty
+  git branch A
+  git branch B
+
+  git checkout A
+  git commit -m "A-1" --allow-empty
+
+  git checkout B
+  git commit -m "B-1" --allow-empty
+  git branch C
+
+  git checkout A
+  git merge --no-ff -m "A-2" B
+  git checkout B
+  git merge --no-ff -m "B-2" A~1
+  git checkout C
+  git merge --no-ff -m "C-2" A~1
+)
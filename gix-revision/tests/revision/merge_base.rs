@@ -38,6 +38,42 @@ fn validate() -> crate::Result {
     Ok(())
 }
 
+#[test]
+fn with_min_generation() -> crate::Result {
+    let root = gix_testtools::scripted_fixture_read_only("make_merge_base_repos.sh")?;
+    let odb = gix_odb::at(root.join(".git/objects"))?;
+    for use_commitgraph in [false, true] {
+        let cache = use_commitgraph
+            .then(|| gix_commitgraph::Graph::from_info_dir(&odb.store_ref().path().join("info")).unwrap());
+        let mut graph = gix_revision::Graph::new(&odb, cache.as_ref());
+        for baseline_path in baseline::expectation_paths(&root)? {
+            for expected in baseline::parse_expectations(&baseline_path)? {
+                let min_generation = expected
+                    .bases
+                    .iter()
+                    .flatten()
+                    .filter_map(|id| {
+                        graph
+                            .get_or_insert_full_commit(*id, |_| {})
+                            .expect("valid")
+                            .and_then(|commit| commit.generation)
+                    })
+                    .min()
+                    .unwrap_or(0);
+                let actual =
+                    merge_base::with_min_generation(expected.first, &expected.others, min_generation, &mut graph)?;
+                assert_eq!(
+                    actual,
+                    expected.bases,
+                    "{input}: the cutoff doesn't affect the result if no merge-base is older (commitgraph: {use_commitgraph})",
+                    input = expected.plain_input
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
 mod octopus {
     use crate::hex_to_id;
 
@@ -98,12 +134,182 @@ mod octopus {
         Ok(())
     }
 
+    #[test]
+    fn three_forked_commits_cached() -> crate::Result {
+        let odb = odb_at("three-forked-commits")?;
+        let mut graph = gix_revision::Graph::new(&odb, None);
+        let mut cache = std::collections::HashMap::new();
+        let base = hex_to_id("3ca3e3dd12585fabbef311d524a5e54678090528");
+        let mut heads = vec![
+            hex_to_id("413d38a3fe7453c68cb7314739d7775f68ab89f5"),
+            hex_to_id("d4d01a9b6f6fcb23d57cd560229cd9680ec9bd6e"),
+            hex_to_id("c507d5413da00c32e5de1ea433030e8e4716bc60"),
+        ];
+        let mut heap = permutohedron::Heap::new(&mut heads);
+        while let Some(heads) = heap.next_permutation() {
+            let actual =
+                gix_revision::merge_base::octopus_cached(*heads.first().unwrap(), &heads[1..], &mut graph, &mut cache)?
+                    .expect("a merge base");
+            assert_eq!(actual, base);
+        }
+        let num_pairs = cache.len();
+        assert!(num_pairs > 0, "each computed pair is remembered");
+
+        let actual = gix_revision::merge_base::octopus_cached(heads[0], &heads[1..], &mut graph, &mut cache)?
+            .expect("a merge base");
+        assert_eq!(actual, base);
+        assert_eq!(cache.len(), num_pairs, "all pairs were answered from the cache");
+        Ok(())
+    }
+
+    #[test]
+    fn cached_and_uncached_results_are_the_same() -> crate::Result {
+        let odb = odb_at("criss-cross")?;
+        let mut graph = gix_revision::Graph::new(&odb, None);
+        let mut cache = std::collections::HashMap::new();
+        let mut heads = vec![
+            hex_to_id("60227038a5b21d282b5bda9e931bcfca38b4f6f2"),
+            hex_to_id("87e0a29cd3eb2e611c86dc3817ce89443bfd0d8e"),
+            hex_to_id("2b20680ced7e113ee3c32c63c240518f6ee96d3b"),
+        ];
+        let all_heads = heads.clone();
+        let mut heap = permutohedron::Heap::new(&mut heads);
+        while let Some(heads) = heap.next_permutation() {
+            for _round in 0..2 {
+                let cached = gix_revision::merge_base::octopus_cached(heads[0], &heads[1..], &mut graph, &mut cache)?;
+                let uncached = gix_revision::merge_base::octopus(heads[0], &heads[1..], &mut graph)?;
+                assert!(cached.is_some());
+                assert_eq!(
+                    cached, uncached,
+                    "the cache doesn't affect the result, even with multiple merge-bases"
+                );
+            }
+        }
+        assert!(
+            cache.keys().all(|(_, other)| all_heads.contains(other)),
+            "only merge-bases with the heads are cached, not those of the final reduction of merge-bases"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn criss_cross_has_multiple_bases() -> crate::Result {
+        let odb = odb_at("criss-cross")?;
+        let mut graph = gix_revision::Graph::new(&odb, None);
+        let a1 = hex_to_id("e5ca2c07c5f4035e6c1b96ba0179509a5166e39a");
+        let b1 = hex_to_id("15b066a16d114585e4b832fffa4a6a3e773c6b7c");
+        let mut heads = vec![
+            hex_to_id("60227038a5b21d282b5bda9e931bcfca38b4f6f2"),
+            hex_to_id("87e0a29cd3eb2e611c86dc3817ce89443bfd0d8e"),
+            hex_to_id("2b20680ced7e113ee3c32c63c240518f6ee96d3b"),
+        ];
+        let mut heap = permutohedron::Heap::new(&mut heads);
+        while let Some(heads) = heap.next_permutation() {
+            let mut actual: Vec<_> =
+                gix_revision::merge_base::octopus_all(*heads.first().unwrap(), &heads[1..], &mut graph)?
+                    .expect("merge bases")
+                    .into();
+            let best = gix_revision::merge_base::octopus(*heads.first().unwrap(), &heads[1..], &mut graph)?
+                .expect("a merge base");
+            assert_eq!(best, actual[0], "octopus() returns the best of all merge-bases");
+            actual.sort();
+            assert_eq!(
+                actual,
+                [b1, a1],
+                "both merge-bases are returned, like `git merge-base --octopus --all`"
+            );
+        }
+
+        let actual: Vec<_> = gix_revision::merge_base::octopus_all(heads[0], &[heads[1], a1], &mut graph)?
+            .expect("merge bases")
+            .into();
+        assert_eq!(
+            actual,
+            [a1],
+            "merge-bases that are ancestors of other merge-bases are removed"
+        );
+        Ok(())
+    }
+
     fn odb_at(name: &str) -> crate::Result<gix_odb::Handle> {
         let root = gix_testtools::scripted_fixture_read_only("merge_base_octopus_repos.sh")?;
         Ok(gix_odb::at(root.join(name).join(".git/objects"))?)
     }
 }
 
+mod is_ancestor {
+    use gix_revision::merge_base::is_ancestor;
+
+    use crate::hex_to_id;
+
+    #[test]
+    fn merge_bases_are_ancestors_of_their_commits() -> crate::Result {
+        let root = gix_testtools::scripted_fixture_read_only("make_merge_base_repos.sh")?;
+        let odb = gix_odb::at(root.join(".git/objects"))?;
+        for use_commitgraph in [false, true] {
+            let cache = use_commitgraph
+                .then(|| gix_commitgraph::Graph::from_info_dir(&odb.store_ref().path().join("info")).unwrap());
+            let mut graph = gix_revision::Graph::new(&odb, cache.as_ref());
+            for baseline_path in super::baseline::expectation_paths(&root)? {
+                for expected in super::baseline::parse_expectations(&baseline_path)? {
+                    if expected.others.is_empty() || expected.others.contains(&expected.first) {
+                        // `merge_base()` returns `first` right away in these cases, even if it's not an ancestor.
+                        continue;
+                    }
+                    for base in expected.bases.iter().flatten() {
+                        assert!(
+                            is_ancestor(*base, expected.first, &mut graph)?,
+                            "{input}: merge-base {base} is an ancestor of the first commit (commitgraph: {use_commitgraph})",
+                            input = expected.plain_input
+                        );
+                        let mut reaches_any_other = false;
+                        for other in &expected.others {
+                            reaches_any_other |= is_ancestor(*base, *other, &mut graph)?;
+                        }
+                        assert!(
+                            reaches_any_other,
+                            "{input}: merge-base {base} is an ancestor of one of the other commits (commitgraph: {use_commitgraph})",
+                            input = expected.plain_input
+                        );
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn criss_cross() -> crate::Result {
+        let root = gix_testtools::scripted_fixture_read_only("merge_base_octopus_repos.sh")?;
+        let odb = gix_odb::at(root.join("criss-cross").join(".git/objects"))?;
+        let mut graph = gix_revision::Graph::new(&odb, None);
+        let base = hex_to_id("3ca3e3dd12585fabbef311d524a5e54678090528");
+        let a1 = hex_to_id("e5ca2c07c5f4035e6c1b96ba0179509a5166e39a");
+        let b1 = hex_to_id("15b066a16d114585e4b832fffa4a6a3e773c6b7c");
+        let a2 = hex_to_id("60227038a5b21d282b5bda9e931bcfca38b4f6f2");
+        let b2 = hex_to_id("87e0a29cd3eb2e611c86dc3817ce89443bfd0d8e");
+
+        for (ancestor, descendant, expected) in [
+            (a2, a2, true),
+            (base, a2, true),
+            (a1, a2, true),
+            (b1, a2, true),
+            (a1, b2, true),
+            (a2, base, false),
+            (a2, b2, false),
+            (b2, a2, false),
+            (a1, b1, false),
+        ] {
+            assert_eq!(
+                is_ancestor(ancestor, descendant, &mut graph)?,
+                expected,
+                "{ancestor} -> {descendant}"
+            );
+        }
+        Ok(())
+    }
+}
+
 mod baseline {
     use std::{
         ffi::OsStr,
@@ -0,0 +1,105 @@
+use gix_hash::ObjectId;
+use nonempty::NonEmpty;
+
+use crate::Commits;
+
+/// Lifecycle
+impl Commits {
+    /// Create a new instance from `commits`, sorting them and removing duplicates,
+    /// or return `None` if `commits` is empty.
+    pub fn from_vec(mut commits: Vec<ObjectId>) -> Option<Self> {
+        commits.sort();
+        commits.dedup();
+        NonEmpty::from_vec(commits).map(Commits)
+    }
+
+    /// Return all commits as sorted vector.
+    pub fn into_vec(self) -> Vec<ObjectId> {
+        self.0.into()
+    }
+}
+
+/// Access
+impl Commits {
+    /// Return `true` if `id` is part of the shallow boundary.
+    pub fn contains(&self, id: &ObjectId) -> bool {
+        self.binary_search(id).is_ok()
+    }
+}
+
+/// Set Operations
+impl Commits {
+    /// Return a new instance with all of our commits as well as all `other` commits.
+    ///
+    /// `other` may be another instance of `Commits`, or any iterator of object ids.
+    pub fn union(&self, other: impl IntoIterator<Item = ObjectId>) -> Self {
+        let mut commits = self.clone().into_vec();
+        commits.extend(other);
+        Self::from_vec(commits).expect("we are never empty")
+    }
+
+    /// Return a new instance with all of our commits that are not in `other`, or `None` if no commit is left.
+    ///
+    /// This is equivalent to applying [`Update::Unshallow`](crate::Update::Unshallow) for each commit in `other`.
+    pub fn difference(&self, other: impl IntoIterator<Item = ObjectId>) -> Option<Self> {
+        let mut other: Vec<_> = other.into_iter().collect();
+        other.sort();
+        self.clone().retain(|id| other.binary_search(id).is_err())
+    }
+}
+
+/// Mutation
+impl Commits {
+    /// Keep only the commits for which `keep` returns `true`, or return `None` if no commit was kept.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let first = gix_hash::ObjectId::from_hex(b"1111111111111111111111111111111111111111")?;
+    /// let second = gix_hash::ObjectId::from_hex(b"2222222222222222222222222222222222222222")?;
+    /// let commits = gix_shallow::Commits::from_vec(vec![second, first]).expect("non-empty");
+    ///
+    /// let kept = commits.clone().retain(|id| *id != first).expect("one commit is left");
+    /// assert_eq!(kept.into_vec(), vec![second]);
+    /// assert_eq!(commits.retain(|_| false), None, "an empty boundary isn't representable");
+    /// # Ok(()) }
+    /// ```
+    pub fn retain(self, mut keep: impl FnMut(&ObjectId) -> bool) -> Option<Self> {
+        let mut commits = self.into_vec();
+        commits.retain(|id| keep(id));
+        NonEmpty::from_vec(commits).map(Commits)
+    }
+}
+
+impl std::ops::Deref for Commits {
+    type Target = NonEmpty<ObjectId>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl IntoIterator for Commits {
+    type Item = ObjectId;
+    type IntoIter = <NonEmpty<ObjectId> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Commits {
+    type Item = &'a ObjectId;
+    type IntoIter = <&'a NonEmpty<ObjectId> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self.0).into_iter()
+    }
+}
+
+impl From<Commits> for Vec<ObjectId> {
+    fn from(commits: Commits) -> Self {
+        commits.into_vec()
+    }
+}
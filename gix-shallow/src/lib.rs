@@ -35,26 +35,47 @@ pub enum Update {
     Unshallow(gix_hash::ObjectId),
 }
 
+/// A non-empty list of shallow commits, sorted by id and without duplicates.
+///
+/// It represents the shallow boundary, beyond which we are lacking all (parent) commits.
+/// It dereferences to the underlying [`NonEmpty`](nonempty::NonEmpty) list for access to its elements.
+#[derive(PartialEq, Eq, Debug, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Commits(nonempty::NonEmpty<gix_hash::ObjectId>);
+
+mod commits;
+
 /// Return a list of shallow commits as unconditionally read from `shallow_file`.
 ///
 /// The list of shallow commits represents the shallow boundary, beyond which we are lacking all (parent) commits.
 /// Note that the list is never empty, as `Ok(None)` is returned in that case indicating the repository
 /// isn't a shallow clone.
-pub fn read(shallow_file: &std::path::Path) -> Result<Option<nonempty::NonEmpty<gix_hash::ObjectId>>, read::Error> {
-    use bstr::ByteSlice;
+pub fn read(shallow_file: &std::path::Path) -> Result<Option<Commits>, read::Error> {
     let buf = match std::fs::read(shallow_file) {
         Ok(buf) => buf,
         Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
         Err(err) => return Err(err.into()),
     };
+    parse(&buf)
+}
 
-    let mut commits = buf
+/// Like [`read()`], but obtain the contents of a shallow file from `reader`, returning `Ok(None)` if it yields no bytes.
+///
+/// This is useful to validate shallow information before it is written to disk.
+pub fn read_from(mut reader: impl std::io::Read) -> Result<Option<Commits>, read::Error> {
+    let mut buf = Vec::new();
+    reader.read_to_end(&mut buf)?;
+    parse(&buf)
+}
+
+fn parse(buf: &[u8]) -> Result<Option<Commits>, read::Error> {
+    use bstr::ByteSlice;
+    let commits = buf
         .lines()
         .map(gix_hash::ObjectId::from_hex)
         .collect::<Result<Vec<_>, _>>()?;
 
-    commits.sort();
-    Ok(nonempty::NonEmpty::from_vec(commits))
+    Ok(Commits::from_vec(commits))
 }
 
 ///
@@ -62,32 +83,127 @@ pub mod write {
     pub(crate) mod function {
         use std::io::Write;
 
-        use super::Error;
-        use crate::Update;
+        use gix_hash::ObjectId;
+        use gix_object::FindExt;
+
+        use super::{Applied, Error, Statistics};
+        use crate::{Commits, Update};
 
         /// Write the [previously obtained](crate::read()) (possibly non-existing) `shallow_commits` to the shallow `file`
         /// after applying all `updates`.
         ///
         /// If this leaves the list of shallow commits empty, the file is removed.
+        /// Use [`apply_updates()`] and [`serialize()`] to obtain the result without writing it to disk.
         ///
         /// ### Deviation
         ///
-        /// Git also prunes the set of shallow commits while writing, we don't until we support some sort of pruning.
+        /// Git also prunes the set of shallow commits while writing, use [`write_with_pruning()`] for that.
         pub fn write(
-            mut file: gix_lock::File,
-            shallow_commits: Option<nonempty::NonEmpty<gix_hash::ObjectId>>,
+            file: gix_lock::File,
+            shallow_commits: Option<Commits>,
+            updates: &[Update],
+        ) -> Result<Applied, Error> {
+            let (shallow_commits, applied) = apply(shallow_commits, updates);
+            write_commits(file, shallow_commits)?;
+            Ok(applied)
+        }
+
+        /// Like [`write()`], but also drop all shallow commits that aren't reachable from any of the commits in `tips`
+        /// anymore, which are looked up in `objects`.
+        ///
+        /// Shallow commits encountered while walking the history of `tips` form the actual boundary and are always kept,
+        /// while the history behind them isn't traversed as it is expected to be missing.
+        /// Note that `tips` must be commits, and all commits reachable from them up to the shallow boundary must exist.
+        pub fn write_with_pruning(
+            file: gix_lock::File,
+            shallow_commits: Option<Commits>,
             updates: &[Update],
-        ) -> Result<(), Error> {
-            let mut shallow_commits = shallow_commits.map(Vec::from).unwrap_or_default();
+            objects: &dyn gix_object::Find,
+            tips: &[ObjectId],
+        ) -> Result<Statistics, Error> {
+            let (shallow_commits, applied) = apply(shallow_commits, updates);
+            let Some(shallow_commits) = shallow_commits else {
+                write_commits(file, None)?;
+                return Ok(Statistics { pruned: 0, applied });
+            };
+            let reachable = reachable_shallow_commits(&shallow_commits, objects, tips)?;
+            let before = shallow_commits.len();
+            let shallow_commits = shallow_commits.retain(|id| reachable.contains(id));
+            let stats = Statistics {
+                pruned: before - shallow_commits.as_ref().map_or(0, |commits| commits.len()),
+                applied,
+            };
+            write_commits(file, shallow_commits)?;
+            Ok(stats)
+        }
+
+        /// Apply all `updates` to the [previously obtained](crate::read()) (possibly non-existing) `shallow_commits`
+        /// and return the result, or `None` if no shallow commit is left.
+        ///
+        /// Updates are applied in order, so a commit that is shallowed and later unshallowed won't be part of the result.
+        pub fn apply_updates(shallow_commits: Option<Commits>, updates: &[Update]) -> Option<Commits> {
+            apply(shallow_commits, updates).0
+        }
+
+        fn apply(shallow_commits: Option<Commits>, updates: &[Update]) -> (Option<Commits>, Applied) {
+            let mut shallow_commits: gix_hashtable::HashSet<ObjectId> = shallow_commits.into_iter().flatten().collect();
+            let mut applied = Applied::default();
             for update in updates {
                 match update {
                     Update::Shallow(id) => {
-                        shallow_commits.push(*id);
+                        if shallow_commits.insert(*id) {
+                            applied.shallowed.push(*id);
+                        } else {
+                            applied.ignored.push(*update);
+                        }
+                    }
+                    Update::Unshallow(id) => {
+                        if shallow_commits.remove(id) {
+                            applied.unshallowed.push(*id);
+                        } else {
+                            applied.ignored.push(*update);
+                        }
                     }
-                    Update::Unshallow(id) => shallow_commits.retain(|oid| oid != id),
                 }
             }
-            if shallow_commits.is_empty() {
+            (Commits::from_vec(shallow_commits.into_iter().collect()), applied)
+        }
+
+        /// Write `commits` to `out` in the format of a shallow file, one hex-encoded id per line.
+        pub fn serialize(commits: &Commits, out: &mut impl Write) -> std::io::Result<()> {
+            for commit in commits {
+                commit.write_hex_to(&mut *out)?;
+                out.write_all(b"\n")?;
+            }
+            Ok(())
+        }
+
+        /// Walk the history of `tips` up to the boundary and return all `shallow_commits` that we encountered.
+        fn reachable_shallow_commits(
+            shallow_commits: &Commits,
+            objects: &dyn gix_object::Find,
+            tips: &[ObjectId],
+        ) -> Result<gix_hashtable::HashSet<ObjectId>, Error> {
+            let mut reachable = gix_hashtable::HashSet::default();
+            let mut seen = gix_hashtable::HashSet::default();
+            let mut queue: Vec<_> = tips.to_vec();
+            let mut buf = Vec::new();
+            while let Some(id) = queue.pop() {
+                if !seen.insert(id) {
+                    continue;
+                }
+                if shallow_commits.contains(&id) {
+                    reachable.insert(id);
+                    continue;
+                }
+                let commit = objects.find_commit_iter(&id, &mut buf)?;
+                queue.extend(commit.parent_ids());
+            }
+            Ok(reachable)
+        }
+
+        fn write_commits(mut file: gix_lock::File, shallow_commits: Option<Commits>) -> Result<(), Error> {
+            let Some(shallow_commits) = shallow_commits else {
                 if let Err(err) = std::fs::remove_file(file.resource_path()) {
                     if err.kind() != std::io::ErrorKind::NotFound {
                         return Err(err.into());
@@ -95,13 +211,9 @@ pub mod write {
                 }
                 drop(file);
                 return Ok(());
-            }
-            shallow_commits.sort();
+            };
             let mut buf = Vec::<u8>::new();
-            for commit in shallow_commits {
-                commit.write_hex_to(&mut buf).map_err(Error::Io)?;
-                buf.push(b'\n');
-            }
+            serialize(&shallow_commits, &mut buf).map_err(Error::Io)?;
             file.write_all(&buf).map_err(Error::Io)?;
             file.flush().map_err(Error::Io)?;
             file.commit()?;
@@ -109,6 +221,27 @@ pub mod write {
         }
     }
 
+    /// A summary of the updates that were applied by [`write()`](crate::write()).
+    #[derive(Default, Debug, Clone, PartialEq, Eq)]
+    pub struct Applied {
+        /// The commits that were added to the shallow boundary.
+        pub shallowed: Vec<gix_hash::ObjectId>,
+        /// The commits that were removed from the shallow boundary.
+        pub unshallowed: Vec<gix_hash::ObjectId>,
+        /// Updates that had no effect, as the commit to shallow was already part of the boundary,
+        /// or the commit to unshallow wasn't.
+        pub ignored: Vec<crate::Update>,
+    }
+
+    /// Information about the pruning performed by [`write_with_pruning()`](crate::write_with_pruning()).
+    #[derive(Default, Debug, Clone, PartialEq, Eq)]
+    pub struct Statistics {
+        /// The amount of shallow commits that were removed as they weren't reachable anymore.
+        pub pruned: usize,
+        /// The updates that were applied before pruning.
+        pub applied: Applied,
+    }
+
     /// The error returned by [`write()`](crate::write()).
     #[derive(Debug, thiserror::Error)]
     #[allow(missing_docs)]
@@ -119,17 +252,19 @@ pub mod write {
         RemoveEmpty(#[from] std::io::Error),
         #[error("Failed to write object id to shallow file")]
         Io(std::io::Error),
+        #[error("Could not find a commit while determining which shallow commits are still reachable")]
+        FindCommit(#[from] gix_object::find::existing_iter::Error),
     }
 }
-pub use write::function::write;
+pub use write::function::{apply_updates, serialize, write, write_with_pruning};
 
 ///
 pub mod read {
-    /// The error returned by [`read`](crate::read()).
+    /// The error returned by [`read()`](crate::read()) and [`read_from()`](crate::read_from()).
     #[derive(Debug, thiserror::Error)]
     #[allow(missing_docs)]
     pub enum Error {
-        #[error("Could not open shallow file for reading")]
+        #[error("Could not read shallow file contents")]
         Io(#[from] std::io::Error),
         #[error("Could not decode a line in shallow file as hex-encoded object hash")]
         DecodeHash(#[from] gix_hash::decode::Error),
@@ -0,0 +1,12 @@
+#!/usr/bin/env bash
+set -eu -o pipefail
+
+git init -q
+
+git commit -q --allow-empty -m "boundary"
+git tag boundary
+git commit -q --allow-empty -m "main"
+
+git checkout -q --orphan deleted
+git commit -q --allow-empty -m "deleted branch"
+git checkout -q main
@@ -0,0 +1,191 @@
+use gix_hash::ObjectId;
+
+use gix_testtools::Result;
+
+/// Return the id the loose reference `name` in the repository at `repo` points to.
+fn ref_id(repo: &std::path::Path, name: &str) -> Result<ObjectId> {
+    Ok(ObjectId::from_hex(
+        std::fs::read(repo.join(".git").join(name))?.trim_ascii(),
+    )?)
+}
+
+fn lock(path: &std::path::Path) -> Result<gix_lock::File> {
+    Ok(gix_lock::File::acquire_to_update_resource(
+        path,
+        gix_lock::acquire::Fail::Immediately,
+        None,
+    )?)
+}
+
+#[test]
+fn write_with_pruning_keeps_the_reachable_boundary() -> Result {
+    let repo = gix_testtools::scripted_fixture_read_only("make_pruning_repo.sh")?;
+    let objects = gix_odb::at(repo.join(".git").join("objects"))?;
+    let boundary = ref_id(&repo, "refs/tags/boundary")?;
+    let main = ref_id(&repo, "refs/heads/main")?;
+    let unreachable = ref_id(&repo, "refs/heads/deleted")?;
+    let missing = ObjectId::from_hex(b"1111111111111111111111111111111111111111")?;
+
+    let dir = tempfile::tempdir()?;
+    let shallow_file = dir.path().join("shallow");
+    std::fs::write(&shallow_file, format!("{boundary}\n{unreachable}\n"))?;
+
+    let shallow = gix_shallow::read(&shallow_file)?;
+    let stats = gix_shallow::write_with_pruning(
+        lock(&shallow_file)?,
+        shallow,
+        &[gix_shallow::Update::Shallow(missing)],
+        &objects,
+        &[main],
+    )?;
+    assert_eq!(
+        stats.pruned, 2,
+        "the unreachable commit and the one that doesn't exist at all are pruned"
+    );
+    assert_eq!(
+        gix_shallow::read(&shallow_file)?.map(gix_shallow::Commits::into_vec),
+        Some(vec![boundary]),
+        "the commit forming the boundary of the history of `main` is kept"
+    );
+
+    let stats = gix_shallow::write_with_pruning(
+        lock(&shallow_file)?,
+        gix_shallow::read(&shallow_file)?,
+        &[],
+        &objects,
+        &[unreachable],
+    )?;
+    assert_eq!(stats.pruned, 1);
+    assert!(
+        !shallow_file.exists(),
+        "without any shallow commits left, the file is removed"
+    );
+    Ok(())
+}
+
+#[test]
+fn read_from() -> Result {
+    assert_eq!(gix_shallow::read_from(&b""[..])?, None, "no bytes means no boundary");
+
+    let first = ObjectId::from_hex(b"1111111111111111111111111111111111111111")?;
+    let second = ObjectId::from_hex(b"2222222222222222222222222222222222222222")?;
+    let commits = gix_shallow::read_from(format!("{second}\n{first}\n{second}\n").as_bytes())?.expect("non-empty");
+    assert_eq!(
+        commits.into_vec(),
+        vec![first, second],
+        "the result is sorted and without duplicates, just like with `read()`"
+    );
+
+    assert!(
+        matches!(
+            gix_shallow::read_from(&b"not a hash\n"[..]),
+            Err(gix_shallow::read::Error::DecodeHash(_))
+        ),
+        "invalid lines are rejected"
+    );
+    Ok(())
+}
+
+mod commits {
+    use gix_hash::ObjectId;
+    use gix_shallow::Commits;
+
+    fn id(hex_byte: u8) -> ObjectId {
+        ObjectId::from_bytes_or_panic(&[hex_byte; 20])
+    }
+
+    #[test]
+    fn contains_union_and_difference() {
+        let commits = Commits::from_vec(vec![id(3), id(1)]).expect("non-empty");
+        assert!(commits.contains(&id(1)));
+        assert!(!commits.contains(&id(2)));
+
+        let other = Commits::from_vec(vec![id(2), id(3)]).expect("non-empty");
+        let union = commits.union(other.clone());
+        assert_eq!(
+            union.clone().into_vec(),
+            vec![id(1), id(2), id(3)],
+            "the result is sorted and without duplicates"
+        );
+        assert!(union.contains(&id(2)), "lookups keep working");
+        assert_eq!(commits.union(None), commits, "nothing changes without other ids");
+
+        assert_eq!(
+            union.difference(other).map(Commits::into_vec),
+            Some(vec![id(1)]),
+            "set operations accept other instances"
+        );
+        assert_eq!(
+            union.difference([id(3), id(4)]).map(Commits::into_vec),
+            Some(vec![id(1), id(2)]),
+            "…and iterators, while ids that aren't contained are ignored"
+        );
+        assert_eq!(
+            commits.difference(union),
+            None,
+            "an empty boundary can't be represented"
+        );
+    }
+}
+
+#[test]
+fn apply_updates_and_serialize() -> Result {
+    use gix_shallow::Update::{Shallow, Unshallow};
+    let first = ObjectId::from_hex(b"1111111111111111111111111111111111111111")?;
+    let second = ObjectId::from_hex(b"2222222222222222222222222222222222222222")?;
+
+    assert_eq!(gix_shallow::apply_updates(None, &[]), None);
+    assert_eq!(
+        gix_shallow::apply_updates(None, &[Shallow(first), Unshallow(first)]),
+        None,
+        "updates are applied in order"
+    );
+
+    let commits =
+        gix_shallow::apply_updates(None, &[Shallow(second), Shallow(first), Shallow(second)]).expect("non-empty");
+    let mut buf = Vec::new();
+    gix_shallow::serialize(&commits, &mut buf)?;
+    assert_eq!(
+        buf,
+        format!("{first}\n{second}\n").as_bytes(),
+        "the output is sorted and without duplicates"
+    );
+    assert_eq!(
+        gix_shallow::read_from(buf.as_slice())?,
+        Some(commits),
+        "the output can be read back"
+    );
+    Ok(())
+}
+
+#[test]
+fn write_reports_applied_updates() -> Result {
+    use gix_shallow::Update::{Shallow, Unshallow};
+    let first = ObjectId::from_hex(b"1111111111111111111111111111111111111111")?;
+    let second = ObjectId::from_hex(b"2222222222222222222222222222222222222222")?;
+    let third = ObjectId::from_hex(b"3333333333333333333333333333333333333333")?;
+
+    let dir = tempfile::tempdir()?;
+    let shallow_file = dir.path().join("shallow");
+    std::fs::write(&shallow_file, format!("{first}\n"))?;
+
+    let applied = gix_shallow::write(
+        lock(&shallow_file)?,
+        gix_shallow::read(&shallow_file)?,
+        &[Shallow(first), Shallow(second), Unshallow(third), Unshallow(first)],
+    )?;
+    assert_eq!(
+        applied,
+        gix_shallow::write::Applied {
+            shallowed: vec![second],
+            unshallowed: vec![first],
+            ignored: vec![Shallow(first), Unshallow(third)],
+        },
+        "updates that don't match the current boundary are ignored, in order of application"
+    );
+    assert_eq!(
+        gix_shallow::read(&shallow_file)?.map(gix_shallow::Commits::into_vec),
+        Some(vec![second])
+    );
+    Ok(())
+}
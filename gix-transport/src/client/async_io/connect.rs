@@ -16,6 +16,11 @@ pub(crate) mod function {
         gix_url::parse::Error: From<E>,
     {
         let mut url = url.try_into().map_err(gix_url::parse::Error::from)?;
+        if let Some((rewrite, service)) = &options.url_rewrite {
+            if let Some(rewritten) = rewrite.rewrite(&url, *service)? {
+                url = rewritten;
+            }
+        }
         Ok(match url.scheme {
             gix_url::Scheme::Git => {
                 if url.user().is_some() {
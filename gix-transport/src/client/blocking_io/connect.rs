@@ -22,6 +22,11 @@ pub(crate) mod function {
         gix_url::parse::Error: From<E>,
     {
         let mut url = url.try_into().map_err(gix_url::parse::Error::from)?;
+        if let Some((rewrite, service)) = &options.url_rewrite {
+            if let Some(rewritten) = rewrite.rewrite(&url, *service)? {
+                url = rewritten;
+            }
+        }
         Ok(match url.scheme {
             gix_url::Scheme::Ext(_) => return Err(Error::UnsupportedScheme(url.scheme)),
             gix_url::Scheme::File => {
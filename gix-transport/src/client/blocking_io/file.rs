@@ -38,6 +38,16 @@ const ENV_VARS_TO_REMOVE: &[&str] = &[
     "GIT_CONFIG_COUNT",
 ];
 
+/// Additional environment variables for the processes spawned by subsequent [handshakes](client::blocking_io::Transport::handshake()),
+/// which can be passed to [`configure()`](client::TransportWithoutIO::configure()) of [`SpawnProcessOnDemand`].
+///
+/// They replace the variables set by a previous configuration, just like [`ExtraHeaders`](super::http::ExtraHeaders) do.
+/// A `GIT_PROTOCOL` variable overrides the one derived from the desired protocol version, which is useful to pass extensions,
+/// and is forwarded by `ssh` even if protocol V1 is desired.
+/// Note that `ssh` only forwards `GIT_PROTOCOL` to the remote, so all other variables only affect the local `ssh` program.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct ExtraEnv(pub Vec<(String, String)>);
+
 /// A utility to spawn a helper process to actually transmit data, possibly over `ssh`.
 ///
 /// It can only be instantiated using the local [`connect()`] or [ssh connect][super::ssh::connect()].
@@ -47,20 +57,29 @@ pub struct SpawnProcessOnDemand {
     path: BString,
     ssh_cmd: Option<(OsString, ssh::ProgramKind)>,
     /// The environment variables to set in the invoked command.
-    envs: Vec<(&'static str, String)>,
+    envs: Vec<(String, String)>,
+    /// The environment variables set by the user via [`ExtraEnv`], which take precedence over `envs`.
+    extra_envs: Vec<(String, String)>,
     ssh_disallow_shell: bool,
+    ssh_connect_timeout: Option<std::time::Duration>,
+    ssh_host_key_checking: Option<ssh::connect::HostKeyChecking>,
+    ssh_identity_file: Option<std::path::PathBuf>,
     connection: Option<Connection<Box<dyn std::io::Read + Send>, process::ChildStdin>>,
     child: Option<process::Child>,
     trace: bool,
 }
 
 impl SpawnProcessOnDemand {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new_ssh(
         url: gix_url::Url,
         program: impl Into<OsString>,
         path: BString,
         ssh_kind: ssh::ProgramKind,
         ssh_disallow_shell: bool,
+        ssh_connect_timeout: Option<std::time::Duration>,
+        ssh_host_key_checking: Option<ssh::connect::HostKeyChecking>,
+        ssh_identity_file: Option<std::path::PathBuf>,
         version: Protocol,
         trace: bool,
     ) -> SpawnProcessOnDemand {
@@ -69,7 +88,11 @@ impl SpawnProcessOnDemand {
             path,
             ssh_cmd: Some((program.into(), ssh_kind)),
             envs: Default::default(),
+            extra_envs: Default::default(),
             ssh_disallow_shell,
+            ssh_connect_timeout,
+            ssh_host_key_checking,
+            ssh_identity_file,
             child: None,
             connection: None,
             desired_version: version,
@@ -84,11 +107,15 @@ impl SpawnProcessOnDemand {
             path,
             ssh_cmd: None,
             envs: if version != Protocol::V1 {
-                vec![("GIT_PROTOCOL", format!("version={}", version as usize))]
+                vec![("GIT_PROTOCOL".into(), format!("version={}", version as usize))]
             } else {
                 Default::default()
             },
+            extra_envs: Default::default(),
             ssh_disallow_shell: false,
+            ssh_connect_timeout: None,
+            ssh_host_key_checking: None,
+            ssh_identity_file: None,
             child: None,
             connection: None,
             desired_version: version,
@@ -102,9 +129,18 @@ impl SpawnProcessOnDemand {
     ) -> Result<(gix_command::Prepare, Option<ssh::ProgramKind>, OsString), client::Error> {
         let (mut cmd, ssh_kind, cmd_name) = match &self.ssh_cmd {
             Some((command, kind)) => (
-                kind.prepare_invocation(command, &self.url, self.desired_version, self.ssh_disallow_shell)
-                    .map_err(client::Error::SshInvocation)?
-                    .stderr(Stdio::piped()),
+                kind.prepare_invocation(
+                    command,
+                    &self.url,
+                    self.desired_version,
+                    self.extra_envs.iter().any(|(name, _)| name == "GIT_PROTOCOL"),
+                    self.ssh_disallow_shell,
+                    self.ssh_connect_timeout,
+                    self.ssh_host_key_checking,
+                    self.ssh_identity_file.as_deref(),
+                )
+                .map_err(client::Error::SshInvocation)?
+                .stderr(Stdio::piped()),
                 Some(*kind),
                 command.to_owned(),
             ),
@@ -149,7 +185,11 @@ impl client::TransportWithoutIO for SpawnProcessOnDemand {
         true
     }
 
-    fn configure(&mut self, _config: &dyn Any) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    /// Pass [`ExtraEnv`] to set additional environment variables for the processes spawned by subsequent handshakes.
+    fn configure(&mut self, config: &dyn Any) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        if let Some(ExtraEnv(envs)) = config.downcast_ref::<ExtraEnv>() {
+            self.extra_envs.clone_from(envs);
+        }
         Ok(())
     }
 }
@@ -250,6 +290,7 @@ impl client::blocking_io::Transport for SpawnProcessOnDemand {
             cmd.env_remove(env_to_remove);
         }
         cmd.envs(std::mem::take(&mut self.envs));
+        cmd.envs(self.extra_envs.iter().cloned());
 
         gix_features::trace::debug!(command = ?cmd, "gix_transport::SpawnProcessOnDemand");
         let mut child = cmd.spawn().map_err(|err| client::Error::InvokeProgram {
@@ -305,6 +346,60 @@ pub fn connect(
 
 #[cfg(test)]
 mod tests {
+    #[test]
+    fn extra_env_replaces_previous_configuration() {
+        use crate::client::TransportWithoutIO;
+
+        let mut transport = super::connect("repo", crate::Protocol::V2, false).expect("infallible");
+        for value in ["version=2:object-format=sha1", "version=2:object-format=sha256"] {
+            transport
+                .configure(&super::ExtraEnv(vec![("GIT_PROTOCOL".into(), value.into())]))
+                .expect("always accepted");
+        }
+        assert_eq!(
+            transport.extra_envs,
+            [("GIT_PROTOCOL".to_string(), "version=2:object-format=sha256".to_string())],
+            "like ExtraHeaders, the previous configuration is replaced"
+        );
+        assert_eq!(
+            transport.envs,
+            [("GIT_PROTOCOL".to_string(), "version=2".to_string())],
+            "the variable derived from the protocol version is kept, and overridden when spawning the process"
+        );
+    }
+
+    #[test]
+    fn ssh_forwards_git_protocol_from_extra_env_even_with_v1() {
+        use crate::client::TransportWithoutIO;
+
+        let url = gix_url::parse("ssh://host/repo".into()).expect("valid url");
+        let mut transport =
+            super::super::ssh::connect(url, crate::Protocol::V1, Default::default(), false).expect("valid");
+        let args = |transport: &super::SpawnProcessOnDemand| -> Vec<String> {
+            let (cmd, _, _) = transport.prepare_command(crate::Service::UploadPack).expect("valid");
+            std::process::Command::from(cmd)
+                .get_args()
+                .map(|arg| arg.to_string_lossy().into_owned())
+                .collect()
+        };
+        assert!(
+            !args(&transport).iter().any(|arg| arg == "SendEnv=GIT_PROTOCOL"),
+            "V1 doesn't need GIT_PROTOCOL"
+        );
+
+        transport
+            .configure(&super::ExtraEnv(vec![(
+                "GIT_PROTOCOL".into(),
+                "object-format=sha256".into(),
+            )]))
+            .expect("always accepted");
+        assert_eq!(
+            args(&transport)[..2],
+            ["-o", "SendEnv=GIT_PROTOCOL"],
+            "but if it's set by the user, it's forwarded"
+        );
+    }
+
     mod ssh {
         mod connect {
             use crate::{Protocol, client::blocking_io::ssh};
@@ -366,6 +461,9 @@ mod tests {
                         command: Some("unrecognized".into()),
                         disallow_shell: false,
                         kind: None,
+                        connect_timeout: None,
+                        host_key_checking: None,
+                        identity_file: None,
                     };
                     assert!(matches!(
                         ssh::connect(url, Protocol::V1, options, false),
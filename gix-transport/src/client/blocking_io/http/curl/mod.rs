@@ -67,6 +67,7 @@ pub struct Curl {
     handle: Option<thread::JoinHandle<Result<(), Error>>>,
     config: http::Options,
     redirected_base_url: Arc<Mutex<Option<String>>>,
+    peer_address: Arc<Mutex<Option<std::net::SocketAddr>>>,
 }
 
 impl Curl {
@@ -78,11 +79,12 @@ impl Curl {
             .join()
             .expect("handler thread should never panic")
             .expect_err("something should have gone wrong with curl (we join on error only)");
-        let (handle, req, res, redirected_base_url) = remote::new();
+        let (handle, req, res, redirected_base_url, peer_address) = remote::new();
         self.handle = Some(handle);
         self.req = req;
         self.res = res;
         self.redirected_base_url = redirected_base_url;
+        self.peer_address = peer_address;
         err_that_brought_thread_down.into()
     }
 
@@ -128,13 +130,14 @@ impl Curl {
 
 impl Default for Curl {
     fn default() -> Self {
-        let (handle, req, res, redirected_base_url) = remote::new();
+        let (handle, req, res, redirected_base_url, peer_address) = remote::new();
         Curl {
             handle: Some(handle),
             req,
             res,
             config: http::Options::default(),
             redirected_base_url,
+            peer_address,
         }
     }
 }
@@ -177,4 +180,9 @@ impl http::Http for Curl {
     fn redirected_base_url(&self) -> Option<String> {
         self.redirected_base_url.lock().clone()
     }
+
+    /// Note that the address is only known once the response was received completely.
+    fn peer_address(&self) -> Option<std::net::SocketAddr> {
+        *self.peer_address.lock()
+    }
 }
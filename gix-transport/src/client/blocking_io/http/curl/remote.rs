@@ -1,6 +1,7 @@
 use std::{
     io,
     io::{Read, Write},
+    net::{IpAddr, SocketAddr},
     sync::{
         Arc,
         mpsc::{Receiver, SyncSender, TrySendError, sync_channel},
@@ -35,6 +36,9 @@ enum StreamOrBuffer {
 /// update the transport-visible base URL before `perform()` returns.
 type SharedRedirectedBaseUrl = Arc<Mutex<Option<String>>>;
 
+/// Shared output for the address of the server that answered the most recently completed request.
+type SharedPeerAddress = Arc<Mutex<Option<SocketAddr>>>;
+
 #[derive(Default)]
 struct Handler {
     /// Sends response headers to the consumer until the request finishes or an error is reported.
@@ -96,9 +100,13 @@ impl Handler {
             FollowRedirects::None => 299,
         };
         match Self::parse_status_inner(data) {
-            Ok(status) if !(200..=valid_end).contains(&status) => {
-                Some((status, format!("Received HTTP status {status}").into()))
-            }
+            Ok(status) if !(200..=valid_end).contains(&status) => Some((
+                status,
+                match u16::try_from(status) {
+                    Ok(status) => http::Error::Status { status }.into(),
+                    Err(_) => format!("Received HTTP status {status}").into(),
+                },
+            )),
             Ok(_) => None,
             Err(err) => Some((500, err)),
         }
@@ -276,13 +284,7 @@ impl curl::easy::Handler for Handler {
                     writer
                         .channel
                         .send(Err(io::Error::new(
-                            if status == 401 {
-                                io::ErrorKind::PermissionDenied
-                            } else if (500..600).contains(&status) {
-                                io::ErrorKind::ConnectionAborted
-                            } else {
-                                io::ErrorKind::Other
-                            },
+                            u16::try_from(status).map_or(io::ErrorKind::Other, http::Error::io_error_kind_for_status),
                             err,
                         )))
                         .ok();
@@ -312,11 +314,14 @@ type Worker = (
     SyncSender<Request>,
     Receiver<Response>,
     SharedRedirectedBaseUrl,
+    SharedPeerAddress,
 );
 
 pub fn new() -> Worker {
     let redirected_base_url_shared = Arc::new(Mutex::new(None));
     let redirected_base_url_shared_out = redirected_base_url_shared.clone();
+    let peer_address_shared = Arc::new(Mutex::new(None));
+    let peer_address_shared_out = peer_address_shared.clone();
     let (req_send, req_recv) = sync_channel(0);
     let (res_send, res_recv) = sync_channel(0);
     let handle = std::thread::spawn(move || -> Result<(), Error> {
@@ -543,6 +548,7 @@ pub fn new() -> Worker {
                     (None, None) => {}
                 }
             } else {
+                *peer_address_shared.lock() = primary_address(&mut handle);
                 let actual_url = handle
                     .effective_url()?
                     .expect("effective url is present and valid UTF-8");
@@ -567,7 +573,20 @@ pub fn new() -> Worker {
         }
         Ok(())
     });
-    (handle, req_send, res_recv, redirected_base_url_shared_out)
+    (
+        handle,
+        req_send,
+        res_recv,
+        redirected_base_url_shared_out,
+        peer_address_shared_out,
+    )
+}
+
+/// Return the IP address and port of the server curl connected to for the most recent transfer.
+fn primary_address<H>(handle: &mut Easy2<H>) -> Option<SocketAddr> {
+    let ip: IpAddr = handle.primary_ip().ok()??.parse().ok()?;
+    let port = handle.primary_port().ok()?;
+    Some(SocketAddr::new(ip, port))
 }
 
 fn to_curl_ssl_version(vers: SslVersion) -> curl::easy::SslVersion {
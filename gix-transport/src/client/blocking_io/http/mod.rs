@@ -208,6 +208,14 @@ impl Default for Options {
     }
 }
 
+/// Additional headers to send with every subsequent request, which can be passed to
+/// [`configure()`](client::TransportWithoutIO::configure()) of the http [`Transport`].
+///
+/// Each header is expected as it occurs in an HTTP request, like `Authorization: Bearer <token>`, without newlines.
+/// They replace the headers set by a previous configuration, and are sent in addition to [`Options::extra_headers`].
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct ExtraHeaders(pub Vec<String>);
+
 /// A transport for supporting arbitrary http clients by abstracting interactions with them into the [Http] trait.
 pub struct Transport<H: Http> {
     url: String,
@@ -218,6 +226,7 @@ pub struct Transport<H: Http> {
     service: Option<Service>,
     line_provider: Option<StreamingPeekableIter<H::ResponseBody>>,
     identity: Option<gix_sec::identity::Account>,
+    extra_headers: Vec<String>,
     trace: bool,
 }
 
@@ -243,6 +252,7 @@ impl<H: Http> Transport<H> {
             http,
             line_provider: None,
             identity,
+            extra_headers: Vec::new(),
             trace,
         }
     }
@@ -346,11 +356,21 @@ impl<H: Http> client::TransportWithoutIO for Transport<H> {
         Cow::Borrowed(self.url.as_str().into())
     }
 
+    fn peer_address(&self) -> Option<std::net::SocketAddr> {
+        self.http.peer_address()
+    }
+
     fn connection_persists_across_multiple_requests(&self) -> bool {
         false
     }
 
+    /// Pass [`ExtraHeaders`] to send additional headers with each request, or anything else to configure the
+    /// [`Http`] implementation, like [`Options`] for the built-in ones.
     fn configure(&mut self, config: &dyn Any) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        if let Some(ExtraHeaders(headers)) = config.downcast_ref::<ExtraHeaders>() {
+            self.extra_headers.clone_from(headers);
+            return Ok(());
+        }
         self.http.configure(config)
     }
 }
@@ -387,6 +407,7 @@ impl<H: Http> blocking_io::Transport for Transport<H> {
             dynamic_headers.push(format!("Git-Protocol: {parameters}").into());
         }
         self.add_basic_auth_if_present(&mut dynamic_headers)?;
+        dynamic_headers.extend(self.extra_headers.iter().cloned().map(Cow::Owned));
         let GetResponse { headers, mut body } = self
             .http
             .get(url.as_ref(), &self.url, static_headers.iter().chain(&dynamic_headers))
@@ -467,6 +488,7 @@ impl<H: Http> blocking_io::Transport for Transport<H> {
                 self.actual_version as usize
             )));
         }
+        dynamic_headers.extend(self.extra_headers.iter().cloned().map(Cow::Owned));
 
         let all_headers = static_headers.iter().chain(&dynamic_headers);
         let PostResponse {
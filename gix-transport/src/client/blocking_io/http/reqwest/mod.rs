@@ -10,6 +10,8 @@ pub struct Remote {
     config: crate::client::blocking_io::http::Options,
     /// The effective base URL after an accepted redirect.
     redirected_base_url: std::sync::Arc<parking_lot::Mutex<Option<String>>>,
+    /// The address of the server that answered the most recent request.
+    peer_address: std::sync::Arc<parking_lot::Mutex<Option<std::net::SocketAddr>>>,
 }
 
 /// A function to configure a single request prior to sending it, support most complex configuration beyond what's possible with
@@ -53,6 +53,8 @@ impl Default for Remote {
         let (res_send, res_recv) = std::sync::mpsc::sync_channel(0);
         let redirected_base_url_shared = Arc::new(Mutex::new(None));
         let redirected_base_url_shared_for_field = redirected_base_url_shared.clone();
+        let peer_address_shared = Arc::new(Mutex::new(None));
+        let peer_address_shared_for_field = peer_address_shared.clone();
         let handle = std::thread::spawn(move || -> Result<(), Error> {
             let mut follow = None;
             let redirect_action = Arc::new(Mutex::new(RedirectAction::Stop));
@@ -188,14 +190,11 @@ impl Default for Remote {
                         }
                         let err = match err.status() {
                             Some(status) => {
-                                let kind = if status == reqwest::StatusCode::UNAUTHORIZED {
-                                    std::io::ErrorKind::PermissionDenied
-                                } else if status.is_server_error() {
-                                    std::io::ErrorKind::ConnectionAborted
-                                } else {
-                                    std::io::ErrorKind::Other
-                                };
-                                std::io::Error::new(kind, format!("Received HTTP status {}", status.as_str()))
+                                let status = status.as_u16();
+                                std::io::Error::new(
+                                    http::Error::io_error_kind_for_status(status),
+                                    http::Error::Status { status },
+                                )
                             }
                             // Preserve the `reqwest::Error` as the source so the underlying cause -- e.g. a
                             // connection or TLS failure -- isn't lost. It was previously stringified, which
@@ -207,6 +206,7 @@ impl Default for Remote {
                     }
                 };
 
+                *peer_address_shared.lock() = res.remote_addr();
                 let actual_url = res.url().as_str();
                 if actual_url != effective_url.as_str() {
                     let new_base_url = redirect::base_url(actual_url, &base_url, url)?;
@@ -247,6 +247,7 @@ impl Default for Remote {
             response: res_recv,
             config: http::Options::default(),
             redirected_base_url: redirected_base_url_shared_for_field,
+            peer_address: peer_address_shared_for_field,
         }
     }
 }
@@ -367,6 +368,10 @@ impl http::Http for Remote {
     fn redirected_base_url(&self) -> Option<String> {
         self.redirected_base_url.lock().clone()
     }
+
+    fn peer_address(&self) -> Option<std::net::SocketAddr> {
+        *self.peer_address.lock()
+    }
 }
 
 pub(crate) struct Request {
@@ -10,14 +10,38 @@ pub enum Error {
     },
     #[error("{description}")]
     Detail { description: String },
+    #[error("Received HTTP status {status}")]
+    Status { status: u16 },
     #[error("An IO error occurred while uploading the body of a POST request")]
     PostBody(#[from] std::io::Error),
 }
 
+impl Error {
+    /// Return `true` if a request answered with the HTTP `status` code might succeed if it was retried later.
+    ///
+    /// This is the case for timeouts, rate-limiting and temporary server failures, but not for other client errors
+    /// like failed authentication or missing resources, which are permanent.
+    pub fn is_spurious_status(status: u16) -> bool {
+        matches!(status, 408 | 429 | 500 | 502 | 503 | 504)
+    }
+
+    /// Return the kind of IO error to use for the unsuccessful HTTP `status`, which is [spurious](crate::IsSpuriousError)
+    /// if [the status is](Self::is_spurious_status()).
+    pub(crate) fn io_error_kind_for_status(status: u16) -> std::io::ErrorKind {
+        match status {
+            401 => std::io::ErrorKind::PermissionDenied,
+            408 => std::io::ErrorKind::TimedOut,
+            status if Self::is_spurious_status(status) => std::io::ErrorKind::ConnectionAborted,
+            _ => std::io::ErrorKind::Other,
+        }
+    }
+}
+
 impl crate::IsSpuriousError for Error {
     fn is_spurious(&self) -> bool {
         match self {
             Error::PostBody(err) => err.is_spurious(),
+            Error::Status { status } => Self::is_spurious_status(*status),
             #[cfg(any(feature = "http-client-reqwest", feature = "http-client-curl"))]
             Error::InitHttpClient { source } => {
                 #[cfg(feature = "http-client-curl")]
@@ -135,4 +159,9 @@ pub trait Http {
     fn redirected_base_url(&self) -> Option<String> {
         None
     }
+
+    /// Return the address of the server that answered the most recent request, if available.
+    fn peer_address(&self) -> Option<std::net::SocketAddr> {
+        None
+    }
 }
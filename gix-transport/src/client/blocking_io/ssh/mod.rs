@@ -80,6 +80,51 @@ pub mod connect {
         /// when invoking the program.
         /// If unset, the `program` basename determines the variant, or an invocation of the `command` itself.
         pub kind: Option<ProgramKind>,
+        /// If set, the longest time to wait for the connection to the remote host to be established, after which
+        /// the `ssh` program fails and with it the attempt to connect.
+        ///
+        /// It's passed as `ConnectTimeout` option rounded up to whole seconds, which is only supported by [`ProgramKind::Ssh`].
+        /// All other kinds ignore it.
+        pub connect_timeout: Option<std::time::Duration>,
+        /// If set, determines how the host key presented by the remote host is verified against the known hosts.
+        /// If unset, this is left to the configuration of the `ssh` program, which by default asks interactively
+        /// if the host is new.
+        ///
+        /// It's passed as `StrictHostKeyChecking` option, which is only supported by [`ProgramKind::Ssh`].
+        /// All other kinds ignore it.
+        /// A failed verification is reported as [`Other`](std::io::ErrorKind::Other) error, as it can't be resolved by
+        /// authenticating or retrying.
+        pub host_key_checking: Option<HostKeyChecking>,
+        /// If set, the file with the private key to authenticate with, similar to `ssh -i <file>` in `core.sshCommand`.
+        ///
+        /// It's passed as `-i <file>` to all program kinds except for [`ProgramKind::Simple`], which fails as it doesn't support it.
+        /// Note that a passphrase needed to decrypt the key is requested by the program itself, typically by prompting on the terminal
+        /// or through `SSH_ASKPASS`, and a failure to decrypt it is reported as [`InvalidInput`](std::io::ErrorKind::InvalidInput)
+        /// error, which unlike [`PermissionDenied`](std::io::ErrorKind::PermissionDenied) doesn't cause credentials to be requested.
+        pub identity_file: Option<std::path::PathBuf>,
+    }
+
+    /// The policy for verifying the host key of the remote host against the standard known-hosts files,
+    /// typically `~/.ssh/known_hosts` and `/etc/ssh/ssh_known_hosts`.
+    ///
+    /// Note that no matter the policy, a host whose key *changed* compared to the one on record is always rejected,
+    /// as it may indicate a man-in-the-middle attack.
+    #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+    pub enum HostKeyChecking {
+        /// Reject hosts that aren't known yet, only connecting to those with a matching key in the known-hosts files.
+        Strict,
+        /// Accept hosts that aren't known yet and add their key to the user's known-hosts file, trusting it on first use.
+        AcceptNew,
+    }
+
+    impl HostKeyChecking {
+        /// Return the value of the `StrictHostKeyChecking` option of `ssh` implementing this policy.
+        pub fn as_ssh_option_value(&self) -> &'static str {
+            match self {
+                HostKeyChecking::Strict => "yes",
+                HostKeyChecking::AcceptNew => "accept-new",
+            }
+        }
     }
 
     impl Options {
@@ -120,6 +165,9 @@ pub fn connect(
         path,
         kind,
         options.disallow_shell,
+        options.connect_timeout,
+        options.host_key_checking,
+        options.identity_file.clone(),
         desired_version,
         trace,
     ))
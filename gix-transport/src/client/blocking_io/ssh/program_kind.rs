@@ -1,4 +1,4 @@
-use std::{ffi::OsStr, io::ErrorKind};
+use std::{ffi::OsStr, io::ErrorKind, path::Path, time::Duration};
 
 use bstr::{BString, ByteSlice, ByteVec};
 use gix_url::ArgumentSafety::*;
@@ -20,13 +20,21 @@ impl ProgramKind {
         }))
     }
 
-    /// Prepare all information needed to invoke the ssh command
+    /// Prepare all information needed to invoke the ssh command.
+    ///
+    /// If `forward_git_protocol` is set, `GIT_PROTOCOL` is forwarded to the remote even if `desired_version` doesn't need it,
+    /// as the caller sets it.
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn prepare_invocation(
         &self,
         ssh_cmd: &OsStr,
         url: &gix_url::Url,
         desired_version: Protocol,
+        forward_git_protocol: bool,
         disallow_shell: bool,
+        connect_timeout: Option<Duration>,
+        host_key_checking: Option<ssh::connect::HostKeyChecking>,
+        identity_file: Option<&Path>,
     ) -> Result<gix_command::Prepare, ssh::invocation::Error> {
         let mut prepare = gix_command::prepare(ssh_cmd).command_may_be_shell_script();
         if disallow_shell {
@@ -34,10 +42,23 @@ impl ProgramKind {
         }
         match self {
             ProgramKind::Ssh => {
+                if desired_version != Protocol::V1 || forward_git_protocol {
+                    prepare = prepare.args(["-o", "SendEnv=GIT_PROTOCOL"]);
+                }
                 if desired_version != Protocol::V1 {
+                    prepare = prepare.env("GIT_PROTOCOL", format!("version={}", desired_version as usize));
+                }
+                if let Some(timeout) = connect_timeout {
+                    let seconds = timeout.as_secs() + u64::from(timeout.subsec_nanos() > 0);
+                    prepare = prepare.arg("-o").arg(format!("ConnectTimeout={}", seconds.max(1)));
+                }
+                if let Some(checking) = host_key_checking {
                     prepare = prepare
-                        .args(["-o", "SendEnv=GIT_PROTOCOL"])
-                        .env("GIT_PROTOCOL", format!("version={}", desired_version as usize));
+                        .arg("-o")
+                        .arg(format!("StrictHostKeyChecking={}", checking.as_ssh_option_value()));
+                }
+                if let Some(identity_file) = identity_file {
+                    prepare = prepare.arg("-i").arg(identity_file);
                 }
                 if let Some(port) = url.port {
                     prepare = prepare.arg(format!("-p{port}"));
@@ -47,6 +68,9 @@ impl ProgramKind {
                 if *self == ProgramKind::TortoisePlink {
                     prepare = prepare.arg("-batch");
                 }
+                if let Some(identity_file) = identity_file {
+                    prepare = prepare.arg("-i").arg(identity_file);
+                }
                 if let Some(port) = url.port {
                     prepare = prepare.arg("-P");
                     prepare = prepare.arg(port.to_string());
@@ -59,6 +83,12 @@ impl ProgramKind {
                         function: "setting the port",
                     });
                 }
+                if identity_file.is_some() {
+                    return Err(ssh::invocation::Error::Unsupported {
+                        command: ssh_cmd.into(),
+                        function: "setting the identity file",
+                    });
+                }
             }
         }
 
@@ -84,6 +114,12 @@ impl ProgramKind {
             ProgramKind::Ssh | ProgramKind::Simple => {
                 if line.contains_str(b"Permission denied") || line.contains_str(b"permission denied") {
                     Some(ErrorKind::PermissionDenied)
+                } else if line.contains_str(b"incorrect passphrase") {
+                    // The passphrase is asked for by the program itself, so credentials wouldn't be used to decrypt the key.
+                    Some(ErrorKind::InvalidInput)
+                } else if line.contains_str(b"Host key verification failed") {
+                    // Neither credentials nor retrying can help here, the host key has to be made known first.
+                    Some(ErrorKind::Other)
                 } else if line.contains_str(b"resolve hostname") {
                     Some(ErrorKind::ConnectionRefused)
                 } else if line.contains_str(b"connect to host")
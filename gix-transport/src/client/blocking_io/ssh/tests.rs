@@ -97,11 +97,11 @@ mod program_kind {
     }
 
     mod prepare_invocation {
-        use std::ffi::OsStr;
+        use std::{ffi::OsStr, path::Path, time::Duration};
 
         use crate::{
             Protocol,
-            client::blocking_io::ssh::{self, ProgramKind},
+            client::blocking_io::ssh::{self, ProgramKind, connect::HostKeyChecking},
         };
 
         #[test]
@@ -130,6 +130,112 @@ mod program_kind {
             }
         }
 
+        #[test]
+        fn connect_timeout_is_rounded_up_to_seconds_for_ssh_only() -> Result {
+            let url = gix_url::parse("ssh://host/path".into()).expect("valid url");
+            for (timeout, expected) in [
+                (Duration::from_millis(1500), "ConnectTimeout=2"),
+                (Duration::from_secs(3), "ConnectTimeout=3"),
+                (Duration::ZERO, "ConnectTimeout=1"),
+            ] {
+                let prepare = ProgramKind::Ssh.prepare_invocation(
+                    OsStr::new("ssh"),
+                    &url,
+                    Protocol::V1,
+                    false,
+                    false,
+                    Some(timeout),
+                    None,
+                    None,
+                )?;
+                assert_eq!(command_args(prepare), ["-o", expected, "host"]);
+            }
+
+            let prepare = ProgramKind::Plink.prepare_invocation(
+                OsStr::new("plink"),
+                &url,
+                Protocol::V1,
+                false,
+                false,
+                Some(Duration::from_secs(1)),
+                None,
+                None,
+            )?;
+            assert_eq!(command_args(prepare), ["host"], "plink has no such option");
+            Ok(())
+        }
+
+        #[test]
+        fn host_key_checking_for_ssh_only() -> Result {
+            let url = gix_url::parse("ssh://host/path".into()).expect("valid url");
+            for (checking, expected) in [
+                (HostKeyChecking::Strict, "StrictHostKeyChecking=yes"),
+                (HostKeyChecking::AcceptNew, "StrictHostKeyChecking=accept-new"),
+            ] {
+                let prepare = ProgramKind::Ssh.prepare_invocation(
+                    OsStr::new("ssh"),
+                    &url,
+                    Protocol::V1,
+                    false,
+                    false,
+                    None,
+                    Some(checking),
+                    None,
+                )?;
+                assert_eq!(command_args(prepare), ["-o", expected, "host"]);
+            }
+
+            let prepare = ProgramKind::Putty.prepare_invocation(
+                OsStr::new("putty"),
+                &url,
+                Protocol::V1,
+                false,
+                false,
+                None,
+                Some(HostKeyChecking::Strict),
+                None,
+            )?;
+            assert_eq!(command_args(prepare), ["host"], "putty has no such option");
+            Ok(())
+        }
+
+        #[test]
+        fn identity_file() -> Result {
+            let url = gix_url::parse("ssh://host/path".into()).expect("valid url");
+            let key = Path::new("/keys/-id_ed25519");
+            for kind in [ProgramKind::Ssh, ProgramKind::Plink, ProgramKind::Putty] {
+                let prepare = kind.prepare_invocation(
+                    kind.exe().expect("not simple"),
+                    &url,
+                    Protocol::V1,
+                    false,
+                    false,
+                    None,
+                    None,
+                    Some(key),
+                )?;
+                assert_eq!(command_args(prepare), ["-i", "/keys/-id_ed25519", "host"], "{kind:?}");
+            }
+
+            assert!(
+                matches!(
+                    ProgramKind::Simple.prepare_invocation(
+                        OsStr::new("simple"),
+                        &url,
+                        Protocol::V1,
+                        false,
+                        false,
+                        None,
+                        None,
+                        Some(key)
+                    ),
+                    Err(ssh::invocation::Error::Unsupported { .. })
+                ),
+                "the simple variant can't take arguments"
+            );
+            Ok(())
+        }
+
         #[test]
         fn tortoise_plink_has_batch_command() {
             assert_eq!(
@@ -233,13 +339,29 @@ mod program_kind {
             let url = gix_url::parse("ssh://host/path".into()).expect("valid url");
 
             let disallow_shell = false;
-            let prepare =
-                ProgramKind::Ssh.prepare_invocation(OsStr::new("echo hi"), &url, Protocol::V1, disallow_shell)?;
+            let prepare = ProgramKind::Ssh.prepare_invocation(
+                OsStr::new("echo hi"),
+                &url,
+                Protocol::V1,
+                false,
+                disallow_shell,
+                None,
+                None,
+                None,
+            )?;
             assert!(prepare.use_shell, "shells are used when needed");
 
             let disallow_shell = true;
-            let prepare =
-                ProgramKind::Ssh.prepare_invocation(OsStr::new("echo hi"), &url, Protocol::V1, disallow_shell)?;
+            let prepare = ProgramKind::Ssh.prepare_invocation(
+                OsStr::new("echo hi"),
+                &url,
+                Protocol::V1,
+                false,
+                disallow_shell,
+                None,
+                None,
+                None,
+            )?;
             assert!(
                 !prepare.use_shell,
                 "but we can enforce it not to be used as well for historical reasons"
@@ -247,6 +369,12 @@ mod program_kind {
             Ok(())
         }
 
+        fn command_args(prepare: gix_command::Prepare) -> Vec<String> {
+            std::process::Command::from(prepare)
+                .get_args()
+                .map(|arg| arg.to_string_lossy().into_owned())
+                .collect()
+        }
         fn joined(input: &[&str]) -> String {
             input.to_vec().join(" ")
         }
@@ -257,7 +385,7 @@ mod program_kind {
         ) -> std::result::Result<gix_command::Prepare, ssh::invocation::Error> {
             let ssh_cmd = kind.exe().unwrap_or_else(|| OsStr::new("simple"));
             let url = gix_url::parse(url.into()).expect("valid url");
-            kind.prepare_invocation(ssh_cmd, &url, version, false)
+            kind.prepare_invocation(ssh_cmd, &url, version, false, false, None, None, None)
         }
         fn call(kind: ProgramKind, url: &str, version: Protocol) -> gix_command::Prepare {
             try_call(kind, url, version).expect("no error")
@@ -290,6 +418,12 @@ mod program_kind {
                     "byron@github.com: Permission denied (publickey).",
                     ErrorKind::PermissionDenied,
                 ),
+                (ProgramKind::Ssh, "Host key verification failed.", ErrorKind::Other),
+                (
+                    ProgramKind::Ssh,
+                    "Load key \"/keys/id_ed25519\": incorrect passphrase supplied to decrypt private key",
+                    ErrorKind::InvalidInput,
+                ),
                 (
                     ProgramKind::Ssh,
                     "ssh: Could not resolve hostname hostfoobar: nodename nor servname provided, or not known",
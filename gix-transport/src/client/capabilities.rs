@@ -49,6 +49,76 @@ impl Default for Capabilities {
 /// The name of a single capability.
 pub struct Capability<'a>(&'a BStr);
 
+/// Capabilities commonly advertised by servers, for use with [`Capabilities::has()`] and [`Capabilities::value()`].
+///
+/// Note that in protocol V2, features like `shallow` and `filter` are advertised as [values](Capability::values())
+/// of the `fetch` capability instead, while `thin-pack` and `ofs-delta` are always supported.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum WellKnown {
+    /// `agent`, the name and version of the server implementation.
+    Agent,
+    /// `ls-refs`, the V2 command to list references.
+    LsRefs,
+    /// `fetch`, the V2 command to receive a pack, with the features it supports as values.
+    Fetch,
+    /// `server-option`, to indicate that server-specific options may be sent with each command.
+    ServerOption,
+    /// `object-format`, the hash used by the objects of the remote repository.
+    ObjectFormat,
+    /// `session-id`, an identifier for the server session.
+    SessionId,
+    /// `object-info`, the V2 command to obtain information about objects without fetching them.
+    ObjectInfo,
+    /// `bundle-uri`, the V2 command to obtain URIs of bundles to download before fetching.
+    BundleUri,
+    /// `shallow`, to allow fetching shallow clones.
+    Shallow,
+    /// `filter`, to allow partial clones.
+    Filter,
+    /// `thin-pack`, to allow receiving packs with deltas against objects not contained in the pack.
+    ThinPack,
+    /// `ofs-delta`, to allow receiving packs with deltas referring to their base by offset.
+    OfsDelta,
+    /// `side-band-64k`, to multiplex pack data and progress in packet lines of up to 64kB.
+    SideBand64k,
+    /// `multi_ack_detailed`, to negotiate with detailed acknowledgements.
+    MultiAckDetailed,
+    /// `include-tag`, to receive annotated tags pointing to objects that are part of the pack.
+    IncludeTag,
+    /// `no-progress`, to suppress progress messages.
+    NoProgress,
+}
+
+impl WellKnown {
+    /// Return the name of the capability as it is advertised by the server.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WellKnown::Agent => "agent",
+            WellKnown::LsRefs => "ls-refs",
+            WellKnown::Fetch => "fetch",
+            WellKnown::ServerOption => "server-option",
+            WellKnown::ObjectFormat => "object-format",
+            WellKnown::SessionId => "session-id",
+            WellKnown::ObjectInfo => "object-info",
+            WellKnown::BundleUri => "bundle-uri",
+            WellKnown::Shallow => "shallow",
+            WellKnown::Filter => "filter",
+            WellKnown::ThinPack => "thin-pack",
+            WellKnown::OfsDelta => "ofs-delta",
+            WellKnown::SideBand64k => "side-band-64k",
+            WellKnown::MultiAckDetailed => "multi_ack_detailed",
+            WellKnown::IncludeTag => "include-tag",
+            WellKnown::NoProgress => "no-progress",
+        }
+    }
+}
+
+impl AsRef<str> for WellKnown {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
 impl<'a> Capability<'a> {
     /// Returns the name of the capability.
     ///
@@ -131,6 +201,19 @@ impl Capabilities {
         self.capability(feature).is_some()
     }
 
+    /// Returns true if the capability `name`, which may also be [`WellKnown`], is present, with or without a value.
+    pub fn has(&self, name: impl AsRef<str>) -> bool {
+        self.contains(name.as_ref())
+    }
+
+    /// Returns the value of the capability `name`, which may also be [`WellKnown`],
+    /// or `None` if it isn't present or has no value.
+    ///
+    /// Use [`has()`](Capabilities::has()) to learn if a capability without value is present.
+    pub fn value(&self, name: impl AsRef<str>) -> Option<&BStr> {
+        self.capability(name.as_ref())?.value()
+    }
+
     /// Returns the capability with `name`.
     pub fn capability(&self, name: &str) -> Option<Capability<'_>> {
         self.iter().find(|c| c.name() == name.as_bytes().as_bstr())
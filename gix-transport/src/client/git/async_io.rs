@@ -61,6 +61,10 @@ where
         )
     }
 
+    fn peer_address(&self) -> Option<std::net::SocketAddr> {
+        self.state.peer_address
+    }
+
     fn connection_persists_across_multiple_requests(&self) -> bool {
         true
     }
@@ -152,6 +156,7 @@ where
                 desired_version,
                 custom_url: None,
                 mode,
+                peer_address: None,
             },
         }
     }
@@ -185,7 +190,8 @@ mod async_net {
             )
             .await?;
             let write = read.clone();
-            Ok(Self::new(
+            let peer_address = read.peer_addr().ok();
+            let mut connection = Self::new(
                 read,
                 write,
                 desired_version,
@@ -193,7 +199,9 @@ mod async_net {
                 None::<(String, _)>,
                 ConnectMode::Daemon,
                 trace,
-            ))
+            );
+            connection.state.peer_address = peer_address;
+            Ok(connection)
         }
     }
 }
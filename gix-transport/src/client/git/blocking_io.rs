@@ -59,6 +59,10 @@ where
         )
     }
 
+    fn peer_address(&self) -> Option<std::net::SocketAddr> {
+        self.state.peer_address
+    }
+
     fn connection_persists_across_multiple_requests(&self) -> bool {
         true
     }
@@ -147,6 +151,7 @@ where
                 desired_version,
                 custom_url: None,
                 mode,
+                peer_address: None,
             },
         }
     }
@@ -228,12 +233,13 @@ pub mod connect {
             std::time::Duration::from_secs(5),
         )?;
         let write = read.try_clone()?;
+        let peer_address = read.peer_addr().ok();
         let vhost = std::env::var("GIT_OVERRIDE_VIRTUAL_HOST")
             .ok()
             .map(parse_host)
             .transpose()?
             .unwrap_or_else(|| (host.to_owned(), port));
-        Ok(Connection::new(
+        let mut connection = Connection::new(
             read,
             write,
             desired_version,
@@ -241,7 +247,9 @@ pub mod connect {
             Some(vhost),
             git::ConnectMode::Daemon,
             trace,
-        ))
+        );
+        connection.state.peer_address = peer_address;
+        Ok(connection)
     }
 }
 
@@ -18,6 +18,8 @@ pub(crate) struct ConnectionState {
     pub(in crate::client) desired_version: Protocol,
     custom_url: Option<BString>,
     pub(in crate::client) mode: ConnectMode,
+    /// The address of the daemon we are connected to, if known.
+    pub(in crate::client) peer_address: Option<std::net::SocketAddr>,
 }
 
 mod message {
@@ -14,6 +14,9 @@ pub mod capabilities;
 #[doc(inline)]
 pub use capabilities::Capabilities;
 
+///
+pub mod url_rewrite;
+
 mod non_io_types;
 pub use gix_sec::identity::Account;
 pub use non_io_types::{Error, MessageKind, WriteMode};
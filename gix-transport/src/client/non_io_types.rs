@@ -43,6 +43,9 @@ pub(crate) mod connect {
         pub ssh: crate::client::blocking_io::ssh::connect::Options,
         /// If `true`, all packetlines received or sent will be passed to the facilities of the `gix-trace` crate.
         pub trace: bool,
+        /// If set, the URL to connect to is rewritten with the given rules for use with the given service
+        /// before its scheme decides which transport to use.
+        pub url_rewrite: Option<(crate::client::url_rewrite::Rewrite, crate::Service)>,
     }
 
     /// The error used in `connect()`.
@@ -92,7 +95,7 @@ pub(crate) mod connect {
 mod error {
     use std::ffi::OsString;
 
-    use bstr::BString;
+    use bstr::{BStr, BString};
 
     #[cfg(feature = "http-client")]
     use crate::client::blocking_io::http;
@@ -145,6 +148,66 @@ mod error {
         SshInvocation(SshInvocationError),
         #[error("The repository path '{path}' could be mistaken for a command-line argument")]
         AmbiguousPath { path: BString },
+        #[error("An error occurred while talking to {url}")]
+        Remote { url: BString, source: Box<Error> },
+    }
+
+    impl Error {
+        /// Provide the `url` of the remote this error occurred with as context, unless it is already present.
+        ///
+        /// This is typically done where errors leave a transport to make clear which remote failed.
+        /// Note that a password contained in `url` is removed so it can't end up in messages or logs.
+        pub fn with_remote_url(self, url: impl Into<BString>) -> Self {
+            match self {
+                err @ Error::Remote { .. } => err,
+                err => Error::Remote {
+                    url: without_password(url.into()),
+                    source: Box::new(err),
+                },
+            }
+        }
+
+        /// Return the URL of the remote this error occurred with, if it was [provided](Self::with_remote_url()).
+        pub fn remote_url(&self) -> Option<&BStr> {
+            match self {
+                Error::Remote { url, .. } => Some(url.as_ref()),
+                _ => None,
+            }
+        }
+
+        /// Return the HTTP status code of an unsuccessful response if this error was caused by one.
+        ///
+        /// Use [`http::Error::is_spurious_status()`] to learn if retrying might help.
+        #[cfg(feature = "http-client")]
+        pub fn http_status(&self) -> Option<u16> {
+            let http_error = match self.without_remote_url() {
+                Error::Http(err) => Some(err),
+                Error::Io(err) => err.get_ref().and_then(|err| err.downcast_ref::<http::Error>()),
+                _ => None,
+            };
+            match http_error? {
+                http::Error::Status { status } => Some(*status),
+                _ => None,
+            }
+        }
+
+        /// Return the actual error, without the context of the [remote URL](Self::remote_url()).
+        pub fn without_remote_url(&self) -> &Error {
+            match self {
+                Error::Remote { source, .. } => source,
+                err => err,
+            }
+        }
+    }
+
+    fn without_password(url: BString) -> BString {
+        match gix_url::parse(url.as_ref()) {
+            Ok(mut parsed) if parsed.password().is_some() => {
+                parsed.set_password(None);
+                parsed.to_bstring()
+            }
+            _ => url,
+        }
     }
 
     impl crate::IsSpuriousError for Error {
@@ -152,6 +215,7 @@ mod error {
             match self {
                 Error::Io(err) => err.is_spurious(),
                 Error::Http(err) => err.is_spurious(),
+                Error::Remote { source, .. } => source.is_spurious(),
                 _ => false,
             }
         }
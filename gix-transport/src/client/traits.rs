@@ -24,6 +24,15 @@ pub trait TransportWithoutIO {
     /// Returns the canonical URL pointing to the destination of this transport.
     fn to_url(&self) -> Cow<'_, BStr>;
 
+    /// Return the address of the peer this transport is connected to, if known.
+    ///
+    /// This is useful to learn which address family and IP were actually used if the host name resolved to multiple addresses.
+    /// Transports that don't talk to the network directly, like those spawning processes, return `None`, which is the default.
+    /// Note that it's typically only available after the handshake was performed.
+    fn peer_address(&self) -> Option<std::net::SocketAddr> {
+        None
+    }
+
     /// If the actually advertised server version is contained in the returned slice or it is empty, continue as normal,
     /// assume the server's protocol version is desired or acceptable.
     ///
@@ -61,6 +70,10 @@ impl<T: TransportWithoutIO + ?Sized> TransportWithoutIO for Box<T> {
         self.deref().to_url()
     }
 
+    fn peer_address(&self) -> Option<std::net::SocketAddr> {
+        self.deref().peer_address()
+    }
+
     fn supported_protocol_versions(&self) -> &[Protocol] {
         self.deref().supported_protocol_versions()
     }
@@ -83,6 +96,10 @@ impl<T: TransportWithoutIO + ?Sized> TransportWithoutIO for &mut T {
         self.deref().to_url()
     }
 
+    fn peer_address(&self) -> Option<std::net::SocketAddr> {
+        self.deref().peer_address()
+    }
+
     fn supported_protocol_versions(&self) -> &[Protocol] {
         self.deref().supported_protocol_versions()
     }
@@ -0,0 +1,62 @@
+//! Rewrite URLs before connecting to them, similar to what `url.<base>.insteadOf` and `url.<base>.pushInsteadOf` do in `git`.
+use bstr::BString;
+use gix_url::rewrite::Rules;
+
+use crate::Service;
+
+/// A set of rules to replace URL prefixes, each of which applies either when fetching or when pushing.
+///
+/// The rule with the longest matching prefix wins, just like in `git`.
+#[derive(Default, Debug, Clone)]
+pub struct Rewrite {
+    fetch: Rules,
+    push: Rules,
+}
+
+/// Builder
+impl Rewrite {
+    /// Replace URLs starting with `prefix` with `replacement` instead, like `url.<replacement>.insteadOf = <prefix>`.
+    pub fn with_rule(mut self, prefix: impl Into<BString>, replacement: impl Into<BString>) -> Self {
+        self.fetch.push(prefix, replacement);
+        self
+    }
+
+    /// Replace URLs starting with `prefix` with `replacement` only when pushing,
+    /// like `url.<replacement>.pushInsteadOf = <prefix>`.
+    pub fn with_push_rule(mut self, prefix: impl Into<BString>, replacement: impl Into<BString>) -> Self {
+        self.push.push(prefix, replacement);
+        self
+    }
+}
+
+/// Access
+impl Rewrite {
+    /// Return `true` if there are no rules.
+    pub fn is_empty(&self) -> bool {
+        self.fetch.is_empty() && self.push.is_empty()
+    }
+
+    /// Rewrite `url` for use with `service`, and return `true` if a rule matched, even if its replacement left `url` unchanged.
+    ///
+    /// When connecting for [`Service::ReceivePack`], push rules are tried first, and if none of them matches
+    /// the rules for fetching are used, which is what `git` does for remotes without a dedicated push URL.
+    ///
+    /// Note that the replacement is unaware of URL syntax, so the result might not be a valid URL anymore.
+    pub fn rewrite_in_place(&self, url: &mut BString, service: Service) -> bool {
+        (service == Service::ReceivePack && self.push.rewrite_in_place(url)) || self.fetch.rewrite_in_place(url)
+    }
+
+    /// Rewrite `url` for use with `service` and parse the result, or return `None` if no rule matched.
+    ///
+    /// See [`rewrite_in_place()`](Self::rewrite_in_place()) for details.
+    pub fn rewrite(&self, url: &gix_url::Url, service: Service) -> Result<Option<gix_url::Url>, gix_url::parse::Error> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+        let mut url = url.to_bstring();
+        if !self.rewrite_in_place(&mut url, service) {
+            return Ok(None);
+        }
+        gix_url::parse(url.as_ref()).map(Some)
+    }
+}
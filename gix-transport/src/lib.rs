@@ -84,3 +84,7 @@ pub use traits::IsSpuriousError;
 
 ///
 pub mod client;
+
+///
+#[cfg(any(feature = "blocking-client", feature = "async-client"))]
+pub mod retry;
@@ -0,0 +1,58 @@
+//! Utilities to retry operations that failed due to [spurious](crate::IsSpuriousError) errors.
+use std::time::Duration;
+
+/// Return the time to wait before the retry following the failed attempt with zero-based `attempt` index,
+/// which is `base * 2^attempt` randomized by ±25% to avoid clients retrying in lockstep.
+fn delay(base: Duration, attempt: usize) -> Duration {
+    let factor = 2u32.saturating_pow(u32::try_from(attempt).unwrap_or(u32::MAX));
+    let jitter_per_mille = gix_utils::rng::usize(750..=1250) as u32;
+    base.saturating_mul(factor).saturating_mul(jitter_per_mille) / 1000
+}
+
+/// Call `op` up to `attempts` times until it succeeds, sleeping with exponential backoff starting at `base` in between.
+///
+/// Only errors that are [spurious](crate::IsSpuriousError::is_spurious()) cause a retry, all others are returned immediately,
+/// just like the error of the last attempt.
+/// Note that `op` is always called at least once, even if `attempts` is `0`.
+#[cfg(feature = "blocking-client")]
+pub fn with_backoff<T, E>(attempts: usize, base: Duration, mut op: impl FnMut() -> Result<T, E>) -> Result<T, E>
+where
+    E: crate::IsSpuriousError,
+{
+    let mut attempt = 0;
+    loop {
+        match op() {
+            Err(err) if err.is_spurious() && attempt + 1 < attempts => {
+                std::thread::sleep(delay(base, attempt));
+                attempt += 1;
+            }
+            res => return res,
+        }
+    }
+}
+
+/// Like [`with_backoff()`], but awaits the future returned by `op` and uses `sleep` to produce a future that
+/// completes after the given duration, keeping this function independent of any particular async runtime.
+#[cfg(feature = "async-client")]
+pub async fn with_backoff_async<T, E, Fut, SleepFut>(
+    attempts: usize,
+    base: Duration,
+    mut op: impl FnMut() -> Fut,
+    mut sleep: impl FnMut(Duration) -> SleepFut,
+) -> Result<T, E>
+where
+    E: crate::IsSpuriousError,
+    Fut: std::future::Future<Output = Result<T, E>>,
+    SleepFut: std::future::Future<Output = ()>,
+{
+    let mut attempt = 0;
+    loop {
+        match op().await {
+            Err(err) if err.is_spurious() && attempt + 1 < attempts => {
+                sleep(delay(base, attempt)).await;
+                attempt += 1;
+            }
+            res => return res,
+        }
+    }
+}
@@ -39,6 +39,11 @@ fn assert_error_status(
         .handshake(Service::UploadPack, &[])
         .err()
         .expect("non-200 status causes error");
+    assert_eq!(
+        error.http_status(),
+        Some(status as u16),
+        "the status is available in a structured manner"
+    );
     let error = error
         .source()
         .unwrap_or_else(|| panic!("no source() in: {error:?} "))
@@ -57,6 +62,51 @@ fn http_status_500_is_communicated_via_special_io_error() -> crate::Result {
     Ok(())
 }
 
+#[test]
+fn http_status_503_is_spurious_unlike_404() -> crate::Result {
+    use gix_transport::IsSpuriousError;
+    for (status, is_spurious) in [(503, true), (404, false)] {
+        let (server, mut client) =
+            mock::serve_and_connect(&format!("http-{status}.response"), "path/not-important", Protocol::V1)?;
+        let err = client
+            .handshake(Service::UploadPack, &[])
+            .err()
+            .expect("non-200 status causes error");
+        assert_eq!(err.http_status(), Some(status));
+        assert_eq!(err.is_spurious(), is_spurious, "{status}");
+        drop(server.received());
+    }
+    Ok(())
+}
+
+#[test]
+fn extra_headers_are_sent_with_handshake_and_request() -> crate::Result {
+    let (server, mut client) =
+        mock::serve_and_connect("v1/http-handshake.response", "path/not-important", Protocol::V1)?;
+    client
+        .configure(&http::ExtraHeaders(vec!["X-Token: secret".into()]))
+        .expect("extra headers are always accepted");
+    client.handshake(Service::UploadPack, &[])?;
+    assert!(
+        server
+            .received_as_string()
+            .lines()
+            .any(|l| l.eq_ignore_ascii_case("X-Token: secret")),
+        "the header is sent with the handshake"
+    );
+
+    server.next_read_and_respond_with(fixture_bytes("v1/http-handshake.response"));
+    client.request(client::WriteMode::Binary, client::MessageKind::Flush, false)?;
+    assert!(
+        server
+            .received_as_string()
+            .lines()
+            .any(|l| l.eq_ignore_ascii_case("X-Token: secret")),
+        "and with each subsequent request"
+    );
+    Ok(())
+}
+
 #[test]
 fn http_identity_is_picked_up_from_url() -> crate::Result {
     let transport = gix_transport::client::blocking_io::http::connect::<Remote>(
@@ -851,6 +901,12 @@ fn handshake_v1() -> crate::Result {
         ]
     );
 
+    assert_eq!(
+        c.peer_address(),
+        Some(server.addr),
+        "the address of the server is known once the response was received"
+    );
+
     assert_eq!(
         server
             .received_as_string()
@@ -75,6 +75,35 @@ fn from_bytes_with_sha256_object_format() -> crate::Result {
     Ok(())
 }
 
+#[test]
+fn has_and_value_distinguish_valueless_capabilities() -> crate::Result {
+    use gix_transport::client::capabilities::WellKnown;
+    let (caps, _delim_pos) = Capabilities::from_bytes(
+        &b"7814e8a05a59c0cf5fb186661d1551c75d1299b5 HEAD\0thin-pack ofs-delta shallow filter= object-format=sha1 agent=git/2.28.0"[..],
+    )?;
+    for present in [
+        WellKnown::ThinPack,
+        WellKnown::OfsDelta,
+        WellKnown::Shallow,
+        WellKnown::Filter,
+    ] {
+        assert!(caps.has(present), "{present:?}");
+    }
+    assert!(!caps.has(WellKnown::IncludeTag));
+    assert!(caps.has("agent"), "plain names work as well");
+
+    assert_eq!(caps.value(WellKnown::ObjectFormat), Some(b"sha1".as_bstr()));
+    assert_eq!(caps.value("agent"), Some(b"git/2.28.0".as_bstr()));
+    assert_eq!(caps.value(WellKnown::Shallow), None, "present, but without value");
+    assert_eq!(
+        caps.value(WellKnown::Filter),
+        Some(b"".as_bstr()),
+        "present with empty value"
+    );
+    assert_eq!(caps.value(WellKnown::IncludeTag), None, "not present");
+    Ok(())
+}
+
 #[maybe_async::test(feature = "blocking-client", async(feature = "async-client", async_std::test))]
 async fn from_lines_with_version_detection_v0() -> crate::Result {
     let mut buf = Vec::<u8>::new();
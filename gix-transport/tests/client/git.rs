@@ -40,6 +40,7 @@ async fn handshake_v1_and_request() -> crate::Result {
         c.connection_persists_across_multiple_requests(),
         "tcp connections are stateful"
     );
+    assert_eq!(c.peer_address(), None, "only connections made via TCP know their peer");
     let c = c.custom_url(Some("anything".into()));
     assert_eq!(c.to_url().as_ref(), "anything");
     let mut c = c.custom_url(None);
@@ -1,4 +1,7 @@
 #[cfg(feature = "blocking-client")]
 mod blocking_io;
 mod capabilities;
+mod error;
 mod git;
+mod retry;
+mod url_rewrite;
@@ -0,0 +1,90 @@
+use std::{cell::Cell, io};
+
+fn failing_with(kind: io::ErrorKind, calls: &Cell<usize>, succeed_on_call: usize) -> Result<usize, io::Error> {
+    calls.set(calls.get() + 1);
+    if calls.get() == succeed_on_call {
+        Ok(calls.get())
+    } else {
+        Err(io::Error::new(kind, format!("call {}", calls.get())))
+    }
+}
+
+#[cfg(feature = "blocking-client")]
+mod blocking {
+    use std::{cell::Cell, io, time::Duration};
+
+    use gix_transport::retry::with_backoff;
+
+    use super::failing_with;
+
+    #[test]
+    fn spurious_errors_are_retried_until_success() {
+        let calls = Cell::new(0);
+        let res = with_backoff(5, Duration::ZERO, || {
+            failing_with(io::ErrorKind::ConnectionReset, &calls, 3)
+        });
+        assert_eq!(res.expect("third attempt succeeds"), 3);
+    }
+
+    #[test]
+    fn the_last_error_is_returned_if_all_attempts_fail() {
+        let calls = Cell::new(0);
+        let err = with_backoff(3, Duration::ZERO, || {
+            failing_with(io::ErrorKind::TimedOut, &calls, usize::MAX)
+        })
+        .unwrap_err();
+        assert_eq!(calls.get(), 3);
+        assert_eq!(err.to_string(), "call 3");
+    }
+
+    #[test]
+    fn non_spurious_errors_are_returned_immediately() {
+        let calls = Cell::new(0);
+        let err = with_backoff(5, Duration::ZERO, || {
+            failing_with(io::ErrorKind::PermissionDenied, &calls, 2)
+        })
+        .unwrap_err();
+        assert_eq!(calls.get(), 1, "no retry was attempted");
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+    }
+
+    #[test]
+    fn zero_attempts_still_call_once() {
+        let calls = Cell::new(0);
+        let err = with_backoff(0, Duration::ZERO, || {
+            failing_with(io::ErrorKind::TimedOut, &calls, usize::MAX)
+        })
+        .unwrap_err();
+        assert_eq!(calls.get(), 1);
+        assert_eq!(err.to_string(), "call 1");
+    }
+}
+
+#[cfg(feature = "async-client")]
+#[async_std::test]
+async fn async_spurious_errors_are_retried_with_growing_delays() {
+    use std::time::Duration;
+
+    let calls = Cell::new(0);
+    let delays = std::cell::RefCell::new(Vec::new());
+    let res = gix_transport::retry::with_backoff_async(
+        4,
+        Duration::from_millis(1),
+        || std::future::ready(failing_with(io::ErrorKind::ConnectionAborted, &calls, 4)),
+        |delay| {
+            delays.borrow_mut().push(delay);
+            std::future::ready(())
+        },
+    )
+    .await;
+    assert_eq!(res.expect("fourth attempt succeeds"), 4);
+    let delays = delays.into_inner();
+    assert_eq!(delays.len(), 3, "one sleep between each attempt");
+    for (attempt, delay) in delays.into_iter().enumerate() {
+        let nominal = Duration::from_millis(1 << attempt);
+        assert!(
+            delay >= nominal * 3 / 4 && delay <= nominal * 5 / 4,
+            "{delay:?} is within 25% of {nominal:?}"
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use bstr::BString;
+use gix_transport::{Service, client::url_rewrite::Rewrite};
+
+fn rewritten(rewrite: &Rewrite, url: &str, service: Service) -> Option<BString> {
+    let mut url = BString::from(url);
+    rewrite.rewrite_in_place(&mut url, service).then_some(url)
+}
+
+#[test]
+fn longest_prefix_wins() {
+    let rewrite = Rewrite::default()
+        .with_rule("https://github.com/", "https://mirror.example.com/github/")
+        .with_rule("https://github.com/org/", "https://mirror.example.com/org/")
+        .with_rule("https://", "http://");
+    assert_eq!(
+        rewritten(&rewrite, "https://github.com/org/repo", Service::UploadPack),
+        Some("https://mirror.example.com/org/repo".into()),
+        "the most specific rule is chosen, independently of its order"
+    );
+    assert_eq!(
+        rewritten(&rewrite, "https://github.com/other/repo", Service::UploadPack),
+        Some("https://mirror.example.com/github/other/repo".into())
+    );
+    assert_eq!(
+        rewritten(&rewrite, "https://example.com/repo", Service::UploadPack),
+        Some("http://example.com/repo".into())
+    );
+    assert_eq!(
+        rewritten(&rewrite, "ssh://github.com/org/repo", Service::UploadPack),
+        None
+    );
+}
+
+#[test]
+fn push_rules_take_precedence_when_pushing() {
+    let rewrite = Rewrite::default()
+        .with_rule("https://github.com/", "https://mirror.example.com/")
+        .with_push_rule("https://github.com/", "ssh://git@github.com/")
+        .with_push_rule("https://github.com/org/", "ssh://git@github.com/org/");
+    assert_eq!(
+        rewritten(&rewrite, "https://github.com/org/repo", Service::UploadPack),
+        Some("https://mirror.example.com/org/repo".into()),
+        "push rules are ignored when fetching"
+    );
+    assert_eq!(
+        rewritten(&rewrite, "https://github.com/other/repo", Service::ReceivePack),
+        Some("ssh://git@github.com/other/repo".into()),
+        "push rules win even if a fetch rule matches as well"
+    );
+
+    let rewrite = Rewrite::default()
+        .with_rule("https://github.com/", "https://mirror.example.com/")
+        .with_push_rule("https://gitlab.com/", "ssh://git@gitlab.com/");
+    assert_eq!(
+        rewritten(&rewrite, "https://github.com/org/repo", Service::ReceivePack),
+        Some("https://mirror.example.com/org/repo".into()),
+        "fetch rules apply when pushing if no push rule matches"
+    );
+}
+
+#[test]
+fn rewrite_parses_the_result() -> crate::Result {
+    let url = gix_url::parse("https://github.com/org/repo".into())?;
+    assert_eq!(Rewrite::default().rewrite(&url, Service::UploadPack)?, None);
+
+    let rewrite = Rewrite::default().with_rule("https://github.com/", "file:///mirrors/");
+    let rewritten = rewrite.rewrite(&url, Service::UploadPack)?.expect("rule matches");
+    assert_eq!(rewritten.scheme, gix_url::Scheme::File);
+    assert_eq!(rewritten.path, "/mirrors/org/repo");
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "blocking-client")]
+fn connect_dispatches_on_the_rewritten_url() {
+    let rewrite = Rewrite::default().with_rule("https://github.com/", "unknown://mirror.example.com/");
+    let err = gix_transport::client::blocking_io::connect::connect(
+        "https://github.com/org/repo",
+        gix_transport::client::blocking_io::connect::Options {
+            url_rewrite: Some((rewrite, Service::UploadPack)),
+            ..Default::default()
+        },
+    )
+    .err()
+    .expect("the rewritten scheme is unsupported");
+    assert!(
+        matches!(err, gix_transport::client::blocking_io::connect::Error::UnsupportedScheme(gix_url::Scheme::Ext(ref scheme)) if scheme == "unknown"),
+        "the rewritten URL decides which transport is used, got {err:?}"
+    );
+}
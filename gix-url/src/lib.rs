@@ -38,6 +38,9 @@ mod impls;
 ///
 pub mod parse;
 
+///
+pub mod rewrite;
+
 /// Minimal URL parser to replace the `url` crate dependency
 mod simple_url;
 
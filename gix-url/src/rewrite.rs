@@ -0,0 +1,51 @@
+use bstr::{BStr, BString, ByteVec};
+
+#[derive(Debug, Clone)]
+struct Rule {
+    prefix: BString,
+    replacement: BString,
+}
+
+/// A list of rules to replace URL prefixes, as configured with `url.<replacement>.insteadOf = <prefix>`
+/// or `url.<replacement>.pushInsteadOf = <prefix>` in `git`.
+///
+/// The rule with the longest matching prefix wins, and of multiple rules with the same prefix the first one is used.
+#[derive(Default, Debug, Clone)]
+pub struct Rules {
+    rules: Vec<Rule>,
+}
+
+impl Rules {
+    /// Add a rule to replace URLs starting with `prefix` with `replacement`.
+    pub fn push(&mut self, prefix: impl Into<BString>, replacement: impl Into<BString>) {
+        self.rules.push(Rule {
+            prefix: prefix.into(),
+            replacement: replacement.into(),
+        });
+    }
+
+    /// Return `true` if there are no rules.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Replace the prefix of `url` according to the rule with the longest matching prefix, and return `true` if a rule matched,
+    /// even if its replacement left `url` unchanged.
+    ///
+    /// Note that the replacement is unaware of URL syntax, so the result might not be a valid URL anymore.
+    pub fn rewrite_in_place(&self, url: &mut BString) -> bool {
+        self.longest_match(url.as_ref())
+            .map(|rule| url.replace_range(..rule.prefix.len(), rule.replacement.as_slice()))
+            .is_some()
+    }
+
+    fn longest_match(&self, url: &BStr) -> Option<&Rule> {
+        self.rules
+            .iter()
+            .filter(|rule| url.starts_with(rule.prefix.as_slice()))
+            .fold(None, |longest: Option<&Rule>, rule| match longest {
+                Some(longest) if longest.prefix.len() >= rule.prefix.len() => Some(longest),
+                _ => Some(rule),
+            })
+    }
+}
@@ -0,0 +1,32 @@
+use gix_url::rewrite::Rules;
+
+#[test]
+fn the_longest_prefix_wins() {
+    let mut rules = Rules::default();
+    assert!(rules.is_empty());
+    rules.push("https://", "ssh://");
+    rules.push("https://github.com/", "git@github.com:");
+    rules.push("https://github.com/", "ignored");
+
+    let mut url = "https://github.com/org/repo".into();
+    assert!(rules.rewrite_in_place(&mut url));
+    assert_eq!(url, "git@github.com:org/repo", "the first of the longest rules is used");
+
+    let mut url = "https://example.com/repo".into();
+    assert!(rules.rewrite_in_place(&mut url));
+    assert_eq!(url, "ssh://example.com/repo");
+
+    let mut url = "file:///repo".into();
+    assert!(!rules.rewrite_in_place(&mut url), "nothing matched");
+    assert_eq!(url, "file:///repo");
+}
+
+#[test]
+fn matching_rules_are_reported_even_without_change() {
+    let mut rules = Rules::default();
+    rules.push("https://", "https://");
+
+    let mut url = "https://example.com/repo".into();
+    assert!(rules.rewrite_in_place(&mut url));
+    assert_eq!(url, "https://example.com/repo");
+}
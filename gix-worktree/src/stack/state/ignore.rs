@@ -109,6 +109,7 @@ impl Ignore {
                 pattern: &mapping.pattern,
                 sequence_number: mapping.sequence_number,
                 kind: mapping.value,
+                is_negative: mapping.pattern.is_negative(),
                 source,
             };
             if mapping.pattern.is_negative() {
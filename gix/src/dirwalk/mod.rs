@@ -1,10 +1,11 @@
 use std::path::PathBuf;
 
-use gix_dir::walk::{CollapsedEntriesEmissionMode, EmissionMode, ForDeletionMode};
+use gix_dir::walk::{CollapsedEntriesEmissionMode, EmissionMode, ForDeletionMode, SymlinkPolicy};
 
-use crate::{AttributeStack, Pathspec, config};
+use crate::{AttributeStack, Pathspec, bstr::BString, config};
 
 mod options;
+pub(crate) mod parallel;
 
 ///
 pub mod iter;
@@ -56,6 +57,15 @@ pub enum Error {
     FilesystemOptions(#[from] config::boolean::Error),
     #[error("Could not list worktrees to assure they are no candidates for deletion")]
     ListWorktrees(#[from] std::io::Error),
+    #[error("Failed to spawn a thread for the parallel directory walk")]
+    SpawnThread(#[source] std::io::Error),
+}
+
+/// Data computed once to be shared by all walks through the same worktree.
+pub(crate) struct Prepared<'index> {
+    pub git_dir_realpath: PathBuf,
+    pub accelerate_lookup: Option<gix_index::AccelerateLookup<'index>>,
+    pub worktree_relative_worktree_dirs: Option<std::collections::BTreeSet<BString>>,
 }
 
 /// The outcome of the [dirwalk()](crate::Repository::dirwalk).
@@ -89,5 +99,6 @@ pub struct Options {
     classify_untracked_bare_repositories: bool,
     emit_collapsed: Option<CollapsedEntriesEmissionMode>,
     symlinks_to_directories_are_ignored_like_directories: bool,
+    follow_symlinks: SymlinkPolicy,
     pub(crate) empty_patterns_match_prefix: bool,
 }
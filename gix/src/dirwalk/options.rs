@@ -1,4 +1,4 @@
-use gix_dir::walk::{CollapsedEntriesEmissionMode, EmissionMode, ForDeletionMode};
+use gix_dir::walk::{CollapsedEntriesEmissionMode, EmissionMode, ForDeletionMode, SymlinkPolicy};
 
 use crate::dirwalk::Options;
 
@@ -19,6 +19,7 @@ impl Options {
             emit_collapsed: None,
             empty_patterns_match_prefix: false,
             symlinks_to_directories_are_ignored_like_directories: false,
+            follow_symlinks: SymlinkPolicy::Never,
         }
     }
 }
@@ -39,6 +40,7 @@ impl From<Options> for gix_dir::walk::Options<'static> {
             emit_collapsed: v.emit_collapsed,
             symlinks_to_directories_are_ignored_like_directories: v
                 .symlinks_to_directories_are_ignored_like_directories,
+            follow_symlinks: v.follow_symlinks,
             worktree_relative_worktree_dirs: None,
         }
     }
@@ -185,4 +187,21 @@ impl Options {
         self.symlinks_to_directories_are_ignored_like_directories = toggle;
         self
     }
+
+    /// Control which symlinks to directories are traversed as if they were directories, with [`SymlinkPolicy::Never`]
+    /// being the default just like in Git.
+    ///
+    /// Use [`SymlinkPolicy::WithinWorktree`] to assure the traversal can't escape the worktree. Note that symlinks are
+    /// never followed into the git directory of this repository, which is the same boundary that keeps the walk from
+    /// entering it otherwise.
+    pub fn follow_symlinks(mut self, policy: SymlinkPolicy) -> Self {
+        self.follow_symlinks = policy;
+        self
+    }
+
+    /// Like [`follow_symlinks()`](Self::follow_symlinks), but only requires a mutably borrowed instance.
+    pub fn set_follow_symlinks(&mut self, policy: SymlinkPolicy) -> &mut Self {
+        self.follow_symlinks = policy;
+        self
+    }
 }
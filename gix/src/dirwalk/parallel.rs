@@ -0,0 +1,248 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::mpsc::Receiver,
+};
+
+use gix_dir::{
+    Entry, EntryRef, entry,
+    walk::{Action, Delegate, EmissionMode::CollapseDirectory, ForDeletionMode},
+};
+
+use crate::{
+    bstr::{BStr, BString},
+    dirwalk,
+};
+
+/// The result of walking a single top-level directory on a worker thread.
+pub(crate) struct Subtree {
+    /// All entries in the order of emission, without `held`.
+    entries: Vec<(Entry, Option<entry::Status>)>,
+    /// The entry for the directory itself if the serial walk would hold it back until the end of the walk.
+    held: Option<Entry>,
+    /// Statistics of the walk.
+    outcome: gix_dir::walk::Outcome,
+}
+
+/// Returns `true` if entries with `status` would be held back by the walk to see if they can be collapsed.
+fn should_hold(opts: &gix_dir::walk::Options<'_>, status: entry::Status) -> bool {
+    !status.is_pruned() && (opts.emit_ignored == Some(CollapseDirectory) || opts.emit_untracked == CollapseDirectory)
+}
+
+/// A delegate to record all emissions of a walk through a top-level directory on a worker thread,
+/// while leaving recursion decisions to the user-provided delegate.
+pub(crate) struct Recorder {
+    delegate: Box<dyn Delegate>,
+    entries: Vec<(Entry, Option<entry::Status>)>,
+}
+
+impl Recorder {
+    pub(crate) fn new(delegate: Box<dyn Delegate>) -> Self {
+        Recorder {
+            delegate,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Turn everything recorded during the walk through the top-level directory `name` into a [`Subtree`].
+    pub(crate) fn take_subtree(
+        &mut self,
+        name: &BStr,
+        outcome: gix_dir::walk::Outcome,
+        options: dirwalk::Options,
+    ) -> Subtree {
+        let opts = gix_dir::walk::Options::from(options);
+        let mut entries = std::mem::take(&mut self.entries);
+        // The walk emits whatever it still holds at the very end, which for the top-level directory is at most
+        // the directory itself, be it collapsed or empty.
+        let held = entries
+            .last()
+            .is_some_and(|(entry, dir_status)| {
+                dir_status.is_none() && entry.rela_path == name && should_hold(&opts, entry.status)
+            })
+            .then(|| entries.pop().expect("checked").0);
+        Subtree { entries, held, outcome }
+    }
+
+    /// Forget everything recorded so far, useful if the walk failed.
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+impl Delegate for Recorder {
+    fn emit(&mut self, entry: EntryRef<'_>, dir_status: Option<entry::Status>) -> Action {
+        self.entries.push((entry.into_owned(), dir_status));
+        std::ops::ControlFlow::Continue(())
+    }
+
+    fn can_recurse(
+        &mut self,
+        entry: EntryRef<'_>,
+        for_deletion: Option<ForDeletionMode>,
+        worktree_root_is_repository: bool,
+    ) -> bool {
+        self.delegate
+            .can_recurse(entry, for_deletion, worktree_root_is_repository)
+    }
+}
+
+/// A delegate for the walk through the top-level of the worktree, which splices in the [`Subtree`] of each
+/// top-level directory it would recurse into, so `delegate` sees everything in the order of the serial walk.
+///
+/// This works as the serial walk emits everything below a top-level directory right when deciding to recurse into it,
+/// while all top-level entries that are held back for collapsing, along with the collapsed top-level directories themselves,
+/// are emitted at the very end in the order in which they were read from disk as the worktree root itself never collapses.
+pub(crate) struct Merge<'a> {
+    delegate: &'a mut dyn Delegate,
+    opts: gix_dir::walk::Options<'static>,
+    /// The position of each top-level entry in the order they are read from disk.
+    positions: HashMap<BString, usize>,
+    /// The index of each top-level directory in the order they are walked by worker threads.
+    directories: HashMap<BString, usize>,
+    rx: Receiver<(usize, Result<Subtree, dirwalk::Error>)>,
+    received: HashMap<usize, Result<Subtree, dirwalk::Error>>,
+    /// Top-level directories whose subtree was spliced in, so their own emission by the top-level walk must be skipped.
+    spliced: HashSet<BString>,
+    /// Top-level entries that the serial walk would emit at the very end, along with their position.
+    held: Vec<(usize, Entry, Option<entry::Status>)>,
+    error: Option<dirwalk::Error>,
+    is_broken: bool,
+    read_dir_calls: u32,
+    seen_entries: u32,
+    returned_entries: usize,
+}
+
+impl<'a> Merge<'a> {
+    pub(crate) fn new(
+        delegate: &'a mut dyn Delegate,
+        names: Vec<BString>,
+        directories: &[BString],
+        rx: Receiver<(usize, Result<Subtree, dirwalk::Error>)>,
+        options: dirwalk::Options,
+    ) -> Self {
+        Merge {
+            delegate,
+            opts: options.into(),
+            positions: names.into_iter().enumerate().map(|(pos, name)| (name, pos)).collect(),
+            directories: directories
+                .iter()
+                .enumerate()
+                .map(|(idx, name)| (name.clone(), idx))
+                .collect(),
+            rx,
+            received: HashMap::new(),
+            spliced: HashSet::new(),
+            held: Vec::new(),
+            error: None,
+            is_broken: false,
+            read_dir_calls: 0,
+            seen_entries: 0,
+            returned_entries: 0,
+        }
+    }
+
+    /// Emit everything that was held back and return the statistics of the whole walk, given the `outcome` of the
+    /// top-level walk.
+    pub(crate) fn finish(mut self, outcome: gix_dir::walk::Outcome) -> Result<gix_dir::walk::Outcome, dirwalk::Error> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+        if !self.is_broken {
+            let mut held = std::mem::take(&mut self.held);
+            held.sort_by_key(|(pos, _, _)| *pos);
+            for (_, entry, dir_status) in held {
+                if self.forward(entry.to_ref(), dir_status).is_break() {
+                    break;
+                }
+            }
+        }
+        Ok(gix_dir::walk::Outcome {
+            read_dir_calls: outcome.read_dir_calls + self.read_dir_calls,
+            returned_entries: self.returned_entries,
+            // Each spliced directory was also seen by the top-level walk, which the serial walk doesn't do.
+            seen_entries: outcome.seen_entries - self.spliced.len() as u32 + self.seen_entries,
+        })
+    }
+
+    fn forward(&mut self, entry: EntryRef<'_>, dir_status: Option<entry::Status>) -> Action {
+        self.returned_entries += 1;
+        let action = self.delegate.emit(entry, dir_status);
+        self.is_broken |= action.is_break();
+        action
+    }
+
+    /// Obtain the subtree of the top-level directory `name`, or `None` if it wasn't walked by a worker.
+    fn subtree(&mut self, name: &BStr) -> Option<Result<Subtree, dirwalk::Error>> {
+        let idx = *self.directories.get(name)?;
+        loop {
+            if let Some(res) = self.received.remove(&idx) {
+                return Some(res);
+            }
+            let (received_idx, res) = self.rx.recv().ok()?;
+            self.received.insert(received_idx, res);
+        }
+    }
+
+    fn position(&self, name: &BStr) -> usize {
+        self.positions.get(name).copied().unwrap_or(usize::MAX)
+    }
+}
+
+impl Delegate for Merge<'_> {
+    fn emit(&mut self, entry: EntryRef<'_>, dir_status: Option<entry::Status>) -> Action {
+        if self.is_broken {
+            return std::ops::ControlFlow::Break(());
+        }
+        let is_top_level = !entry.rela_path.contains(&b'/');
+        if is_top_level && self.spliced.contains(entry.rela_path.as_ref()) {
+            return std::ops::ControlFlow::Continue(());
+        }
+        if is_top_level && dir_status.is_none() && should_hold(&self.opts, entry.status) {
+            self.held
+                .push((self.position(entry.rela_path.as_ref()), entry.into_owned(), dir_status));
+            return std::ops::ControlFlow::Continue(());
+        }
+        self.forward(entry, dir_status)
+    }
+
+    fn can_recurse(
+        &mut self,
+        entry: EntryRef<'_>,
+        for_deletion: Option<ForDeletionMode>,
+        worktree_root_is_repository: bool,
+    ) -> bool {
+        if self.is_broken {
+            return false;
+        }
+        let rela_path = entry.rela_path.clone();
+        let can_recurse = self
+            .delegate
+            .can_recurse(entry, for_deletion, worktree_root_is_repository);
+        if !can_recurse || rela_path.is_empty() || rela_path.contains(&b'/') {
+            return can_recurse;
+        }
+        let Some(subtree) = self.subtree(rela_path.as_ref()) else {
+            return true;
+        };
+        match subtree {
+            Ok(subtree) => {
+                self.read_dir_calls += subtree.outcome.read_dir_calls;
+                self.seen_entries += subtree.outcome.seen_entries;
+                for (entry, dir_status) in subtree.entries {
+                    if self.forward(entry.to_ref(), dir_status).is_break() {
+                        break;
+                    }
+                }
+                if let Some(held) = subtree.held {
+                    self.held.push((self.position(rela_path.as_ref()), held, None));
+                }
+            }
+            Err(err) => {
+                self.error = Some(err);
+                self.is_broken = true;
+            }
+        }
+        self.spliced.insert(rela_path.into_owned());
+        false
+    }
+}
@@ -21,6 +21,8 @@ pub mod virtual_merge_base {
 
 ///
 pub mod commit {
+    pub use gix_merge::commit::MergeBase;
+
     /// The outcome produced by [`Repository::merge_commits()`](crate::Repository::merge_commits()).
     #[derive(Clone)]
     pub struct Outcome<'a> {
@@ -42,12 +44,25 @@ pub mod commit {
         pub virtual_merge_bases: Vec<gix_hash::ObjectId>,
     }
 
+    impl Outcome<'_> {
+        /// Return the merge-base that was used, or `None` if there was no merge-base and an empty tree was used instead.
+        pub fn merge_base(&self) -> Option<MergeBase> {
+            MergeBase::from_outcome_parts(
+                self.merge_bases.as_ref(),
+                &self.virtual_merge_bases,
+                self.merge_base_tree_id,
+            )
+        }
+    }
+
     /// A way to configure [`Repository::merge_commits()`](crate::Repository::merge_commits()).
     #[derive(Default, Debug, Clone)]
     pub struct Options {
         allow_missing_merge_base: bool,
         tree_merge: crate::merge::tree::Options,
         use_first_merge_base: bool,
+        merge_base: Option<gix_hash::ObjectId>,
+        max_recursion: Option<usize>,
     }
 
     impl From<gix_merge::tree::Options> for Options {
@@ -56,6 +71,8 @@ pub mod commit {
                 tree_merge: value.into(),
                 use_first_merge_base: false,
                 allow_missing_merge_base: false,
+                merge_base: None,
+                max_recursion: None,
             }
         }
     }
@@ -66,6 +83,8 @@ pub mod commit {
                 tree_merge: value,
                 use_first_merge_base: false,
                 allow_missing_merge_base: false,
+                merge_base: None,
+                max_recursion: None,
             }
         }
     }
@@ -76,12 +95,16 @@ pub mod commit {
                 allow_missing_merge_base,
                 tree_merge,
                 use_first_merge_base,
+                merge_base,
+                max_recursion,
             }: Options,
         ) -> Self {
             gix_merge::commit::Options {
                 allow_missing_merge_base,
                 tree_merge: tree_merge.into(),
                 use_first_merge_base,
+                merge_base,
+                max_recursion,
             }
         }
     }
@@ -100,6 +123,20 @@ pub mod commit {
             self.use_first_merge_base = use_first_merge_base;
             self
         }
+
+        /// If `Some(commit_id)`, use `commit_id` as merge-base instead of computing the merge-bases of both commits.
+        pub fn with_merge_base(mut self, merge_base: Option<gix_hash::ObjectId>) -> Self {
+            self.merge_base = merge_base;
+            self
+        }
+
+        /// If `Some(depth)`, limit how deeply multiple merge-bases are merged recursively into a virtual merge-base,
+        /// and use the first merge-base once the limit is reached. `Some(0)` is equivalent to
+        /// [`with_use_first_merge_base(true)`](Self::with_use_first_merge_base()).
+        pub fn with_max_recursion(mut self, max_recursion: Option<usize>) -> Self {
+            self.max_recursion = max_recursion;
+            self
+        }
     }
 }
 
@@ -127,6 +164,10 @@ pub mod tree {
         /// `true` if `conflicts` contains only a single *unresolved* conflict in the last slot, but possibly more resolved ones.
         /// This also makes this outcome a very partial merge that cannot be completed.
         pub failed_on_first_unresolved_conflict: bool,
+        /// The renames detected between the *base* tree and *our* tree, if rename tracking is enabled.
+        pub our_rewrites: Vec<gix_merge::tree::Rewrite>,
+        /// The renames detected between the *base* tree and *their* tree, if rename tracking is enabled.
+        pub their_rewrites: Vec<gix_merge::tree::Rewrite>,
     }
 
     impl Outcome<'_> {
@@ -100,6 +100,8 @@ impl<'repo> Remote<'repo> {
                     .transpose()?
                     .unwrap_or_default(),
                 trace: self.repo.config.trace_packet(),
+                // URLs were rewritten according to the configuration when instantiating the remote.
+                url_rewrite: None,
             },
         )
         .await?;
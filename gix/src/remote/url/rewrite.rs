@@ -1,21 +1,11 @@
-use gix_features::threading::OwnShared;
+use gix_url::rewrite::Rules;
 
-use crate::{
-    bstr::{BStr, BString, ByteVec},
-    config,
-    remote::Direction,
-};
-
-#[derive(Debug, Clone)]
-struct Replace {
-    find: BString,
-    with: OwnShared<BString>,
-}
+use crate::{bstr::BString, config, remote::Direction};
 
 #[derive(Default, Debug, Clone)]
 pub(crate) struct Rewrite {
-    url_rewrite: Vec<Replace>,
-    push_url_rewrite: Vec<Replace>,
+    url_rewrite: Rules,
+    push_url_rewrite: Rules,
 }
 
 /// Init
@@ -27,25 +17,18 @@ impl Rewrite {
         config
             .sections_by_name_and_filter("url", &mut filter)
             .map(|sections| {
-                let mut url_rewrite = Vec::new();
-                let mut push_url_rewrite = Vec::new();
+                let mut url_rewrite = Rules::default();
+                let mut push_url_rewrite = Rules::default();
                 for section in sections {
-                    let replace = match section.header().subsection_name() {
-                        Some(base) => OwnShared::new(base.to_owned()),
-                        None => continue,
+                    let Some(replace) = section.header().subsection_name() else {
+                        continue;
                     };
 
                     for instead_of in section.values(config::tree::Url::INSTEAD_OF.name) {
-                        url_rewrite.push(Replace {
-                            with: OwnShared::clone(&replace),
-                            find: instead_of.into_owned(),
-                        });
+                        url_rewrite.push(instead_of.into_owned(), replace);
                     }
                     for instead_of in section.values(config::tree::Url::PUSH_INSTEAD_OF.name) {
-                        push_url_rewrite.push(Replace {
-                            with: OwnShared::clone(&replace),
-                            find: instead_of.into_owned(),
-                        });
+                        push_url_rewrite.push(instead_of.into_owned(), replace);
                     }
                 }
                 Rewrite {
@@ -59,7 +42,7 @@ impl Rewrite {
 
 /// Access
 impl Rewrite {
-    fn replacements_for(&self, direction: Direction) -> &[Replace] {
+    fn replacements_for(&self, direction: Direction) -> &Rules {
         match direction {
             Direction::Fetch => &self.url_rewrite,
             Direction::Push => &self.push_url_rewrite,
@@ -79,22 +62,6 @@ impl Rewrite {
     ///
     /// Note that the result must still be checked for validity, it might not be a valid URL as we do a syntax-unaware replacement.
     pub fn rewrite_url_in_place(&self, url: &mut BString, direction: Direction) -> bool {
-        self.replacements_for(direction)
-            .iter()
-            .fold(None::<(usize, &BStr)>, |mut acc, replace| {
-                if url.starts_with(replace.find.as_ref()) {
-                    let (bytes_matched, prev_rewrite_with) =
-                        acc.get_or_insert((replace.find.len(), replace.with.as_slice().into()));
-                    if *bytes_matched < replace.find.len() {
-                        *bytes_matched = replace.find.len();
-                        *prev_rewrite_with = replace.with.as_slice().into();
-                    }
-                }
-                acc
-            })
-            .map(|(bytes_matched, replace_with)| {
-                url.replace_range(..bytes_matched, replace_with);
-            })
-            .is_some()
+        self.replacements_for(direction).rewrite_in_place(url)
     }
 }
@@ -22,7 +22,11 @@ impl Repository {
             diff_algorithm,
             ranges,
             since,
+            since_uses,
             rewrites,
+            direction,
+            copy_detection,
+            ignore_whitespace,
         } = options;
         let diff_algorithm = match diff_algorithm {
             Some(diff_algorithm) => diff_algorithm,
@@ -33,8 +37,12 @@ impl Repository {
             diff_algorithm,
             ranges,
             since,
+            since_uses,
             rewrites,
             debug_track_path: false,
+            direction,
+            copy_detection,
+            ignore_whitespace,
         };
 
         let outcome = gix_blame::file(
@@ -44,6 +52,8 @@ impl Repository {
             &mut resource_cache,
             file_path,
             options,
+            &mut gix_features::progress::Discard,
+            &std::sync::atomic::AtomicBool::default(),
         )?;
 
         Ok(outcome)
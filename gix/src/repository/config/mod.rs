@@ -89,6 +89,9 @@ impl crate::Repository {
                 .and_then(|variant| Ssh::VARIANT.try_into_variant(variant).transpose())
                 .transpose()
                 .with_leniency(self.options.lenient_config)?,
+            connect_timeout: None,
+            host_key_checking: None,
+            identity_file: None,
         };
         Ok(opts)
     }
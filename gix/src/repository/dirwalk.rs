@@ -1,8 +1,11 @@
-use std::sync::atomic::AtomicBool;
+use std::{
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
 
 use crate::{
     Repository,
-    bstr::{BStr, BString},
+    bstr::{BStr, BString, ByteSlice},
     config, dirwalk, is_dir_to_mode,
     util::OwnedOrStaticAtomicBool,
     worktree::IndexPersistedOrInMemory,
@@ -40,27 +43,159 @@ impl Repository {
         delegate: &mut dyn gix_dir::walk::Delegate,
     ) -> Result<dirwalk::Outcome<'_>, dirwalk::Error> {
         let _span = gix_trace::coarse!("gix::dirwalk");
-        let workdir = self.workdir().ok_or(dirwalk::Error::MissingWorkDir)?;
-        let mut excludes = self.excludes(
+        let prepared = self.dirwalk_prepare(index, options)?;
+        self.dirwalk_prepared(index, patterns, should_interrupt, options, &prepared, None, delegate)
+    }
+
+    /// Like [`dirwalk()`](Self::dirwalk()), but don't recurse into directories for which `prune(entry)` returns `true`,
+    /// which are emitted instead as if [`Delegate::can_recurse()`](gix_dir::walk::Delegate::can_recurse()) returned `false`.
+    ///
+    /// This is useful to skip large directories like `node_modules` without reading their contents.
+    /// `prune` is only called for directories that would otherwise be recursed into, i.e. after pathspecs, excludes and
+    /// `delegate` had their say.
+    pub fn dirwalk_with_prune(
+        &self,
+        index: &gix_index::State,
+        patterns: impl IntoIterator<Item = impl AsRef<BStr>>,
+        should_interrupt: &AtomicBool,
+        options: dirwalk::Options,
+        prune: impl FnMut(&gix_dir::EntryRef<'_>) -> bool,
+        delegate: &mut dyn gix_dir::walk::Delegate,
+    ) -> Result<dirwalk::Outcome<'_>, dirwalk::Error> {
+        self.dirwalk(
             index,
-            None,
-            crate::worktree::stack::state::ignore::Source::WorktreeThenIdMappingIfNotSkipped,
-        )?;
-        let mut pathspec = self.pathspec(
-            options.empty_patterns_match_prefix, /* empty patterns match prefix */
             patterns,
-            true, /* inherit ignore case */
-            index,
-            crate::worktree::stack::state::attributes::Source::WorktreeThenIdMapping,
-        )?;
+            should_interrupt,
+            options,
+            &mut Prune { prune, delegate },
+        )
+    }
+
+    /// Like [`dirwalk()`](Self::dirwalk()), but traverse the top-level directories of the worktree on up to `thread_limit`
+    /// threads, or as many as there are logical cores if `None`.
+    ///
+    /// Entries are emitted to `delegate` on the calling thread in exactly the same order as [`dirwalk()`](Self::dirwalk())
+    /// would emit them, so results are deterministic and independent of the amount of threads.
+    /// `delegate_factory` is called once per thread to create the delegate that decides if directories encountered by that thread
+    /// [can be recursed into](gix_dir::walk::Delegate::can_recurse()), and should thus behave like `delegate` in that regard.
+    /// Its [`emit()`](gix_dir::walk::Delegate::emit()) method is never called.
+    ///
+    /// Note that the walk is performed serially if the traversal root isn't the worktree root, if the `parallel` feature
+    /// is disabled, or if only a single thread is available.
+    /// The [statistics](gix_dir::walk::Outcome) match those of the serial walk, even though top-level directories are
+    /// traversed ahead of knowing whether they are needed.
+    #[allow(clippy::too_many_arguments)]
+    pub fn dirwalk_parallel(
+        &self,
+        index: &gix_index::State,
+        patterns: impl IntoIterator<Item = impl AsRef<BStr>>,
+        should_interrupt: &AtomicBool,
+        options: dirwalk::Options,
+        thread_limit: Option<usize>,
+        delegate_factory: impl Fn() -> Box<dyn gix_dir::walk::Delegate> + Sync,
+        delegate: &mut dyn gix_dir::walk::Delegate,
+    ) -> Result<dirwalk::Outcome<'_>, dirwalk::Error> {
+        let _span = gix_trace::coarse!("gix::dirwalk_parallel");
+        let workdir = self.workdir().ok_or(dirwalk::Error::MissingWorkDir)?;
+        let patterns: Vec<BString> = patterns.into_iter().map(|p| p.as_ref().to_owned()).collect();
+        let prepared = self.dirwalk_prepare(index, options)?;
+        let num_threads = gix_features::parallel::num_threads(thread_limit);
+        if num_threads <= 1 || self.dirwalk_traversal_root(index, &patterns, options)? != workdir {
+            return self.dirwalk_prepared(index, &patterns, should_interrupt, options, &prepared, None, delegate);
+        }
+
+        let mut names = Vec::new();
+        let mut directories = Vec::new();
+        for entry in
+            gix_fs::read_dir(workdir, gix_dir::walk::Options::from(options).precompose_unicode).map_err(|err| {
+                gix_dir::walk::Error::ReadDir {
+                    path: workdir.to_owned(),
+                    source: err,
+                }
+            })?
+        {
+            let entry = entry.map_err(|err| gix_dir::walk::Error::DirEntry {
+                parent_directory: workdir.to_owned(),
+                source: err,
+            })?;
+            let name = gix_path::os_str_into_bstr(&entry.file_name())
+                .expect("no illformed UTF-8")
+                .to_owned();
+            if entry.file_type().is_ok_and(|ft| ft.is_dir()) {
+                directories.push(name.clone());
+            }
+            names.push(name);
+        }
+
+        let repo = self.clone().into_sync();
+        let next_directory = AtomicUsize::new(0);
+        let stop = AtomicBool::new(false);
+        let (tx, rx) = std::sync::mpsc::channel();
+        gix_features::parallel::threads(|scope| -> Result<dirwalk::Outcome<'_>, dirwalk::Error> {
+            for thread_id in 0..num_threads.min(directories.len()) {
+                let tx = tx.clone();
+                let (repo, patterns, prepared, directories) = (&repo, &patterns, &prepared, &directories);
+                let (next_directory, stop, delegate_factory) = (&next_directory, &stop, &delegate_factory);
+                gix_features::parallel::build_thread()
+                    .name(format!("gix::dirwalk_parallel::{thread_id}"))
+                    .spawn_scoped(scope, move || {
+                        let repo: Repository = repo.to_thread_local();
+                        let workdir = repo.workdir().expect("checked on the calling thread");
+                        let mut recorder = dirwalk::parallel::Recorder::new(delegate_factory());
+                        while !stop.load(Ordering::Relaxed) {
+                            let directory_index = next_directory.fetch_add(1, Ordering::Relaxed);
+                            let Some(name) = directories.get(directory_index) else {
+                                break;
+                            };
+                            let res = repo.dirwalk_prepared(
+                                index,
+                                patterns,
+                                should_interrupt,
+                                options,
+                                prepared,
+                                Some(&workdir.join(gix_path::from_bstr(name.as_bstr()))),
+                                &mut recorder,
+                            );
+                            let res = match res {
+                                Ok(out) => Ok(recorder.take_subtree(name.as_ref(), out.dirwalk, options)),
+                                Err(err) => {
+                                    recorder.clear();
+                                    Err(err)
+                                }
+                            };
+                            if tx.send((directory_index, res)).is_err() {
+                                break;
+                            }
+                        }
+                    })
+                    .map_err(dirwalk::Error::SpawnThread)?;
+            }
+            drop(tx);
+
+            let mut merge = dirwalk::parallel::Merge::new(delegate, names, &directories, rx, options);
+            let res = self.dirwalk_prepared(index, &patterns, should_interrupt, options, &prepared, None, &mut merge);
+            stop.store(true, Ordering::Relaxed);
+            let mut out = res?;
+            out.dirwalk = merge.finish(out.dirwalk)?;
+            Ok(out)
+        })
+    }
 
+    /// Compute everything that can be shared among multiple walks through the same worktree.
+    fn dirwalk_prepare<'index>(
+        &self,
+        index: &'index gix_index::State,
+        options: dirwalk::Options,
+    ) -> Result<dirwalk::Prepared<'index>, dirwalk::Error> {
         let git_dir_realpath =
             crate::path::realpath_opts(self.git_dir(), self.current_dir(), crate::path::realpath::MAX_SYMLINKS)?;
         let fs_caps = self.filesystem_options()?;
         let accelerate_lookup = fs_caps.ignore_case.then(|| index.prepare_icase_backing());
-        let mut opts = gix_dir::walk::Options::from(options);
-        let worktree_relative_worktree_dirs_storage;
-        if let Some(workdir) = self.workdir().filter(|_| opts.for_deletion.is_some()) {
+        let mut worktree_relative_worktree_dirs = None;
+        if let Some(workdir) = self
+            .workdir()
+            .filter(|_| gix_dir::walk::Options::from(options).for_deletion.is_some())
+        {
             let linked_worktrees = self.worktrees()?;
             if !linked_worktrees.is_empty() {
                 let real_workdir = gix_path::realpath_opts(
@@ -68,25 +203,62 @@ impl Repository {
                     self.options.current_dir_or_empty(),
                     gix_path::realpath::MAX_SYMLINKS,
                 )?;
-                worktree_relative_worktree_dirs_storage = linked_worktrees
-                    .into_iter()
-                    .filter_map(|proxy| proxy.base().ok())
-                    .filter_map(|base| base.strip_prefix(&real_workdir).map(ToOwned::to_owned).ok())
-                    .map(|rela_path| {
-                        gix_path::to_unix_separators_on_windows(gix_path::into_bstr(rela_path)).into_owned()
-                    })
-                    .collect();
-                opts.worktree_relative_worktree_dirs = Some(&worktree_relative_worktree_dirs_storage);
+                worktree_relative_worktree_dirs = Some(
+                    linked_worktrees
+                        .into_iter()
+                        .filter_map(|proxy| proxy.base().ok())
+                        .filter_map(|base| base.strip_prefix(&real_workdir).map(ToOwned::to_owned).ok())
+                        .map(|rela_path| {
+                            gix_path::to_unix_separators_on_windows(gix_path::into_bstr(rela_path)).into_owned()
+                        })
+                        .collect(),
+                );
             }
         }
+        Ok(dirwalk::Prepared {
+            git_dir_realpath,
+            accelerate_lookup,
+            worktree_relative_worktree_dirs,
+        })
+    }
+
+    /// Perform a walk using `prepared` data, starting at `traversal_root` if set, or the root derived from `options`
+    /// and `patterns` otherwise.
+    #[allow(clippy::too_many_arguments)]
+    fn dirwalk_prepared(
+        &self,
+        index: &gix_index::State,
+        patterns: impl IntoIterator<Item = impl AsRef<BStr>>,
+        should_interrupt: &AtomicBool,
+        options: dirwalk::Options,
+        prepared: &dirwalk::Prepared<'_>,
+        traversal_root: Option<&Path>,
+        delegate: &mut dyn gix_dir::walk::Delegate,
+    ) -> Result<dirwalk::Outcome<'_>, dirwalk::Error> {
+        let workdir = self.workdir().ok_or(dirwalk::Error::MissingWorkDir)?;
+        let mut excludes = self.excludes(
+            index,
+            None,
+            crate::worktree::stack::state::ignore::Source::WorktreeThenIdMappingIfNotSkipped,
+        )?;
+        let mut pathspec = self.pathspec(
+            options.empty_patterns_match_prefix, /* empty patterns match prefix */
+            patterns,
+            true, /* inherit ignore case */
+            index,
+            crate::worktree::stack::state::attributes::Source::WorktreeThenIdMapping,
+        )?;
+
+        let mut opts = gix_dir::walk::Options::from(options);
+        opts.worktree_relative_worktree_dirs = prepared.worktree_relative_worktree_dirs.as_ref();
         let (outcome, traversal_root) = gix_dir::walk(
             workdir,
             gix_dir::walk::Context {
                 should_interrupt: Some(should_interrupt),
-                git_dir_realpath: git_dir_realpath.as_ref(),
+                git_dir_realpath: prepared.git_dir_realpath.as_ref(),
                 current_dir: self.current_dir(),
                 index,
-                ignore_case_index_lookup: accelerate_lookup.as_ref(),
+                ignore_case_index_lookup: prepared.accelerate_lookup.as_ref(),
                 pathspec: &mut pathspec.search,
                 pathspec_attributes: &mut |relative_path, case, is_dir, out| {
                     let stack = pathspec
@@ -100,7 +272,8 @@ impl Repository {
                 },
                 excludes: Some(&mut excludes.inner),
                 objects: &self.objects,
-                explicit_traversal_root: (!options.empty_patterns_match_prefix).then_some(workdir),
+                explicit_traversal_root: traversal_root
+                    .or_else(|| (!options.empty_patterns_match_prefix).then_some(workdir)),
             },
             opts,
             delegate,
@@ -114,6 +287,35 @@ impl Repository {
         })
     }
 
+    /// Return the traversal root that [`gix_dir::walk()`] would choose for `patterns`.
+    fn dirwalk_traversal_root(
+        &self,
+        index: &gix_index::State,
+        patterns: &[BString],
+        options: dirwalk::Options,
+    ) -> Result<PathBuf, dirwalk::Error> {
+        let workdir = self.workdir().ok_or(dirwalk::Error::MissingWorkDir)?;
+        if !options.empty_patterns_match_prefix {
+            return Ok(workdir.to_owned());
+        }
+        let pathspec = self.pathspec(
+            true, /* empty patterns match prefix */
+            patterns,
+            true, /* inherit ignore case */
+            index,
+            crate::worktree::stack::state::attributes::Source::WorktreeThenIdMapping,
+        )?;
+        let root = pathspec
+            .search
+            .longest_common_directory()
+            .and_then(|candidate| {
+                let candidate = workdir.join(candidate);
+                candidate.is_dir().then_some(candidate)
+            })
+            .unwrap_or_else(|| workdir.join(pathspec.search.prefix_directory()));
+        Ok(root)
+    }
+
     /// Create an iterator over a running traversal, which stops if the iterator is dropped. All arguments
     /// are the same as in [`dirwalk()`](Self::dirwalk).
     ///
@@ -135,3 +337,34 @@ impl Repository {
         )
     }
 }
+
+/// A delegate that prevents recursion into all directories matching `prune`.
+struct Prune<'a, F> {
+    prune: F,
+    delegate: &'a mut dyn gix_dir::walk::Delegate,
+}
+
+impl<F> gix_dir::walk::Delegate for Prune<'_, F>
+where
+    F: FnMut(&gix_dir::EntryRef<'_>) -> bool,
+{
+    fn emit(
+        &mut self,
+        entry: gix_dir::EntryRef<'_>,
+        collapsed_directory_status: Option<gix_dir::entry::Status>,
+    ) -> gix_dir::walk::Action {
+        self.delegate.emit(entry, collapsed_directory_status)
+    }
+
+    fn can_recurse(
+        &mut self,
+        entry: gix_dir::EntryRef<'_>,
+        for_deletion: Option<gix_dir::walk::ForDeletionMode>,
+        worktree_root_is_repository: bool,
+    ) -> bool {
+        let is_worktree_root = entry.rela_path.is_empty();
+        self.delegate
+            .can_recurse(entry.clone(), for_deletion, worktree_root_is_repository)
+            && (is_worktree_root || !(self.prune)(&entry))
+    }
+}
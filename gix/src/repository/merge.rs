@@ -69,6 +69,7 @@ impl Repository {
         Ok(gix_merge::blob::platform::merge::Options {
             is_virtual_ancestor: false,
             resolve_binary_with: None,
+            treat_as_binary: None,
             text: gix_merge::blob::builtin_driver::text::Options {
                 diff_algorithm: self.diff_algorithm()?,
                 conflict: text::Conflict::Keep {
@@ -146,6 +147,8 @@ impl Repository {
             tree,
             conflicts,
             failed_on_first_unresolved_conflict,
+            our_rewrites,
+            their_rewrites,
         } = gix_merge::tree(
             ancestor_tree.as_ref(),
             our_tree.as_ref(),
@@ -168,6 +171,8 @@ impl Repository {
             },
             conflicts,
             failed_on_first_unresolved_conflict,
+            our_rewrites,
+            their_rewrites,
         })
     }
 
@@ -204,6 +209,8 @@ impl Repository {
                     tree,
                     conflicts,
                     failed_on_first_unresolved_conflict,
+                    our_rewrites,
+                    their_rewrites,
                 },
             merge_base_tree_id,
             merge_bases,
@@ -229,6 +236,8 @@ impl Repository {
             },
             conflicts,
             failed_on_first_unresolved_conflict,
+            our_rewrites,
+            their_rewrites,
         };
         Ok(crate::merge::commit::Outcome {
             tree_merge,
@@ -100,10 +100,18 @@ pub mod blame_file {
         pub diff_algorithm: Option<gix_diff::blob::Algorithm>,
         /// The ranges to blame in the file.
         pub ranges: gix_blame::BlameRanges,
-        /// Don't consider commits before the given date.
+        /// Don't consider commits before the given date, as determined by `since_uses`.
         pub since: Option<gix_date::Time>,
+        /// The date of each commit to compare with `since`.
+        pub since_uses: gix_blame::DateKind,
         /// Determine if rename tracking should be performed, and how.
         pub rewrites: Option<gix_diff::Rewrites>,
+        /// The direction in which to traverse the commit-graph.
+        pub direction: gix_blame::BlameDirection,
+        /// Whether and where to look for lines that were copied from other files.
+        pub copy_detection: gix_blame::CopyDetection,
+        /// If `true`, ignore all whitespace when comparing lines.
+        pub ignore_whitespace: bool,
     }
 
     /// The error returned by [Repository::blame_file()](crate::Repository::blame_file()).
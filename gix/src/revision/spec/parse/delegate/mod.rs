@@ -47,7 +47,9 @@ impl<'repo> Delegate<'repo> {
                 if delayed_errors.len() == 1 {
                     delayed_errors.pop().expect("it's exactly one")
                 } else {
-                    Exn::raise_all(delayed_errors, message("one or more delayed errors")).erased()
+                    Exn::raise_all(delayed_errors, message("one or more delayed errors"))
+                        .sort_children_by_key(|frame| frame.error().to_string())
+                        .erased()
                 }
             }),
         }
@@ -1,8 +1,8 @@
 pub(crate) type CommitsStorage =
-    gix_features::threading::OwnShared<gix_fs::SharedFileSnapshotMut<nonempty::NonEmpty<gix_hash::ObjectId>>>;
+    gix_features::threading::OwnShared<gix_fs::SharedFileSnapshotMut<gix_shallow::Commits>>;
 /// A lazily loaded and auto-updated list of commits which are at the shallow boundary (behind which there are no commits available),
 /// sorted to allow bisecting.
-pub type Commits = gix_fs::SharedFileSnapshot<nonempty::NonEmpty<gix_hash::ObjectId>>;
+pub type Commits = gix_fs::SharedFileSnapshot<gix_shallow::Commits>;
 
 ///
 pub mod read {
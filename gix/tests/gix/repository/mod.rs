@@ -140,6 +140,106 @@ mod dirwalk {
         );
         Ok(())
     }
+
+    #[test]
+    fn prune_prevents_recursion_but_emits_the_directory() -> crate::Result {
+        let repo = crate::named_repo("make_basic_repo.sh")?;
+        let index = repo.index()?;
+        let mut collect = gix::dir::walk::delegate::Collect::default();
+        let mut pruned = Vec::new();
+        repo.dirwalk_with_prune(
+            &index,
+            None::<&str>,
+            &AtomicBool::default(),
+            repo.dirwalk_options()?,
+            |entry| {
+                pruned.push(entry.rela_path.to_string());
+                entry.rela_path.as_ref() == "some-with-file"
+            },
+            &mut collect,
+        )?;
+        assert!(
+            pruned.iter().any(|path| path == "some/very"),
+            "directories are still recursed into unless pruned"
+        );
+        assert!(
+            pruned.iter().all(|path| !path.starts_with("some-with-file/")),
+            "nothing within the pruned directory was seen: {pruned:?}"
+        );
+        let entries = collect.into_entries_by_path();
+        assert!(
+            entries
+                .iter()
+                .any(|(e, _)| e.rela_path == "some-with-file" && e.disk_kind == Some(Directory)),
+            "the pruned directory itself is emitted"
+        );
+        assert!(
+            entries
+                .iter()
+                .all(|(e, _)| !e.rela_path.starts_with(b"some-with-file/")),
+            "but nothing inside of it"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parallel_emits_entries_in_serial_order() -> crate::Result {
+        let (repo, _tmp) = crate::repo_rw("make_basic_repo.sh")?;
+        let workdir = repo.workdir().expect("non-bare").to_owned();
+        std::fs::write(workdir.join(".gitignore"), "*.o\ntarget/\n")?;
+        for dir in ["src/a/b", "target/debug", "all-ignored", "mixed", "empty"] {
+            std::fs::create_dir_all(workdir.join(dir))?;
+        }
+        for file in [
+            "src/a/b/c.rs",
+            "src/a/d.o",
+            "target/debug/bin",
+            "all-ignored/x.o",
+            "mixed/x.o",
+            "mixed/y",
+            "top-level-file",
+        ] {
+            std::fs::write(workdir.join(file), b"")?;
+        }
+
+        let index = repo.index()?;
+        let defaults = repo.dirwalk_options()?;
+        for options in [
+            defaults,
+            defaults.emit_untracked(EmissionMode::CollapseDirectory),
+            defaults
+                .emit_untracked(EmissionMode::CollapseDirectory)
+                .emit_ignored(Some(EmissionMode::CollapseDirectory))
+                .emit_tracked(true)
+                .emit_empty_directories(true),
+            defaults
+                .emit_ignored(Some(EmissionMode::Matching))
+                .emit_pruned(true)
+                .emit_collapsed(Some(gix::dir::walk::CollapsedEntriesEmissionMode::All)),
+        ] {
+            let mut serial = gix::dir::walk::delegate::Collect::default();
+            let expected = repo.dirwalk(&index, None::<&str>, &AtomicBool::default(), options, &mut serial)?;
+            for thread_limit in [Some(1), Some(2), None] {
+                let mut parallel = gix::dir::walk::delegate::Collect::default();
+                let actual = repo.dirwalk_parallel(
+                    &index,
+                    None::<&str>,
+                    &AtomicBool::default(),
+                    options,
+                    thread_limit,
+                    || Box::new(gix::dir::walk::delegate::Collect::default()),
+                    &mut parallel,
+                )?;
+                assert_eq!(
+                    parallel.unorded_entries, serial.unorded_entries,
+                    "entries are emitted in the same order as in the serial walk"
+                );
+                assert_eq!(actual.dirwalk, expected.dirwalk, "statistics are the same as well");
+                assert_eq!(actual.traversal_root, expected.traversal_root);
+            }
+        }
+        Ok(())
+    }
 }
 
 #[test]
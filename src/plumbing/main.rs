@@ -1704,7 +1704,7 @@ pub fn main() -> Result<()> {
             progress,
             progress_keep_open,
             None,
-            move |_progress, out, err| {
+            move |progress, out, err| {
                 let repo = repository(Mode::Lenient)?;
                 let diff_algorithm = repo.diff_algorithm()?;
 
@@ -1715,9 +1715,14 @@ pub fn main() -> Result<()> {
                         diff_algorithm,
                         ranges: gix::blame::BlameRanges::from_one_based_inclusive_ranges(ranges)?,
                         since,
+                        since_uses: gix::blame::DateKind::default(),
                         rewrites: Some(gix::diff::Rewrites::default()),
                         debug_track_path: false,
+                        direction: gix::blame::BlameDirection::default(),
+                        copy_detection: gix::blame::CopyDetection::default(),
+                        ignore_whitespace: false,
                     },
+                    progress,
                     out,
                     statistics.then_some(err),
                 )
@@ -39,8 +39,12 @@ pub(super) mod function {
             diff_algorithm,
             ranges: gix::blame::BlameRanges::default(),
             since: None,
+            since_uses: gix::blame::DateKind::default(),
             rewrites: Some(gix::diff::Rewrites::default()),
             debug_track_path: true,
+            direction: gix::blame::BlameDirection::default(),
+            copy_detection: gix::blame::CopyDetection::default(),
+            ignore_whitespace: false,
         };
 
         let index = repo.index_or_empty()?;
@@ -71,6 +75,8 @@ pub(super) mod function {
             &mut resource_cache,
             file.as_bstr(),
             options,
+            &mut gix::progress::Discard,
+            &std::sync::atomic::AtomicBool::default(),
         )?;
 
         let blame_infos = outcome